@@ -0,0 +1,33 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::prelude::{fmt, String, Vec};
+
+/// A C enumeration, as also produced by a fieldless `#[repr(C)]` enum in
+/// Rust.
+///
+/// See: https://en.cppreference.com/w/c/language/enum
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enum {
+    pub name: String,
+    pub variants: Vec<(String, i64)>,
+}
+
+impl Enum {
+    pub fn new(name: impl Into<String>, variants: Vec<(String, i64)>) -> Self {
+        Self {
+            name: name.into(),
+            variants,
+        }
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "enum {} {{", self.name)?;
+        for (name, value) in &self.variants {
+            write!(f, " {} = {},", name, value)?;
+        }
+        write!(f, " }};")
+    }
+}
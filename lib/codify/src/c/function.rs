@@ -0,0 +1,51 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::{
+    prelude::{fmt, String, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// A C function pointer type, as also produced by `extern "C" fn` in Rust.
+///
+/// See: https://en.cppreference.com/w/c/language/pointer#Pointers_to_functions
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Function {
+    pub name: String,
+    pub params: Vec<Type>,
+    pub result: Type,
+}
+
+impl Function {
+    pub fn new(name: impl Into<String>, params: Vec<Type>, result: Type) -> Self {
+        Self {
+            name: name.into(),
+            params,
+            result,
+        }
+    }
+
+    /// Converts the signature of an `extern "C" fn` into a C function
+    /// pointer type.
+    pub fn from_rust(
+        name: impl Into<String>,
+        params: Vec<rust::Type>,
+        result: rust::Type,
+    ) -> Result<Self, ()> {
+        let params = params.into_iter().map(Type::try_from).try_collect()?;
+        Ok(Self::new(name, params, Type::try_from(result)?))
+    }
+}
+
+impl fmt::Display for Function {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "typedef {} (*{})(", self.result, self.name)?;
+        match &self.params[..] {
+            [] => write!(f, "void")?,
+            params => write!(f, "{}", params.iter().format(", "))?,
+        }
+        write!(f, ");")
+    }
+}
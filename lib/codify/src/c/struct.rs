@@ -0,0 +1,84 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::{
+    prelude::{fmt, String, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// A C structure declaration, as also produced by `#[repr(C)]` in Rust.
+///
+/// See: https://en.cppreference.com/w/c/language/struct
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Struct {
+    pub name: String,
+    pub fields: Vec<(String, Type)>,
+}
+
+impl Struct {
+    pub fn new(name: impl Into<String>, fields: Vec<(String, Type)>) -> Self {
+        Self {
+            name: name.into(),
+            fields,
+        }
+    }
+
+    /// Converts the fields of a `#[repr(C)]` Rust structure into a C
+    /// structure.
+    pub fn from_rust(
+        name: impl Into<String>,
+        fields: Vec<(String, rust::Type)>,
+    ) -> Result<Self, ()> {
+        let fields = fields
+            .into_iter()
+            .map(|(name, t)| Ok((name, Type::try_from(t)?)))
+            .try_collect()?;
+        Ok(Self::new(name, fields))
+    }
+
+    /// Returns the byte offset of each field, in declaration order.
+    pub fn offsets(&self) -> Option<Vec<usize>> {
+        let mut offsets = Vec::with_capacity(self.fields.len());
+        let mut offset = 0;
+        for (_, t) in &self.fields {
+            let (size, align) = t.layout()?;
+            offset = align_to(offset, align);
+            offsets.push(offset);
+            offset += size;
+        }
+        Some(offsets)
+    }
+
+    /// Returns the size and alignment of this structure in bytes, including
+    /// any trailing padding.
+    pub fn layout(&self) -> Option<(usize, usize)> {
+        let (mut size, mut align) = (0, 1);
+        for (_, t) in &self.fields {
+            let (field_size, field_align) = t.layout()?;
+            size = align_to(size, field_align) + field_size;
+            align = align.max(field_align);
+        }
+        Some((align_to(size, align), align))
+    }
+}
+
+/// Rounds `offset` up to the nearest multiple of `align`.
+fn align_to(offset: usize, align: usize) -> usize {
+    (offset + align - 1) / align * align
+}
+
+impl fmt::Display for Struct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "struct {} {{", self.name)?;
+        for (name, t) in &self.fields {
+            match t {
+                Type::Array(t, Some(n)) => write!(f, " {} {}[{}];", t, name, n)?,
+                Type::Array(t, None) => write!(f, " {} {}[];", t, name)?,
+                t => write!(f, " {} {};", t, name)?,
+            }
+        }
+        write!(f, " }};")
+    }
+}
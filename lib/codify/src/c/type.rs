@@ -48,13 +48,45 @@ pub enum Type {
     #[allow(non_camel_case_types)]
     Size_t,
 
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    Int8_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    Int16_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    Int32_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    Int64_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    UInt8_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    UInt16_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    UInt32_t,
+
+    /// See: https://en.cppreference.com/w/c/types/integer
+    #[allow(non_camel_case_types)]
+    UInt64_t,
+
     Array(Box<Type>, Option<usize>),
 
     Ptr(Box<Type>),
 
     PtrMut(Box<Type>),
 
-    /// TODO: clock_t, errno_t, int*_t, uint*_t.
+    /// TODO: clock_t, errno_t.
 
     /// See: https://docs.rs/libc/latest/libc/type.time_t.html
     #[cfg(feature = "libc")]
@@ -62,6 +94,31 @@ pub enum Type {
     Time_t,
 }
 
+impl Type {
+    /// Returns the size and alignment of this type in bytes, assuming the
+    /// LP64 data model used by 64-bit Linux and macOS.
+    ///
+    /// See: https://en.cppreference.com/w/c/language/object#Alignment
+    pub fn layout(&self) -> Option<(usize, usize)> {
+        use Type::*;
+        Some(match self {
+            Void | Array(_, None) => return None,
+            Bool | Char | SChar | UChar | Int8_t | UInt8_t => (1, 1),
+            Short | UShort | Int16_t | UInt16_t => (2, 2),
+            Float | Int | UInt | Int32_t | UInt32_t => (4, 4),
+            Double | Long | LongLong | ULong | ULongLong => (8, 8),
+            SSize_t | Size_t | Int64_t | UInt64_t => (8, 8),
+            Array(t, Some(n)) => {
+                let (size, align) = t.layout()?;
+                (size * n, align)
+            }
+            Ptr(_) | PtrMut(_) => (8, 8),
+            #[cfg(feature = "libc")]
+            Time_t => (8, 8),
+        })
+    }
+}
+
 impl core::str::FromStr for Type {
     type Err = ();
 
@@ -85,6 +142,14 @@ impl core::str::FromStr for Type {
             "unsigned long" | "unsigned long int" => ULong,
             "unsigned long long" | "unsigned long long int" => ULongLong,
             "size_t" => Size_t,
+            "int8_t" => Int8_t,
+            "int16_t" => Int16_t,
+            "int32_t" => Int32_t,
+            "int64_t" => Int64_t,
+            "uint8_t" => UInt8_t,
+            "uint16_t" => UInt16_t,
+            "uint32_t" => UInt32_t,
+            "uint64_t" => UInt64_t,
             #[cfg(feature = "libc")]
             "time_t" => Time_t,
             _ if input.ends_with("[]") => {
@@ -128,6 +193,14 @@ impl fmt::Display for Type {
             ULong => write!(f, "unsigned long"),
             ULongLong => write!(f, "unsigned long long"),
             Size_t => write!(f, "size_t"),
+            Int8_t => write!(f, "int8_t"),
+            Int16_t => write!(f, "int16_t"),
+            Int32_t => write!(f, "int32_t"),
+            Int64_t => write!(f, "int64_t"),
+            UInt8_t => write!(f, "uint8_t"),
+            UInt16_t => write!(f, "uint16_t"),
+            UInt32_t => write!(f, "uint32_t"),
+            UInt64_t => write!(f, "uint64_t"),
             Array(t, None) => write!(f, "{}[]", t),
             Array(t, Some(n)) => write!(f, "{}[{}]", t, n),
             Ptr(t) => write!(f, "const {}*", t),
@@ -139,7 +212,7 @@ impl fmt::Display for Type {
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -152,27 +225,34 @@ impl TryFrom<rust::Type> for Type {
         Ok(match input {
             rust::Type::Any => return Err(()),
             rust::Type::Unit => Void,
+            rust::Type::Tuple(_) => return Err(()),
             rust::Type::Bool => Bool,
             rust::Type::F32 => Float,
             rust::Type::F64 => Double,
-            rust::Type::U8 => UChar,
-            rust::Type::U16 => UShort,
-            rust::Type::U32 => ULong,
-            rust::Type::U64 => ULongLong,
+            // The `<stdint.h>` types have the exact width of Rust's integers,
+            // unlike `long`, which is 32 bits wide on Windows:
+            rust::Type::U8 => UInt8_t,
+            rust::Type::U16 => UInt16_t,
+            rust::Type::U32 => UInt32_t,
+            rust::Type::U64 => UInt64_t,
             rust::Type::U128 => return Err(()),
-            rust::Type::Usize => return Err(()),
-            rust::Type::I8 => SChar,
-            rust::Type::I16 => Short,
-            rust::Type::I32 => Long,
-            rust::Type::I64 => LongLong,
+            rust::Type::Usize => Size_t,
+            rust::Type::I8 => Int8_t,
+            rust::Type::I16 => Int16_t,
+            rust::Type::I32 => Int32_t,
+            rust::Type::I64 => Int64_t,
             rust::Type::I128 => return Err(()),
-            rust::Type::Isize => return Err(()),
+            rust::Type::Isize => SSize_t,
             rust::Type::Range(_) => return Err(()),
             rust::Type::Char => Char,
             rust::Type::Str => Ptr(Box::new(Char)),
             rust::Type::String => return Err(()),
+            rust::Type::Option(_) => return Err(()),
             rust::Type::Box(t) => return Self::try_from(*t).map(|t| Ptr(Box::new(t))),
+            rust::Type::Rc(_) | rust::Type::Arc(_) | rust::Type::Weak(_) => return Err(()),
+            rust::Type::Cow(_) => return Err(()),
             rust::Type::Vec(t) => return Self::try_from(*t).map(|t| Ptr(Box::new(t))),
+            rust::Type::Set(_) => return Err(()),
             rust::Type::Map(_, _) => return Err(()),
             rust::Type::Ref(t) => return Self::try_from(*t).map(|t| Ptr(Box::new(t))),
             rust::Type::RefMut(t) => return Self::try_from(*t).map(|t| PtrMut(Box::new(t))),
@@ -189,6 +269,16 @@ impl crate::ToRust for Type {
         Some(match self {
             Void => rust::Type::Unit,
             Bool => rust::Type::Bool,
+            SSize_t => rust::Type::Isize,
+            Size_t => rust::Type::Usize,
+            Int8_t => rust::Type::I8,
+            Int16_t => rust::Type::I16,
+            Int32_t => rust::Type::I32,
+            Int64_t => rust::Type::I64,
+            UInt8_t => rust::Type::U8,
+            UInt16_t => rust::Type::U16,
+            UInt32_t => rust::Type::U32,
+            UInt64_t => rust::Type::U64,
             t => rust::Type::Ffi(t.clone()),
         })
     }
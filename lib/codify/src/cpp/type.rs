@@ -1,14 +1,18 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    parse::generic,
+    prelude::{fmt, format, Box, Cow, Named},
     rust,
 };
 
 /// See: https://en.cppreference.com/w/cpp/language/types
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
+    /// See: https://en.cppreference.com/w/cpp/keyword/void
+    Void,
+
     /// See: https://en.cppreference.com/w/cpp/keyword/bool
     Bool,
 
@@ -17,6 +21,66 @@ pub enum Type {
 
     /// See: https://en.wikipedia.org/wiki/Double-precision_floating-point_format
     Double,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    Int8,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    Int16,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    Int32,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    Int64,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    UInt8,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    UInt16,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    UInt32,
+
+    /// See: https://en.cppreference.com/w/cpp/types/integer
+    UInt64,
+
+    /// See: https://en.cppreference.com/w/cpp/types/size_t
+    #[allow(non_camel_case_types)]
+    Size_t,
+
+    /// See: https://en.cppreference.com/w/cpp/types/ptrdiff_t
+    #[allow(non_camel_case_types)]
+    PtrDiff_t,
+
+    /// See: https://en.cppreference.com/w/cpp/language/types#Character_types
+    #[allow(non_camel_case_types)]
+    Char32_t,
+
+    /// See: https://en.cppreference.com/w/cpp/string/basic_string
+    String,
+
+    /// See: https://en.cppreference.com/w/cpp/memory/unique_ptr
+    UniquePtr(Box<Type>),
+
+    /// See: https://en.cppreference.com/w/cpp/memory/shared_ptr
+    SharedPtr(Box<Type>),
+
+    /// See: https://en.cppreference.com/w/cpp/memory/weak_ptr
+    WeakPtr(Box<Type>),
+
+    /// See: https://en.cppreference.com/w/cpp/language/reference
+    Ref(Box<Type>),
+
+    /// See: https://en.cppreference.com/w/cpp/language/reference
+    RefMut(Box<Type>),
+
+    /// See: https://en.cppreference.com/w/cpp/language/pointer
+    Ptr(Box<Type>),
+
+    /// See: https://en.cppreference.com/w/cpp/language/pointer
+    PtrMut(Box<Type>),
 }
 
 impl core::str::FromStr for Type {
@@ -24,28 +88,113 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
-        Ok(match input {
+        let input = input.trim();
+        // The `std::` qualifier is optional, as after `using namespace std;`:
+        let unqualified = input.strip_prefix("std::").unwrap_or(input);
+        Ok(match unqualified {
+            "void" => Void,
             "bool" => Bool,
             "float" => Float,
             "double" => Double,
-            _ => return Err(()),
+            // The fundamental integer types have these widths on every
+            // mainstream data model, LP64 and LLP64 alike:
+            "signed char" => Int8,
+            "short" | "short int" => Int16,
+            "int" => Int32,
+            "long long" | "long long int" => Int64,
+            "unsigned char" => UInt8,
+            "unsigned short" | "unsigned short int" => UInt16,
+            "unsigned" | "unsigned int" => UInt32,
+            "unsigned long long" | "unsigned long long int" => UInt64,
+            "int8_t" => Int8,
+            "int16_t" => Int16,
+            "int32_t" => Int32,
+            "int64_t" => Int64,
+            "uint8_t" => UInt8,
+            "uint16_t" => UInt16,
+            "uint32_t" => UInt32,
+            "uint64_t" => UInt64,
+            "size_t" => Size_t,
+            "ptrdiff_t" => PtrDiff_t,
+            "char32_t" => Char32_t,
+            "string" => String,
+            _ => {
+                if let Some(t) = input.strip_suffix('&') {
+                    match pointee(t) {
+                        (t, true) => Ref(Box::new(t.parse()?)),
+                        (t, false) => RefMut(Box::new(t.parse()?)),
+                    }
+                } else if let Some(t) = input.strip_suffix('*') {
+                    match pointee(t) {
+                        (t, true) => Ptr(Box::new(t.parse()?)),
+                        (t, false) => PtrMut(Box::new(t.parse()?)),
+                    }
+                } else if let Some(t) = generic(unqualified, "unique_ptr") {
+                    UniquePtr(Box::new(t.parse()?))
+                } else if let Some(t) = generic(unqualified, "shared_ptr") {
+                    SharedPtr(Box::new(t.parse()?))
+                } else if let Some(t) = generic(unqualified, "weak_ptr") {
+                    WeakPtr(Box::new(t.parse()?))
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
 
+/// Returns the pointee of a pointer or reference declarator, and whether it
+/// is `const`, as in `int const` or `const int`. A leading `const` only
+/// qualifies the pointee when that isn't itself a pointer or reference, as
+/// `const int*&` refers to a mutable pointer to a `const int`.
+fn pointee(input: &str) -> (&str, bool) {
+    let input = input.trim();
+    if let Some(t) = input.strip_suffix("const") {
+        if t.ends_with([' ', '*', '&']) {
+            return (t.trim_end(), true);
+        }
+    }
+    match input.strip_prefix("const ") {
+        Some(t) if !t.ends_with(['*', '&']) => (t, true),
+        _ => (input, false),
+    }
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Type::*;
         match self {
+            Void => write!(f, "void"),
             Bool => write!(f, "bool"),
             Float => write!(f, "float"),
             Double => write!(f, "double"),
+            Int8 => write!(f, "std::int8_t"),
+            Int16 => write!(f, "std::int16_t"),
+            Int32 => write!(f, "std::int32_t"),
+            Int64 => write!(f, "std::int64_t"),
+            UInt8 => write!(f, "std::uint8_t"),
+            UInt16 => write!(f, "std::uint16_t"),
+            UInt32 => write!(f, "std::uint32_t"),
+            UInt64 => write!(f, "std::uint64_t"),
+            Size_t => write!(f, "std::size_t"),
+            PtrDiff_t => write!(f, "std::ptrdiff_t"),
+            Char32_t => write!(f, "char32_t"),
+            String => write!(f, "std::string"),
+            UniquePtr(t) => write!(f, "std::unique_ptr<{}>", t),
+            SharedPtr(t) => write!(f, "std::shared_ptr<{}>", t),
+            WeakPtr(t) => write!(f, "std::weak_ptr<{}>", t),
+            // The `const` follows the pointee so that it stays unambiguous
+            // in nested declarators, as in `char const* const*`:
+            Ref(t) => write!(f, "{} const&", t),
+            RefMut(t) => write!(f, "{}&", t),
+            Ptr(t) => write!(f, "{} const*", t),
+            PtrMut(t) => write!(f, "{}*", t),
         }
     }
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -56,9 +205,29 @@ impl TryFrom<rust::Type> for Type {
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
         use Type::*;
         Ok(match input {
+            rust::Type::Unit => Void,
             rust::Type::Bool => Bool,
             rust::Type::F32 => Float,
             rust::Type::F64 => Double,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::U8 => UInt8,
+            rust::Type::U16 => UInt16,
+            rust::Type::U32 => UInt32,
+            rust::Type::U64 => UInt64,
+            rust::Type::Usize => Size_t,
+            rust::Type::Isize => PtrDiff_t,
+            rust::Type::Char => Char32_t,
+            rust::Type::String => String,
+            rust::Type::Box(t) => UniquePtr(Box::new(Self::try_from(*t)?)),
+            rust::Type::Rc(t) | rust::Type::Arc(t) => SharedPtr(Box::new(Self::try_from(*t)?)),
+            rust::Type::Weak(t) => WeakPtr(Box::new(Self::try_from(*t)?)),
+            rust::Type::Ref(t) => Ref(Box::new(Self::try_from(*t)?)),
+            rust::Type::RefMut(t) => RefMut(Box::new(Self::try_from(*t)?)),
+            rust::Type::Ptr(t) => Ptr(Box::new(Self::try_from(*t)?)),
+            rust::Type::PtrMut(t) => PtrMut(Box::new(Self::try_from(*t)?)),
             _ => return Err(()),
         })
     }
@@ -68,9 +237,29 @@ impl crate::ToRust for Type {
     fn to_rust(&self) -> Option<rust::Type> {
         use Type::*;
         Some(match self {
+            Void => rust::Type::Unit,
             Bool => rust::Type::Bool,
             Float => rust::Type::F32,
             Double => rust::Type::F64,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            UInt8 => rust::Type::U8,
+            UInt16 => rust::Type::U16,
+            UInt32 => rust::Type::U32,
+            UInt64 => rust::Type::U64,
+            Size_t => rust::Type::Usize,
+            PtrDiff_t => rust::Type::Isize,
+            Char32_t => rust::Type::Char,
+            String => rust::Type::String,
+            UniquePtr(t) => rust::Type::Box(Box::new(t.to_rust()?)),
+            SharedPtr(t) => rust::Type::Arc(Box::new(t.to_rust()?)),
+            WeakPtr(t) => rust::Type::Weak(Box::new(t.to_rust()?)),
+            Ref(t) => rust::Type::Ref(Box::new(t.to_rust()?)),
+            RefMut(t) => rust::Type::RefMut(Box::new(t.to_rust()?)),
+            Ptr(t) => rust::Type::Ptr(Box::new(t.to_rust()?)),
+            PtrMut(t) => rust::Type::PtrMut(Box::new(t.to_rust()?)),
         })
     }
 }
@@ -0,0 +1,131 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::r#type::{marshal_as, Unmanaged};
+use crate::{
+    c,
+    prelude::{fmt, Box, String, Vec},
+};
+use itertools::Itertools;
+
+/// A sequential-layout struct declaration for a C structure.
+///
+/// See: https://learn.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.structlayoutattribute
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StructLayout(pub c::Struct);
+
+/// A `LibraryImport` method declaration for a C function exported by
+/// `library`.
+///
+/// See: https://learn.microsoft.com/en-us/dotnet/standard/native-interop/pinvoke-source-generation
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LibraryImport {
+    pub library: String,
+    pub function: c::Function,
+}
+
+impl LibraryImport {
+    pub fn new(library: impl Into<String>, function: c::Function) -> Self {
+        Self {
+            library: library.into(),
+            function,
+        }
+    }
+}
+
+impl fmt::Display for StructLayout {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fields = &self.0.fields;
+        writeln!(f, "[StructLayout(LayoutKind.Sequential)]")?;
+        if fields.iter().any(|(_, t)| is_unsafe(t)) {
+            writeln!(f, "public unsafe struct {}", self.0.name)?;
+        } else {
+            writeln!(f, "public struct {}", self.0.name)?;
+        }
+        writeln!(f, "{{")?;
+        for (name, t) in fields {
+            match t {
+                // See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/unsafe-code#fixed-size-buffers
+                c::Type::Array(t, Some(n)) if is_fixable(t) => {
+                    writeln!(f, "    public fixed {} {}[{}];", Unmanaged(t), name, n)?
+                }
+                t => {
+                    write!(f, "    ")?;
+                    if let Some(marshal_as) = marshal_as(t) {
+                        write!(f, "[MarshalAs({})] ", marshal_as)?;
+                    }
+                    writeln!(f, "public {} {};", Unmanaged(t), name)?
+                }
+            }
+        }
+        write!(f, "}}")
+    }
+}
+
+impl fmt::Display for LibraryImport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // C arrays decay to pointers when passed to a function:
+        let decay = |t: &c::Type| match t {
+            c::Type::Array(t, _) => c::Type::PtrMut(t.clone()),
+            t => t.clone(),
+        };
+        let function = &self.function;
+        let params: Vec<_> = function.params.iter().map(decay).collect();
+        let result = match decay(&function.result) {
+            // A returned string is owned by the library, so return it as a
+            // raw pointer rather than let the marshaller free it:
+            c::Type::Ptr(t) if *t == c::Type::Char => c::Type::Ptr(Box::new(c::Type::Void)),
+            t => t,
+        };
+
+        writeln!(f, "[LibraryImport(\"{}\")]", self.library)?;
+        if let Some(marshal_as) = marshal_as(&result) {
+            writeln!(f, "[return: MarshalAs({})]", marshal_as)?;
+        }
+        let modifiers = if params.iter().chain([&result]).any(is_unsafe) {
+            "internal static unsafe partial"
+        } else {
+            "internal static partial"
+        };
+        let params = params.iter().enumerate().format_with(", ", |(i, t), f| {
+            if let Some(marshal_as) = marshal_as(t) {
+                f(&format_args!("[MarshalAs({})] ", marshal_as))?;
+            }
+            f(&format_args!("{} arg{}", Unmanaged(t), i))
+        });
+        write!(
+            f,
+            "{} {} {}({});",
+            modifiers,
+            Unmanaged(&result),
+            function.name,
+            params
+        )
+    }
+}
+
+/// Checks whether a C type can be the element type of a fixed-size buffer.
+fn is_fixable(t: &c::Type) -> bool {
+    use c::Type::*;
+    matches!(
+        t,
+        Float | Double | Char | SChar | UChar | Short | UShort | Int | UInt | LongLong | ULongLong
+    ) || matches!(
+        t,
+        Int8_t | Int16_t | Int32_t | Int64_t | UInt8_t | UInt16_t | UInt32_t | UInt64_t
+    )
+}
+
+/// Checks whether a C type is rendered as an unmanaged pointer or a fixed
+/// buffer, either of which requires an `unsafe` context.
+fn is_unsafe(t: &c::Type) -> bool {
+    use c::Type::*;
+    match t {
+        Array(t, Some(_)) => is_fixable(t),
+        Array(_, None) => true,
+        Ptr(t) if **t == Char => false,
+        Ptr(t) | PtrMut(t) => **t != Void,
+        _ => false,
+    }
+}
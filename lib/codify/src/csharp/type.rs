@@ -1,9 +1,12 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Box, Cow, Named, Vec},
+    parse::{enclosed, generic, split},
+    prelude::{fmt, format, Box, Cow, Named, String, Vec},
     rust,
 };
+use core::num::NonZeroUsize;
+use itertools::Itertools;
 
 /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/value-types
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -72,16 +75,58 @@ pub enum Type {
     /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/reference-types#the-string-type
     String,
 
-    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.array
-    /// NOTE: We don't support multi-dimensional arrays.
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.guid
+    Guid,
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.datetime
+    DateTime,
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.timespan
+    TimeSpan,
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/arrays#single-dimensional-arrays
+    /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/arrays#jagged-arrays
     Array(Box<Type>),
 
+    /// An array with the given number of dimensions, as in 2 for `int[,]`.
+    ///
+    /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/arrays#multidimensional-arrays
+    MultiArray(Box<Type>, NonZeroUsize),
+
     /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.list-1
     List(Box<Type>),
 
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.ilist-1
+    IList(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.ireadonlylist-1
+    IReadOnlyList(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.ienumerable-1
+    IEnumerable(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.hashset-1
+    HashSet(Box<Type>),
+
     /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.dictionary-2
     Dictionary(Box<Type>, Box<Type>),
 
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.collections.generic.idictionary-2
+    IDictionary(Box<Type>, Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.readonlyspan-1
+    ReadOnlySpan(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.memory-1
+    Memory(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/api/system.threading.tasks.task-1
+    /// NOTE: The non-generic `Task` is represented as `Task(Void)`.
+    Task(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/value-tuples
+    ValueTuple(Vec<Type>),
+
     /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/keywords/ref
     RefMut(Box<Type>),
 
@@ -96,6 +141,198 @@ pub enum Type {
 
     /// See: https://learn.microsoft.com/en-us/dotnet/csharp/language-reference/builtin-types/nullable-value-types
     Nullable(Box<Type>),
+
+    /// See: https://learn.microsoft.com/en-us/dotnet/standard/native-interop/type-marshalling
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl Type {
+    /// Returns the namespaces this type needs imported by `using`
+    /// directives in order to be written with its unqualified name.
+    pub fn usings(&self) -> Vec<&'static str> {
+        let mut usings = Vec::new();
+        self.push_usings(&mut usings);
+        usings.sort_unstable();
+        usings.dedup();
+        usings
+    }
+
+    fn push_usings(&self, usings: &mut Vec<&'static str>) {
+        use Type::*;
+        match self {
+            Int128 | UInt128 | Range | Guid | DateTime | TimeSpan => usings.push("System"),
+            List(t) | IList(t) | IReadOnlyList(t) | IEnumerable(t) | HashSet(t) => {
+                usings.push("System.Collections.Generic");
+                t.push_usings(usings);
+            }
+            Dictionary(k, v) | IDictionary(k, v) => {
+                usings.push("System.Collections.Generic");
+                k.push_usings(usings);
+                v.push_usings(usings);
+            }
+            ReadOnlySpan(t) | Memory(t) => {
+                usings.push("System");
+                t.push_usings(usings);
+            }
+            Task(t) => {
+                usings.push("System.Threading.Tasks");
+                t.push_usings(usings);
+            }
+            ValueTuple(ts) => {
+                if ts.len() < 2 {
+                    usings.push("System");
+                }
+                ts.iter().for_each(|t| t.push_usings(usings));
+            }
+            Array(t) | MultiArray(t, _) | RefMut(t) | Ptr(t) | PtrMut(t) | Out(t) | Nullable(t) => {
+                t.push_usings(usings)
+            }
+            _ => {}
+        }
+    }
+
+    /// Returns a displayable form of this type that omits namespaces, for
+    /// use after the `using` directives returned by [`Type::usings`].
+    pub fn unqualified(&self) -> impl fmt::Display + '_ {
+        Unqualified(self)
+    }
+
+    fn rendered(&self, qualified: bool) -> Rendered<'_> {
+        Rendered(self, qualified)
+    }
+
+    /// Writes the marshaling attribute of a top-level FFI type, which C#
+    /// does not permit on a type argument.
+    fn fmt_attribute(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.marshal_as() {
+            Some(marshal_as) => write!(f, "[MarshalAs({})] ", marshal_as),
+            None => Ok(()),
+        }
+    }
+
+    fn marshal_as(&self) -> Option<String> {
+        match self {
+            #[cfg(feature = "language-c")]
+            Type::Ffi(t) => marshal_as(t),
+            _ => None,
+        }
+    }
+
+    fn fmt_with(&self, f: &mut fmt::Formatter, qualified: bool) -> fmt::Result {
+        use Type::*;
+        let (system, generic, tasks) = if qualified {
+            (
+                "System.",
+                "System.Collections.Generic.",
+                "System.Threading.Tasks.",
+            )
+        } else {
+            ("", "", "")
+        };
+        match self {
+            Void => write!(f, "void"),
+            Object => write!(f, "object"),
+            Bool => write!(f, "bool"),
+            Float => write!(f, "float"),
+            Double => write!(f, "double"),
+            Decimal => write!(f, "decimal"),
+            SByte => write!(f, "sbyte"),
+            Byte => write!(f, "byte"),
+            Short => write!(f, "short"),
+            UShort => write!(f, "ushort"),
+            Int => write!(f, "int"),
+            UInt => write!(f, "uint"),
+            Long => write!(f, "long"),
+            ULong => write!(f, "ulong"),
+            Int128 => write!(f, "{}Int128", system),
+            UInt128 => write!(f, "{}UInt128", system),
+            NInt => write!(f, "nint"),
+            NUInt => write!(f, "nuint"),
+            Range => write!(f, "{}Range", system),
+            Char => write!(f, "char"),
+            String => write!(f, "string"),
+            Guid => write!(f, "{}Guid", system),
+            DateTime => write!(f, "{}DateTime", system),
+            TimeSpan => write!(f, "{}TimeSpan", system),
+            Array(_) | MultiArray(_, _) => {
+                // Rank specifiers read from the outermost array inward, as in
+                // `int[][,]` for an array of two-dimensional arrays:
+                let mut element = self;
+                while let Array(e) | MultiArray(e, _) = element {
+                    element = e;
+                }
+                write!(f, "{}", element.rendered(qualified))?;
+                let mut t = self;
+                while let Array(e) | MultiArray(e, _) = t {
+                    match t {
+                        MultiArray(_, rank) => write!(f, "[{}]", ",".repeat(rank.get() - 1))?,
+                        _ => write!(f, "[]")?,
+                    }
+                    t = e;
+                }
+                Ok(())
+            }
+            List(e) => write!(f, "{}List<{}>", generic, e.rendered(qualified)),
+            IList(e) => write!(f, "{}IList<{}>", generic, e.rendered(qualified)),
+            IReadOnlyList(e) => write!(f, "{}IReadOnlyList<{}>", generic, e.rendered(qualified)),
+            IEnumerable(e) => write!(f, "{}IEnumerable<{}>", generic, e.rendered(qualified)),
+            HashSet(e) => write!(f, "{}HashSet<{}>", generic, e.rendered(qualified)),
+            Dictionary(k, v) => write!(
+                f,
+                "{}Dictionary<{}, {}>",
+                generic,
+                k.rendered(qualified),
+                v.rendered(qualified)
+            ),
+            IDictionary(k, v) => write!(
+                f,
+                "{}IDictionary<{}, {}>",
+                generic,
+                k.rendered(qualified),
+                v.rendered(qualified)
+            ),
+            ReadOnlySpan(e) => write!(f, "{}ReadOnlySpan<{}>", system, e.rendered(qualified)),
+            Memory(e) => write!(f, "{}Memory<{}>", system, e.rendered(qualified)),
+            Task(e) if **e == Void => write!(f, "{}Task", tasks),
+            Task(e) => write!(f, "{}Task<{}>", tasks, e.rendered(qualified)),
+            ValueTuple(ts) if ts.is_empty() => write!(f, "{}ValueTuple", system),
+            ValueTuple(ts) if ts.len() == 1 => {
+                write!(f, "{}ValueTuple<{}>", system, ts[0].rendered(qualified))
+            }
+            ValueTuple(ts) => write!(
+                f,
+                "({})",
+                ts.iter().map(|t| t.rendered(qualified)).format(", ")
+            ),
+            RefMut(e) => write!(f, "ref {}", e.rendered(qualified)),
+            Ptr(e) => write!(f, "const {}*", e.rendered(qualified)),
+            PtrMut(e) => write!(f, "{}*", e.rendered(qualified)),
+            Out(e) => write!(f, "out {}", e.rendered(qualified)),
+            Nullable(e) => write!(f, "{}?", e.rendered(qualified)),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => write!(f, "{}", Unmanaged(t)),
+        }
+    }
+}
+
+/// A C# type rendered with or without namespace qualifiers.
+struct Rendered<'a>(&'a Type, bool);
+
+impl fmt::Display for Rendered<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_with(f, self.1)
+    }
+}
+
+/// A top-level C# type rendered without namespace qualifiers.
+struct Unqualified<'a>(&'a Type);
+
+impl fmt::Display for Unqualified<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_attribute(f)?;
+        self.0.fmt_with(f, false)
+    }
 }
 
 impl core::str::FromStr for Type {
@@ -104,32 +341,60 @@ impl core::str::FromStr for Type {
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
 
-        let input_len = input.len();
-        if input_len == 0 {
+        let input = input.trim();
+        if input.is_empty() {
             return Err(());
         }
 
-        let last_char = input.chars().last().unwrap();
-        match last_char {
-            '?' if input_len > 1 => {
-                return Ok(Nullable(Box::new(input[0..input_len - 1].trim().parse()?)))
-            }
-            '*' if input_len > 8 && input.starts_with("const ") => {
-                return Ok(Ptr(Box::new(input[6..input_len - 1].trim().parse()?)))
-            }
-            '*' if input_len > 1 => {
-                return Ok(PtrMut(Box::new(input[0..input_len - 1].trim().parse()?)))
-            }
-            ']' if input_len > 2 => {
-                return Ok(Array(Box::new(input[0..input_len - 2].trim().parse()?)))
-            }
-            _ => (),
+        if input.starts_with("[MarshalAs(") || matches!(input, "CLong" | "CULong") {
+            #[cfg(not(feature = "language-c"))]
+            return Err(());
+            #[cfg(feature = "language-c")]
+            return parse_marshaled(input).map(Ffi);
         }
 
-        Ok(match input {
+        if let Some(t) = input.strip_suffix('?') {
+            return Ok(Nullable(Box::new(t.parse()?)));
+        }
+        if let Some(t) = input.strip_prefix("const ") {
+            let t = t.trim_end().strip_suffix('*').ok_or(())?;
+            return Ok(Ptr(Box::new(t.parse()?)));
+        }
+        if let Some(t) = input.strip_suffix('*') {
+            return Ok(PtrMut(Box::new(t.parse()?)));
+        }
+        if input.ends_with(']') {
+            return parse_array(input);
+        }
+        if let Some(t) = input.strip_prefix("ref ") {
+            return Ok(RefMut(Box::new(t.parse()?)));
+        }
+        if let Some(t) = input.strip_prefix("out ") {
+            return Ok(Out(Box::new(t.parse()?)));
+        }
+        if let Some(ts) = enclosed(input, '(', ')') {
+            return Ok(ValueTuple(
+                split(ts, ',')
+                    .into_iter()
+                    .map(parse_element)
+                    .try_collect()?,
+            ));
+        }
+
+        // Accept both qualified names and short names after `using` directives:
+        let name = [
+            "System.Collections.Generic.",
+            "System.Threading.Tasks.",
+            "System.",
+        ]
+        .iter()
+        .find_map(|namespace| input.strip_prefix(namespace))
+        .unwrap_or(input);
+
+        Ok(match name {
             "void" | "Void" => Void,
             "object" | "Object" => Object,
-            "bool" | "Bool" => Bool,
+            "bool" | "Boolean" => Bool,
             "float" | "Single" => Float,
             "double" | "Double" => Double,
             "decimal" | "Decimal" => Decimal,
@@ -141,96 +406,185 @@ impl core::str::FromStr for Type {
             "uint" | "UInt32" => UInt,
             "long" | "Int64" => Long,
             "ulong" | "UInt64" => ULong,
+            "Int128" => Int128,
+            "UInt128" => UInt128,
             "nint" | "IntPtr" => NInt,
             "nuint" | "UIntPtr" => NUInt,
+            "Range" => Range,
             "char" | "Char" => Char,
             "string" | "String" => String,
+            "Guid" => Guid,
+            "DateTime" => DateTime,
+            "TimeSpan" => TimeSpan,
+            "Task" => Task(Box::new(Void)),
+            "ValueTuple" => ValueTuple(Vec::new()),
             _ => {
-                if input.starts_with("System.") {
-                    if input.starts_with("System.Collections.Generic.") && last_char == '>' {
-                        let start_idx = input.find('<').ok_or(())?;
-
-                        return Ok(match &input[27..start_idx] {
-                            "List" if input_len > 34 && last_char == '>' => List(Box::new(
-                                input[start_idx + 1..input_len - 1].trim().parse()?,
-                            )),
-                            "Dictionary" if input_len > 42 => {
-                                let comma_idx = input.find(',').ok_or(())?;
-
-                                Dictionary(
-                                    Box::new(input[start_idx + 1..comma_idx].trim().parse()?),
-                                    Box::new(input[comma_idx + 1..input_len - 1].trim().parse()?),
-                                )
-                            }
-                            _ => return Err(()),
-                        });
-                    }
-
-                    return input[8..].parse();
-                }
-
-                if input_len > 4 {
-                    return Ok(match &input[..4] {
-                        "ref " => RefMut(Box::new(input[4..].trim().parse()?)),
-                        "out " => Out(Box::new(input[4..].trim().parse()?)),
-                        _ => return Err(()),
-                    });
+                let parse_one = |t: &str| -> Result<Box<Type>, ()> { Ok(Box::new(t.parse()?)) };
+                let parse_two = |ts: &str| -> Result<(Box<Type>, Box<Type>), ()> {
+                    let [k, v] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Ok((Box::new(k.parse()?), Box::new(v.parse()?)))
+                };
+                if let Some(t) = generic(name, "List") {
+                    List(parse_one(t)?)
+                } else if let Some(t) = generic(name, "IList") {
+                    IList(parse_one(t)?)
+                } else if let Some(t) = generic(name, "IReadOnlyList") {
+                    IReadOnlyList(parse_one(t)?)
+                } else if let Some(t) = generic(name, "IEnumerable") {
+                    IEnumerable(parse_one(t)?)
+                } else if let Some(t) = generic(name, "HashSet") {
+                    HashSet(parse_one(t)?)
+                } else if let Some(ts) = generic(name, "Dictionary") {
+                    let (k, v) = parse_two(ts)?;
+                    Dictionary(k, v)
+                } else if let Some(ts) = generic(name, "IDictionary") {
+                    let (k, v) = parse_two(ts)?;
+                    IDictionary(k, v)
+                } else if let Some(t) = generic(name, "ReadOnlySpan") {
+                    ReadOnlySpan(parse_one(t)?)
+                } else if let Some(t) = generic(name, "Memory") {
+                    Memory(parse_one(t)?)
+                } else if let Some(t) = generic(name, "Task") {
+                    Task(parse_one(t)?)
+                } else if let Some(ts) = generic(name, "ValueTuple") {
+                    ValueTuple(split(ts, ',').into_iter().map(str::parse).try_collect()?)
+                } else {
+                    return Err(());
                 }
+            }
+        })
+    }
+}
 
-                return Err(());
+/// Parses an array type, whose rank specifiers read from the outermost
+/// array inward, as in `int[][,]` for an array of two-dimensional arrays.
+fn parse_array(input: &str) -> Result<Type, ()> {
+    let mut depth = 0usize;
+    let start = input
+        .char_indices()
+        .find(|&(_, c)| {
+            match c {
+                '<' | '(' => depth += 1,
+                '>' | ')' => depth = depth.saturating_sub(1),
+                _ => {}
             }
+            c == '[' && depth == 0
         })
+        .ok_or(())?
+        .0;
+    let (element, mut specifiers) = input.split_at(start);
+    let mut ranks = Vec::new();
+    while !specifiers.is_empty() {
+        let (dimensions, rest) = specifiers
+            .strip_prefix('[')
+            .and_then(|s| s.split_once(']'))
+            .ok_or(())?;
+        if !dimensions.chars().all(|c| c == ',' || c.is_whitespace()) {
+            return Err(());
+        }
+        ranks.push(dimensions.matches(',').count());
+        specifiers = rest.trim_start();
+    }
+    let mut t = element.parse()?;
+    for commas in ranks.into_iter().rev() {
+        t = match NonZeroUsize::new(commas + 1) {
+            Some(rank) if rank.get() > 1 => Type::MultiArray(Box::new(t), rank),
+            _ => Type::Array(Box::new(t)),
+        };
     }
+    Ok(t)
+}
+
+/// Parses a tuple element, which may be named, as in `(int Count, string Name)`.
+fn parse_element(input: &str) -> Result<Type, ()> {
+    input.parse().or_else(|_| match input.rsplit_once(' ') {
+        Some((t, name)) if name.chars().all(|c| c.is_alphanumeric() || c == '_') => t.parse(),
+        _ => Err(()),
+    })
 }
 
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use Type::*;
-        match self {
+        self.fmt_attribute(f)?;
+        self.fmt_with(f, true)
+    }
+}
+
+/// Returns the `MarshalAs` arguments needed for a C type, if any.
+///
+/// See: https://learn.microsoft.com/en-us/dotnet/api/system.runtime.interopservices.unmanagedtype
+#[cfg(feature = "language-c")]
+pub(crate) fn marshal_as(t: &crate::c::Type) -> Option<String> {
+    use crate::c::Type::*;
+    Some(match t {
+        Bool => "UnmanagedType.U1".into(),
+        Ptr(t) if **t == Char => "UnmanagedType.LPUTF8Str".into(),
+        Array(_, Some(n)) => format!("UnmanagedType.ByValArray, SizeConst = {}", n),
+        _ => return None,
+    })
+}
+
+/// A C type rendered as its unmanaged C# equivalent, without any
+/// marshaling attributes.
+#[cfg(feature = "language-c")]
+pub(crate) struct Unmanaged<'a>(pub(crate) &'a crate::c::Type);
+
+#[cfg(feature = "language-c")]
+impl fmt::Display for Unmanaged<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use crate::c::Type::*;
+        match self.0 {
+            // See: https://learn.microsoft.com/en-us/dotnet/standard/native-interop/best-practices#common-windows-data-types
             Void => write!(f, "void"),
-            Object => write!(f, "object"),
-            // Tuple(types) => write!(
-            //     f,
-            //     "({})",
-            //     types
-            //         .into_iter()
-            //         .map(|t| format!("{}", t))
-            //         .collect::<Vec<_>>()
-            //         .join(", ")
-            // ),
             Bool => write!(f, "bool"),
             Float => write!(f, "float"),
             Double => write!(f, "double"),
-            Decimal => write!(f, "decimal"),
-            SByte => write!(f, "sbyte"),
-            Byte => write!(f, "byte"),
-            Short => write!(f, "short"),
-            UShort => write!(f, "ushort"),
-            Int => write!(f, "int"),
-            UInt => write!(f, "uint"),
-            Long => write!(f, "long"),
-            ULong => write!(f, "ulong"),
-            Int128 => write!(f, "System.Int128"),
-            UInt128 => write!(f, "System.UInt128"),
-            NInt => write!(f, "nint"),
-            NUInt => write!(f, "nuint"),
-            Range => write!(f, "System.Range"),
-            Char => write!(f, "char"),
-            String => write!(f, "string"),
-            Array(t) => write!(f, "{}[]", t),
-            List(t) => write!(f, "System.Collections.Generic.List<{}>", t),
-            Dictionary(k, v) => write!(f, "System.Collections.Generic.Dictionary<{}, {}>", k, v),
-            RefMut(t) => write!(f, "ref {}", t),
-            Ptr(t) => write!(f, "const {}*", t),
-            PtrMut(t) => write!(f, "{}*", t),
-            Out(t) => write!(f, "out {}", t),
-            Nullable(t) => write!(f, "{}?", t),
+            Char | SChar | Int8_t => write!(f, "sbyte"),
+            Short | Int16_t => write!(f, "short"),
+            Int | Int32_t => write!(f, "int"),
+            Long => write!(f, "CLong"),
+            LongLong | Int64_t => write!(f, "long"),
+            SSize_t => write!(f, "nint"),
+            UChar | UInt8_t => write!(f, "byte"),
+            UShort | UInt16_t => write!(f, "ushort"),
+            UInt | UInt32_t => write!(f, "uint"),
+            ULong => write!(f, "CULong"),
+            ULongLong | UInt64_t => write!(f, "ulong"),
+            Size_t => write!(f, "nuint"),
+            Array(t, None) => write!(f, "{}*", Unmanaged(t)),
+            Array(t, Some(_)) => write!(f, "{}[]", Unmanaged(t)),
+            Ptr(t) if **t == Char => write!(f, "string"),
+            Ptr(t) | PtrMut(t) if **t == Void => write!(f, "nint"),
+            Ptr(t) | PtrMut(t) => write!(f, "{}*", Unmanaged(t)),
+            #[cfg(feature = "libc")]
+            Time_t => write!(f, "long"),
         }
     }
 }
 
+/// Parses a C# type with marshaling metadata, as in
+/// `[MarshalAs(UnmanagedType.U1)] bool`.
+#[cfg(feature = "language-c")]
+fn parse_marshaled(input: &str) -> Result<crate::c::Type, ()> {
+    use crate::c::Type::*;
+    Ok(match input.split_once(']') {
+        Some((attribute, t)) => match (attribute.trim(), t.trim()) {
+            ("[MarshalAs(UnmanagedType.U1)", "bool") => Bool,
+            ("[MarshalAs(UnmanagedType.LPUTF8Str)", "string") => Ptr(Box::new(Char)),
+            _ => return Err(()),
+        },
+        None => match input {
+            "CLong" => Long,
+            "CULong" => ULong,
+            _ => return Err(()),
+        },
+    })
+}
+
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -242,6 +596,7 @@ impl TryFrom<rust::Type> for Type {
         use Type::*;
         Ok(match input {
             rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => ValueTuple(ts.into_iter().map(Self::try_from).try_collect()?),
             rust::Type::Any => Object,
             rust::Type::Bool => Bool,
             rust::Type::F32 => Float,
@@ -262,8 +617,14 @@ impl TryFrom<rust::Type> for Type {
             rust::Type::Char => Char,
             rust::Type::Str => String,
             rust::Type::String => String,
-            rust::Type::Box(t) => return Self::try_from(*t).map(|t| t),
+            rust::Type::Option(t) => Nullable(Box::new(Self::try_from(*t)?)),
+            rust::Type::Box(t) => return Self::try_from(*t),
+            rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                return Self::try_from(*t)
+            }
+            rust::Type::Weak(_) => return Err(()),
             rust::Type::Vec(t) => return Self::try_from(*t).map(|t| List(Box::new(t))),
+            rust::Type::Set(t) => HashSet(Box::new(Self::try_from(*t)?)),
             rust::Type::Map(k, v) => {
                 Dictionary(Box::new(Self::try_from(*k)?), Box::new(Self::try_from(*v)?))
             }
@@ -272,7 +633,7 @@ impl TryFrom<rust::Type> for Type {
             rust::Type::Ptr(t) => return Self::try_from(*t).map(|t| Ptr(Box::new(t))),
             rust::Type::PtrMut(t) => return Self::try_from(*t).map(|t| PtrMut(Box::new(t))),
             #[cfg(feature = "language-c")]
-            rust::Type::Ffi(_) => return Err(()),
+            rust::Type::Ffi(t) => Ffi(t),
         })
     }
 }
@@ -302,10 +663,25 @@ impl crate::ToRust for Type {
             Range => rust::Type::Range(Box::new(rust::Type::I32)),
             Char => rust::Type::Char,
             String => rust::Type::String,
-            Array(t) | List(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
-            Dictionary(k, v) => rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?)),
+            Guid | DateTime | TimeSpan => return None,
+            Array(t) | List(t) | IList(t) | IReadOnlyList(t) | IEnumerable(t) => {
+                rust::Type::Vec(Box::new(t.to_rust()?))
+            }
+            ReadOnlySpan(t) | Memory(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
+            MultiArray(_, _) => return None,
+            HashSet(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Dictionary(k, v) | IDictionary(k, v) => {
+                rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?))
+            }
+            Task(_) => return None, // no synchronous equivalent in Rust
+            ValueTuple(ts) if ts.is_empty() => rust::Type::Unit,
+            ValueTuple(ts) => {
+                rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?)
+            }
             RefMut(t) | Ptr(t) | PtrMut(t) | Out(t) => rust::Type::Ref(Box::new(t.to_rust()?)),
-            Nullable(_) => return None,
+            Nullable(t) => rust::Type::Option(Box::new(t.to_rust()?)),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
         })
     }
 }
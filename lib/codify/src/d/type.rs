@@ -0,0 +1,338 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{applied, enclosed, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://dlang.org/spec/type.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Void,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Bool,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Byte,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Ubyte,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Short,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Ushort,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Int,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Uint,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Long,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Ulong,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Float,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Double,
+
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Real,
+
+    /// A UTF-8 code unit.
+    ///
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Char,
+
+    /// A UTF-16 code unit.
+    ///
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Wchar,
+
+    /// A UTF-32 code unit, i.e., a Unicode code point.
+    ///
+    /// See: https://dlang.org/spec/type.html#basic-data-types
+    Dchar,
+
+    /// See: https://dlang.org/spec/type.html#size_t
+    #[allow(non_camel_case_types)]
+    Size_t,
+
+    /// See: https://dlang.org/spec/type.html#ptrdiff_t
+    #[allow(non_camel_case_types)]
+    Ptrdiff_t,
+
+    /// See: https://dlang.org/spec/arrays.html#strings
+    String,
+
+    /// See: https://dlang.org/spec/arrays.html#static-arrays
+    StaticArray(Box<Type>, usize),
+
+    /// See: https://dlang.org/spec/arrays.html#dynamic-arrays
+    DynamicArray(Box<Type>),
+
+    /// An associative array, given as its key and value types.
+    ///
+    /// See: https://dlang.org/spec/hash-map.html
+    AssocArray(Box<Type>, Box<Type>),
+
+    /// See: https://dlang.org/phobos/std_typecons.html#Nullable
+    Nullable(Box<Type>),
+
+    /// See: https://dlang.org/phobos/std_typecons.html#Tuple
+    Tuple(Vec<Type>),
+
+    /// See: https://dlang.org/spec/type.html#pointers
+    Ptr(Box<Type>),
+
+    /// See: https://dlang.org/spec/const3.html
+    ConstPtr(Box<Type>),
+
+    /// A C type, using the aliases of `core.stdc.config` and `core.stdc.time`
+    /// where D has no built-in equivalent.
+    ///
+    /// See: https://dlang.org/phobos/core_stdc_config.html
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+/// Returns the template argument(s) of `input` if it is an instantiation of
+/// the template `name`, as in `Nullable!int` or `Tuple!(int, string)`.
+fn instance<'a>(input: &'a str, name: &str) -> Option<&'a str> {
+    let args = input.strip_prefix(name)?.strip_prefix('!')?;
+    Some(enclosed(args, '(', ')').unwrap_or(args))
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        Ok(match input {
+            "void" => Void,
+            "bool" => Bool,
+            "byte" => Byte,
+            "ubyte" => Ubyte,
+            "short" => Short,
+            "ushort" => Ushort,
+            "int" => Int,
+            "uint" => Uint,
+            "long" => Long,
+            "ulong" => Ulong,
+            "float" => Float,
+            "double" => Double,
+            "real" => Real,
+            "char" => Char,
+            "wchar" => Wchar,
+            "dchar" => Dchar,
+            "size_t" => Size_t,
+            "ptrdiff_t" => Ptrdiff_t,
+            "string" => String,
+            #[cfg(feature = "language-c")]
+            "c_long" => Ffi(crate::c::Type::Long),
+            #[cfg(feature = "language-c")]
+            "c_ulong" => Ffi(crate::c::Type::ULong),
+            #[cfg(feature = "language-c")]
+            "c_longlong" | "cpp_longlong" => Ffi(crate::c::Type::LongLong),
+            #[cfg(feature = "language-c")]
+            "c_ulonglong" | "cpp_ulonglong" => Ffi(crate::c::Type::ULongLong),
+            #[cfg(all(feature = "language-c", feature = "libc"))]
+            "time_t" => Ffi(crate::c::Type::Time_t),
+            _ => {
+                if let Some(t) = input
+                    .strip_suffix('*')
+                    .and_then(|t| applied(t, "const", '(', ')'))
+                {
+                    ConstPtr(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_suffix('*') {
+                    Ptr(Box::new(t.parse()?))
+                } else if let Some(t) = instance(input, "Nullable") {
+                    Nullable(Box::new(t.parse()?))
+                } else if let Some(ts) = instance(input, "Tuple") {
+                    Tuple(split(ts, ',').into_iter().map(str::parse).try_collect()?)
+                } else if let Some((t, k)) =
+                    input.strip_suffix(']').and_then(|t| t.rsplit_once('['))
+                {
+                    match k.trim() {
+                        "" => DynamicArray(Box::new(t.parse()?)),
+                        k => match k.parse() {
+                            Ok(n) => StaticArray(Box::new(t.parse()?), n),
+                            Err(_) => AssocArray(Box::new(k.parse()?), Box::new(t.parse()?)),
+                        },
+                    }
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Void => write!(f, "void"),
+            Bool => write!(f, "bool"),
+            Byte => write!(f, "byte"),
+            Ubyte => write!(f, "ubyte"),
+            Short => write!(f, "short"),
+            Ushort => write!(f, "ushort"),
+            Int => write!(f, "int"),
+            Uint => write!(f, "uint"),
+            Long => write!(f, "long"),
+            Ulong => write!(f, "ulong"),
+            Float => write!(f, "float"),
+            Double => write!(f, "double"),
+            Real => write!(f, "real"),
+            Char => write!(f, "char"),
+            Wchar => write!(f, "wchar"),
+            Dchar => write!(f, "dchar"),
+            Size_t => write!(f, "size_t"),
+            Ptrdiff_t => write!(f, "ptrdiff_t"),
+            String => write!(f, "string"),
+            StaticArray(t, n) => write!(f, "{}[{}]", t, n),
+            DynamicArray(t) => write!(f, "{}[]", t),
+            AssocArray(k, v) => write!(f, "{}[{}]", v, k),
+            Nullable(t) => write!(f, "Nullable!({})", t),
+            Tuple(ts) => write!(f, "Tuple!({})", ts.iter().format(", ")),
+            Ptr(t) => write!(f, "{}*", t),
+            ConstPtr(t) => write!(f, "const({})*", t),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://dlang.org/spec/interfaceToC.html#data_type_compat
+                crate::c::Type::Void => write!(f, "void"),
+                crate::c::Type::Bool => write!(f, "bool"),
+                crate::c::Type::Float => write!(f, "float"),
+                crate::c::Type::Double => write!(f, "double"),
+                crate::c::Type::Char => write!(f, "char"),
+                crate::c::Type::SChar => write!(f, "byte"),
+                crate::c::Type::Short => write!(f, "short"),
+                crate::c::Type::Int => write!(f, "int"),
+                crate::c::Type::Long => write!(f, "c_long"),
+                crate::c::Type::LongLong => write!(f, "c_longlong"),
+                crate::c::Type::SSize_t => write!(f, "ptrdiff_t"),
+                crate::c::Type::UChar => write!(f, "ubyte"),
+                crate::c::Type::UShort => write!(f, "ushort"),
+                crate::c::Type::UInt => write!(f, "uint"),
+                crate::c::Type::ULong => write!(f, "c_ulong"),
+                crate::c::Type::ULongLong => write!(f, "c_ulonglong"),
+                crate::c::Type::Size_t => write!(f, "size_t"),
+                crate::c::Type::Int8_t => write!(f, "byte"),
+                crate::c::Type::Int16_t => write!(f, "short"),
+                crate::c::Type::Int32_t => write!(f, "int"),
+                crate::c::Type::Int64_t => write!(f, "long"),
+                crate::c::Type::UInt8_t => write!(f, "ubyte"),
+                crate::c::Type::UInt16_t => write!(f, "ushort"),
+                crate::c::Type::UInt32_t => write!(f, "uint"),
+                crate::c::Type::UInt64_t => write!(f, "ulong"),
+                crate::c::Type::Array(t, None) => write!(f, "{}*", Ffi((**t).clone())),
+                crate::c::Type::Array(t, Some(n)) => write!(f, "{}[{}]", Ffi((**t).clone()), n),
+                crate::c::Type::Ptr(t) => write!(f, "const({})*", Ffi((**t).clone())),
+                crate::c::Type::PtrMut(t) => write!(f, "{}*", Ffi((**t).clone())),
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "time_t"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => Float,
+            rust::Type::F64 => Double,
+            rust::Type::I8 => Byte,
+            rust::Type::I16 => Short,
+            rust::Type::I32 => Int,
+            rust::Type::I64 => Long,
+            rust::Type::Isize => Ptrdiff_t,
+            rust::Type::U8 => Ubyte,
+            rust::Type::U16 => Ushort,
+            rust::Type::U32 => Uint,
+            rust::Type::U64 => Ulong,
+            rust::Type::Usize => Size_t,
+            rust::Type::Char => Dchar,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Nullable(convert(*t)?),
+            rust::Type::Vec(t) => DynamicArray(convert(*t)?),
+            // A set is conventionally an associative array of `bool` values:
+            rust::Type::Set(t) => AssocArray(convert(*t)?, Box::new(Bool)),
+            rust::Type::Map(k, v) => AssocArray(convert(*k)?, convert(*v)?),
+            rust::Type::Ptr(t) => ConstPtr(convert(*t)?),
+            rust::Type::PtrMut(t) => Ptr(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Void => rust::Type::Unit,
+            Bool => rust::Type::Bool,
+            Byte => rust::Type::I8,
+            Ubyte | Char => rust::Type::U8,
+            Short => rust::Type::I16,
+            Ushort | Wchar => rust::Type::U16,
+            Int => rust::Type::I32,
+            Uint => rust::Type::U32,
+            Long => rust::Type::I64,
+            Ulong => rust::Type::U64,
+            Float => rust::Type::F32,
+            Double => rust::Type::F64,
+            Real => return None, // no stable equivalent in Rust
+            Dchar => rust::Type::Char,
+            Size_t => rust::Type::Usize,
+            Ptrdiff_t => rust::Type::Isize,
+            String => rust::Type::String,
+            #[cfg(feature = "language-c")]
+            StaticArray(t, n) => rust::Type::Ffi(crate::c::Type::Array(
+                Box::new(crate::c::Type::try_from(t.to_rust()?).ok()?),
+                Some(*n),
+            )),
+            #[cfg(not(feature = "language-c"))]
+            StaticArray(_, _) => return None,
+            DynamicArray(t) => rust::Type::Vec(convert(t)?),
+            AssocArray(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Nullable(t) => rust::Type::Option(convert(t)?),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Ptr(t) => rust::Type::PtrMut(convert(t)?),
+            ConstPtr(t) => rust::Type::Ptr(convert(t)?),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -1,19 +1,108 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    parse::{enclosed, generic, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
     rust,
 };
+use itertools::Itertools;
 
 /// See: https://dart.dev/language/built-in-types
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
+    /// See: https://dart.dev/language/functions#return-values
+    Void,
+
+    /// See: https://dart.dev/language/type-system#type-inference
+    Dynamic,
+
+    /// See: https://api.dart.dev/stable/dart-core/Object-class.html
+    Object,
+
     /// See: https://dart.dev/language/built-in-types#booleans
     Bool,
 
+    /// See: https://api.dart.dev/stable/dart-core/int-class.html
+    Int,
+
     /// See: https://api.dart.dev/stable/dart-core/double-class.html
     Double,
+
+    /// See: https://api.dart.dev/stable/dart-core/num-class.html
+    Num,
+
+    /// See: https://api.dart.dev/stable/dart-core/String-class.html
+    String,
+
+    /// See: https://api.dart.dev/stable/dart-core/List-class.html
+    List(Box<Type>),
+
+    /// See: https://api.dart.dev/stable/dart-core/Map-class.html
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://api.dart.dev/stable/dart-core/Set-class.html
+    Set(Box<Type>),
+
+    /// See: https://dart.dev/null-safety/understanding-null-safety
+    Nullable(Box<Type>),
+
+    /// See: https://dart.dev/language/records
+    Record(Vec<Type>),
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Int8List-class.html
+    Int8List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Int16List-class.html
+    Int16List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Int32List-class.html
+    Int32List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Int64List-class.html
+    Int64List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Uint8List-class.html
+    Uint8List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Uint16List-class.html
+    Uint16List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Uint32List-class.html
+    Uint32List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Uint64List-class.html
+    Uint64List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Float32List-class.html
+    Float32List,
+
+    /// See: https://api.dart.dev/stable/dart-typed_data/Float64List-class.html
+    Float64List,
+
+    /// See: https://api.dart.dev/stable/dart-ffi/dart-ffi-library.html
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl Type {
+    /// Returns the Dart type that corresponds to this type on the Dart side
+    /// of a `NativeFunction` typedef pair, as in `int` for `Int32`.
+    pub fn to_dart(&self) -> Type {
+        match self {
+            #[cfg(feature = "language-c")]
+            Type::Ffi(t) => match t {
+                crate::c::Type::Void => Type::Void,
+                crate::c::Type::Bool => Type::Bool,
+                crate::c::Type::Float | crate::c::Type::Double => Type::Double,
+                crate::c::Type::Array(_, _)
+                | crate::c::Type::Ptr(_)
+                | crate::c::Type::PtrMut(_) => self.clone(),
+                _ => Type::Int,
+            },
+            _ => self.clone(),
+        }
+    }
 }
 
 impl core::str::FromStr for Type {
@@ -21,26 +110,203 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
+        let input = input.trim();
         Ok(match input {
+            "void" => Void,
+            "dynamic" => Dynamic,
+            "Object" => Object,
             "bool" => Bool,
+            "int" => Int,
             "double" => Double,
-            _ => return Err(()),
+            "num" => Num,
+            "String" => String,
+            "Int8List" => Int8List,
+            "Int16List" => Int16List,
+            "Int32List" => Int32List,
+            "Int64List" => Int64List,
+            "Uint8List" => Uint8List,
+            "Uint16List" => Uint16List,
+            "Uint32List" => Uint32List,
+            "Uint64List" => Uint64List,
+            "Float32List" => Float32List,
+            "Float64List" => Float64List,
+            _ => {
+                if let Some(t) = input.strip_suffix('?') {
+                    Nullable(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "List") {
+                    List(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Map") {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = generic(input, "Set") {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(t) = enclosed(input, '(', ')') {
+                    let mut types = split(t, ',');
+                    if types.len() == 1 {
+                        return t.parse(); // a parenthesized type, as in `(int)`
+                    }
+                    if types.last() == Some(&"") {
+                        types.pop(); // a trailing comma, as in `(int,)`
+                    }
+                    Record(types.into_iter().map(str::parse).try_collect()?)
+                } else {
+                    #[cfg(feature = "language-c")]
+                    return parse_native(input).map(Ffi);
+                    #[cfg(not(feature = "language-c"))]
+                    return Err(());
+                }
+            }
         })
     }
 }
 
+/// See: https://api.dart.dev/stable/dart-ffi/dart-ffi-library.html
+#[cfg(feature = "language-c")]
+fn parse_native(input: &str) -> Result<crate::c::Type, ()> {
+    use crate::c::Type::*;
+    Ok(match input.trim() {
+        "Void" => Void,
+        "Bool" => Bool,
+        "Float" => Float,
+        "Double" => Double,
+        "Char" => Char,
+        "SignedChar" => SChar,
+        "Short" => Short,
+        "Int" => Int,
+        "Long" => Long,
+        "LongLong" => LongLong,
+        "IntPtr" => SSize_t,
+        "UnsignedChar" => UChar,
+        "UnsignedShort" => UShort,
+        "UnsignedInt" => UInt,
+        "UnsignedLong" => ULong,
+        "UnsignedLongLong" => ULongLong,
+        "Size" | "UintPtr" => Size_t,
+        "Int8" => Int8_t,
+        "Int16" => Int16_t,
+        "Int32" => Int32_t,
+        "Int64" => Int64_t,
+        "Uint8" => UInt8_t,
+        "Uint16" => UInt16_t,
+        "Uint32" => UInt32_t,
+        "Uint64" => UInt64_t,
+        input => {
+            if let Some(t) = generic(input, "Pointer") {
+                match t {
+                    "Utf8" => Ptr(Box::new(Char)),
+                    t => PtrMut(Box::new(parse_native(t)?)),
+                }
+            } else if let Some(t) = generic(input, "Array") {
+                Array(Box::new(parse_native(t)?), None)
+            } else if let Some(input) = input.strip_prefix("@Array(") {
+                // Nested arrays share one annotation, as in
+                // `@Array(2, 3) Array<Array<Int32>>`:
+                let (dimensions, mut t) = input.split_once(')').ok_or(())?;
+                let dimensions: Vec<usize> = split(dimensions, ',')
+                    .into_iter()
+                    .map(|n| n.parse().map_err(|_| ()))
+                    .try_collect()?;
+                for _ in &dimensions {
+                    t = generic(t.trim(), "Array").ok_or(())?;
+                }
+                let mut t = parse_native(t)?;
+                for n in dimensions.into_iter().rev() {
+                    t = Array(Box::new(t), Some(n));
+                }
+                t
+            } else {
+                return Err(());
+            }
+        }
+    })
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Type::*;
         match self {
+            Void => write!(f, "void"),
+            Dynamic => write!(f, "dynamic"),
+            Object => write!(f, "Object"),
             Bool => write!(f, "bool"),
+            Int => write!(f, "int"),
             Double => write!(f, "double"),
+            Num => write!(f, "num"),
+            String => write!(f, "String"),
+            List(t) => write!(f, "List<{}>", t),
+            Map(k, v) => write!(f, "Map<{}, {}>", k, v),
+            Set(t) => write!(f, "Set<{}>", t),
+            Nullable(t) => write!(f, "{}?", t),
+            Record(ts) if ts.len() == 1 => write!(f, "({},)", ts[0]),
+            Record(ts) => write!(f, "({})", ts.iter().format(", ")),
+            Int8List => write!(f, "Int8List"),
+            Int16List => write!(f, "Int16List"),
+            Int32List => write!(f, "Int32List"),
+            Int64List => write!(f, "Int64List"),
+            Uint8List => write!(f, "Uint8List"),
+            Uint16List => write!(f, "Uint16List"),
+            Uint32List => write!(f, "Uint32List"),
+            Uint64List => write!(f, "Uint64List"),
+            Float32List => write!(f, "Float32List"),
+            Float64List => write!(f, "Float64List"),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://api.dart.dev/stable/dart-ffi/dart-ffi-library.html
+                crate::c::Type::Void => write!(f, "Void"),
+                crate::c::Type::Bool => write!(f, "Bool"),
+                crate::c::Type::Float => write!(f, "Float"),
+                crate::c::Type::Double => write!(f, "Double"),
+                crate::c::Type::Char => write!(f, "Char"),
+                crate::c::Type::SChar => write!(f, "SignedChar"),
+                crate::c::Type::Short => write!(f, "Short"),
+                crate::c::Type::Int => write!(f, "Int"),
+                crate::c::Type::Long => write!(f, "Long"),
+                crate::c::Type::LongLong => write!(f, "LongLong"),
+                crate::c::Type::SSize_t => write!(f, "IntPtr"),
+                crate::c::Type::UChar => write!(f, "UnsignedChar"),
+                crate::c::Type::UShort => write!(f, "UnsignedShort"),
+                crate::c::Type::UInt => write!(f, "UnsignedInt"),
+                crate::c::Type::ULong => write!(f, "UnsignedLong"),
+                crate::c::Type::ULongLong => write!(f, "UnsignedLongLong"),
+                crate::c::Type::Size_t => write!(f, "Size"),
+                crate::c::Type::Int8_t => write!(f, "Int8"),
+                crate::c::Type::Int16_t => write!(f, "Int16"),
+                crate::c::Type::Int32_t => write!(f, "Int32"),
+                crate::c::Type::Int64_t => write!(f, "Int64"),
+                crate::c::Type::UInt8_t => write!(f, "Uint8"),
+                crate::c::Type::UInt16_t => write!(f, "Uint16"),
+                crate::c::Type::UInt32_t => write!(f, "Uint32"),
+                crate::c::Type::UInt64_t => write!(f, "Uint64"),
+                // See: https://api.dart.dev/stable/dart-ffi/Array-class.html
+                crate::c::Type::Array(_, Some(_)) => {
+                    let (mut element, mut dimensions) = (t, Vec::new());
+                    while let crate::c::Type::Array(t, Some(n)) = element {
+                        dimensions.push(n);
+                        element = t;
+                    }
+                    write!(f, "@Array({}) ", dimensions.iter().format(", "))?;
+                    dimensions.iter().try_for_each(|_| write!(f, "Array<"))?;
+                    write!(f, "{}", Ffi(element.clone()))?;
+                    dimensions.iter().try_for_each(|_| write!(f, ">"))
+                }
+                // An array of unknown length is passed as a pointer:
+                crate::c::Type::Array(t, None) => write!(f, "Pointer<{}>", Ffi((**t).clone())),
+                // See: https://pub.dev/documentation/ffi/latest/ffi/Utf8-final-class.html
+                crate::c::Type::Ptr(t) if **t == crate::c::Type::Char => write!(f, "Pointer<Utf8>"),
+                crate::c::Type::Ptr(t) | crate::c::Type::PtrMut(t) => {
+                    write!(f, "Pointer<{}>", Ffi((**t).clone()))
+                }
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "Int64"),
+            },
         }
     }
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -51,8 +317,42 @@ impl TryFrom<rust::Type> for Type {
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
         use Type::*;
         Ok(match input {
+            rust::Type::Any => Dynamic,
+            rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => Record(ts.into_iter().map(Self::try_from).try_collect()?),
             rust::Type::Bool => Bool,
             rust::Type::F32 | rust::Type::F64 => Double,
+            rust::Type::I8 | rust::Type::U8 => Int,
+            rust::Type::I16 | rust::Type::U16 => Int,
+            rust::Type::I32 | rust::Type::U32 => Int,
+            rust::Type::I64 | rust::Type::U64 => Int,
+            rust::Type::Isize | rust::Type::Usize => Int,
+            rust::Type::Char | rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Nullable(Box::new(Self::try_from(*t)?)),
+            rust::Type::Box(t) | rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                Self::try_from(*t)?
+            }
+            rust::Type::Vec(t) => match *t {
+                rust::Type::I8 => Int8List,
+                rust::Type::I16 => Int16List,
+                rust::Type::I32 => Int32List,
+                rust::Type::I64 => Int64List,
+                rust::Type::U8 => Uint8List,
+                rust::Type::U16 => Uint16List,
+                rust::Type::U32 => Uint32List,
+                rust::Type::U64 => Uint64List,
+                rust::Type::F32 => Float32List,
+                rust::Type::F64 => Float64List,
+                t => List(Box::new(Self::try_from(t)?)),
+            },
+            rust::Type::Set(t) => Set(Box::new(Self::try_from(*t)?)),
+            rust::Type::Map(k, v) => {
+                Map(Box::new(Self::try_from(*k)?), Box::new(Self::try_from(*v)?))
+            }
+            #[cfg(feature = "language-c")]
+            rust::Type::Ptr(_) | rust::Type::PtrMut(_) => Ffi(crate::c::Type::try_from(input)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
             _ => return Err(()),
         })
     }
@@ -62,8 +362,30 @@ impl crate::ToRust for Type {
     fn to_rust(&self) -> Option<rust::Type> {
         use Type::*;
         Some(match self {
+            Void => rust::Type::Unit,
+            Dynamic | Object => rust::Type::Any,
             Bool => rust::Type::Bool,
-            Double => rust::Type::F64,
+            Int => rust::Type::I64,
+            Double | Num => rust::Type::F64,
+            String => rust::Type::String,
+            List(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
+            Map(k, v) => rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?)),
+            Set(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Nullable(t) => rust::Type::Option(Box::new(t.to_rust()?)),
+            Record(ts) if ts.is_empty() => rust::Type::Unit,
+            Record(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Int8List => rust::Type::Vec(Box::new(rust::Type::I8)),
+            Int16List => rust::Type::Vec(Box::new(rust::Type::I16)),
+            Int32List => rust::Type::Vec(Box::new(rust::Type::I32)),
+            Int64List => rust::Type::Vec(Box::new(rust::Type::I64)),
+            Uint8List => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Uint16List => rust::Type::Vec(Box::new(rust::Type::U16)),
+            Uint32List => rust::Type::Vec(Box::new(rust::Type::U32)),
+            Uint64List => rust::Type::Vec(Box::new(rust::Type::U64)),
+            Float32List => rust::Type::Vec(Box::new(rust::Type::F32)),
+            Float64List => rust::Type::Vec(Box::new(rust::Type::F64)),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
         })
     }
 }
@@ -0,0 +1,223 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{applied, enclosed, split},
+    prelude::{fmt, format, vec, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://hexdocs.pm/elixir/typespecs.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    Any,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    Atom,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#literals
+    Nil,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#built-in-types
+    Boolean,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    Integer,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    NonNegInteger,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    PosInteger,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    Float,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#built-in-types
+    Binary,
+
+    /// See: https://hexdocs.pm/elixir/String.html#t:t/0
+    String,
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#literals
+    List(Box<Type>),
+
+    /// Any map whatsoever, which is the only typespec accurate for a Rust map
+    /// that may be empty.
+    ///
+    /// See: https://hexdocs.pm/elixir/typespecs.html#basic-types
+    AnyMap,
+
+    /// A map with at least one pair of the given key and value types.
+    ///
+    /// See: https://hexdocs.pm/elixir/typespecs.html#literals
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#literals
+    Tuple(Vec<Type>),
+
+    /// See: https://hexdocs.pm/elixir/typespecs.html#the-typespec-language
+    Union(Vec<Type>),
+}
+
+/// Splits `input` at its first `=>` that is not nested inside brackets, as
+/// in `atom() => [integer()]`.
+fn arrow(input: &str) -> Option<(&str, &str)> {
+    let mut depth = 0isize;
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' | '[' | '{' => depth += 1,
+            ')' | ']' | '}' => depth -= 1,
+            '=' if depth == 0 && input[i..].starts_with("=>") => {
+                return Some((input[..i].trim(), input[i + 2..].trim()));
+            }
+            _ => {}
+        }
+    }
+    None
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let variants = split(input, '|');
+        if variants.len() > 1 {
+            return Ok(Union(variants.into_iter().map(str::parse).try_collect()?));
+        }
+        Ok(match input {
+            "any()" | "term()" => Any,
+            "atom()" => Atom,
+            "nil" => Nil,
+            "boolean()" => Boolean,
+            "integer()" => Integer,
+            "non_neg_integer()" => NonNegInteger,
+            "pos_integer()" => PosInteger,
+            "float()" => Float,
+            "binary()" => Binary,
+            "String.t()" => String,
+            "map()" => AnyMap,
+            _ => {
+                if let Some(t) = enclosed(input, '[', ']') {
+                    List(Box::new(t.parse()?))
+                } else if let Some(t) = applied(input, "list", '(', ')') {
+                    List(Box::new(t.parse()?))
+                } else if let Some(kv) = input.strip_prefix('%').and_then(|t| enclosed(t, '{', '}'))
+                {
+                    let (k, v) = arrow(kv).ok_or(())?;
+                    let k = ["optional", "required"]
+                        .iter()
+                        .find_map(|q| enclosed(k.strip_prefix(q)?, '(', ')'))
+                        .unwrap_or(k);
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(ts) = enclosed(input, '{', '}') {
+                    match ts {
+                        "" => Tuple(Vec::new()),
+                        ts => Tuple(split(ts, ',').into_iter().map(str::parse).try_collect()?),
+                    }
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Any => write!(f, "any()"),
+            Atom => write!(f, "atom()"),
+            Nil => write!(f, "nil"),
+            Boolean => write!(f, "boolean()"),
+            Integer => write!(f, "integer()"),
+            NonNegInteger => write!(f, "non_neg_integer()"),
+            PosInteger => write!(f, "pos_integer()"),
+            Float => write!(f, "float()"),
+            Binary => write!(f, "binary()"),
+            String => write!(f, "String.t()"),
+            List(t) => write!(f, "[{}]", t),
+            AnyMap => write!(f, "map()"),
+            Map(k, v) => write!(f, "%{{{} => {}}}", k, v),
+            Tuple(ts) => write!(f, "{{{}}}", ts.iter().format(", ")),
+            Union(ts) => write!(f, "{}", ts.iter().format(" | ")),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+/// Follows the encodings of Rustler, the library for writing Erlang NIFs
+/// in Rust.
+///
+/// See: https://docs.rs/rustler/latest/rustler/types/index.html
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Tuple(Vec::new()),
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Boolean,
+            rust::Type::F32 | rust::Type::F64 => Float,
+            rust::Type::I8 | rust::Type::I16 | rust::Type::I32 | rust::Type::I64 => Integer,
+            rust::Type::I128 | rust::Type::Isize => Integer,
+            rust::Type::U8 | rust::Type::U16 | rust::Type::U32 | rust::Type::U64 => NonNegInteger,
+            rust::Type::U128 | rust::Type::Usize => NonNegInteger,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Vec(t) if *t == rust::Type::U8 => Binary,
+            rust::Type::Vec(t) => List(convert(*t)?),
+            rust::Type::Option(t) => match Self::try_from(*t)? {
+                Union(ts) if ts.contains(&Nil) => Union(ts), // already nilable
+                t => Union(vec![t, Nil]),
+            },
+            rust::Type::Map(k, v) => {
+                // Check that the key and value types are representable:
+                Self::try_from(*k)?;
+                Self::try_from(*v)?;
+                AnyMap
+            }
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Any => rust::Type::Any,
+            Boolean => rust::Type::Bool,
+            Integer => rust::Type::I64,
+            NonNegInteger | PosInteger => rust::Type::U64,
+            Float => rust::Type::F64,
+            Binary => rust::Type::Vec(Box::new(rust::Type::U8)),
+            String => rust::Type::String,
+            List(t) => rust::Type::Vec(convert(t)?),
+            AnyMap => rust::Type::Map(Box::new(rust::Type::Any), Box::new(rust::Type::Any)),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Union(ts) => match &ts[..] {
+                [t, Nil] | [Nil, t] => rust::Type::Option(convert(t)?),
+                _ => return None,
+            },
+            Atom | Nil => return None,
+        })
+    }
+}
+
+impl crate::Type for Type {}
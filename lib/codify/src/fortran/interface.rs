@@ -0,0 +1,76 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::{c, prelude::fmt};
+use itertools::Itertools;
+
+/// A Fortran interface block declaring a C function, with the arguments
+/// passed by value or by reference as the C parameter types require.
+///
+/// See: https://gcc.gnu.org/onlinedocs/gfortran/Interoperable-Subroutines-and-Functions.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Interface(pub c::Function);
+
+/// A dummy argument declaration for a C parameter type, as in
+/// `integer(c_int), value, intent(in)`.
+struct Param<'a>(&'a c::Type);
+
+impl fmt::Display for Param<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use c::Type::*;
+        match self.0 {
+            // A C string is a NUL-terminated array of characters:
+            Ptr(t) if **t == Char => {
+                write!(f, "{}, dimension(*), intent(in)", Type::Ffi(Char))
+            }
+            // A pointer to a scalar is the address of the actual argument:
+            Ptr(t) if is_scalar(t) => write!(f, "{}, intent(in)", Type::Ffi((**t).clone())),
+            PtrMut(t) if is_scalar(t) => {
+                write!(f, "{}, intent(inout)", Type::Ffi((**t).clone()))
+            }
+            // Any other pointer is passed along as an opaque `c_ptr`:
+            Ptr(_) | PtrMut(_) => write!(f, "{}, value, intent(in)", Type::Ffi(self.0.clone())),
+            // An array parameter decays into a pointer to its first element:
+            Array(_, _) => write!(f, "{}, intent(inout)", Type::Ffi(self.0.clone())),
+            t => write!(f, "{}, value, intent(in)", Type::Ffi(t.clone())),
+        }
+    }
+}
+
+fn is_scalar(t: &c::Type) -> bool {
+    use c::Type::*;
+    !matches!(t, Void | Array(_, _) | Ptr(_) | PtrMut(_))
+}
+
+impl fmt::Display for Interface {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let function = &self.0;
+        let procedure = match function.result {
+            c::Type::Void => "subroutine",
+            _ => "function",
+        };
+        let args =
+            (0..function.params.len()).format_with(", ", |i, f| f(&format_args!("arg{}", i)));
+        writeln!(f, "interface")?;
+        writeln!(
+            f,
+            "  {} {}({}) bind(c, name=\"{}\")",
+            procedure, function.name, args, function.name
+        )?;
+        writeln!(f, "    use, intrinsic :: iso_c_binding")?;
+        for (i, param) in function.params.iter().enumerate() {
+            writeln!(f, "    {} :: arg{}", Param(param), i)?;
+        }
+        if function.result != c::Type::Void {
+            writeln!(
+                f,
+                "    {} :: {}",
+                Type::Ffi(function.result.clone()),
+                function.name
+            )?;
+        }
+        writeln!(f, "  end {} {}", procedure, function.name)?;
+        write!(f, "end interface")
+    }
+}
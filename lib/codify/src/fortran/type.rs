@@ -0,0 +1,254 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    c,
+    parse::{applied, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://fortranwiki.org/fortran/show/Intrinsic+types
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// An integer of the default kind.
+    ///
+    /// See: https://fortranwiki.org/fortran/show/integer
+    Integer,
+
+    /// A real of the default kind.
+    ///
+    /// See: https://fortranwiki.org/fortran/show/real
+    Real,
+
+    /// See: https://fortranwiki.org/fortran/show/real
+    DoublePrecision,
+
+    /// See: https://fortranwiki.org/fortran/show/complex
+    Complex,
+
+    /// A logical of the default kind, which is not interoperable with C's
+    /// `bool`.
+    ///
+    /// See: https://fortranwiki.org/fortran/show/logical
+    Logical,
+
+    /// See: https://fortranwiki.org/fortran/show/character
+    Character,
+
+    /// See: https://fortranwiki.org/fortran/show/iso_c_binding
+    CFunPtr,
+
+    /// An array of the given extent, or of assumed size if `None`.
+    ///
+    /// See: https://fortranwiki.org/fortran/show/dimension
+    Array(Box<Type>, Option<usize>),
+
+    /// A C type, as declared with the kinds of the `iso_c_binding` module,
+    /// as in `integer(c_int)`, `real(c_double)`, and `type(c_ptr)`.
+    ///
+    /// See: https://gcc.gnu.org/onlinedocs/gfortran/Interoperable-Subroutines-and-Functions.html
+    Ffi(c::Type),
+}
+
+/// Returns the `iso_c_binding` kind declaring the C type `t`, as the
+/// intrinsic type and kind parameter, or `None` if `t` isn't a scalar.
+///
+/// Fortran has no unsigned integers, so an unsigned C type is declared with
+/// the kind of its signed counterpart.
+///
+/// See: https://gcc.gnu.org/onlinedocs/gfortran/ISO_005fC_005fBINDING.html
+fn kind(t: &c::Type) -> Option<(&'static str, &'static str)> {
+    use c::Type::*;
+    Some(match t {
+        Int | UInt => ("integer", "c_int"),
+        Short | UShort => ("integer", "c_short"),
+        Long | ULong => ("integer", "c_long"),
+        LongLong | ULongLong => ("integer", "c_long_long"),
+        SChar | UChar => ("integer", "c_signed_char"),
+        Size_t => ("integer", "c_size_t"),
+        SSize_t => ("integer", "c_ptrdiff_t"),
+        Int8_t | UInt8_t => ("integer", "c_int8_t"),
+        Int16_t | UInt16_t => ("integer", "c_int16_t"),
+        Int32_t | UInt32_t => ("integer", "c_int32_t"),
+        Int64_t | UInt64_t => ("integer", "c_int64_t"),
+        #[cfg(feature = "libc")]
+        Time_t => ("integer", "c_int64_t"),
+        Float => ("real", "c_float"),
+        Double => ("real", "c_double"),
+        Bool => ("logical", "c_bool"),
+        Char => ("character", "c_char"),
+        Void | Array(_, _) | Ptr(_) | PtrMut(_) => return None,
+    })
+}
+
+/// The C types that parsing a kind yields, which are the signed ones where
+/// a kind declares both a signed and an unsigned type.
+const SCALARS: &[c::Type] = &[
+    c::Type::Int,
+    c::Type::Short,
+    c::Type::Long,
+    c::Type::LongLong,
+    c::Type::SChar,
+    c::Type::Size_t,
+    c::Type::SSize_t,
+    c::Type::Int8_t,
+    c::Type::Int16_t,
+    c::Type::Int32_t,
+    c::Type::Int64_t,
+    c::Type::Float,
+    c::Type::Double,
+    c::Type::Bool,
+    c::Type::Char,
+];
+
+impl Type {
+    /// Returns the element type of this type and its dimensions, in
+    /// Fortran's column-major order, as in the `integer(c_int)` and `[3, 2]`
+    /// of C's `int[2][3]`.
+    fn dimensions(&self) -> (Type, Vec<Option<usize>>) {
+        let (mut t, mut dimensions) = (self.clone(), Vec::new());
+        loop {
+            t = match t {
+                Type::Array(t, n) => {
+                    dimensions.push(n);
+                    *t
+                }
+                Type::Ffi(c::Type::Array(t, n)) => {
+                    dimensions.push(n);
+                    Type::Ffi(*t)
+                }
+                t => {
+                    dimensions.reverse();
+                    return (t, dimensions);
+                }
+            }
+        }
+    }
+
+    /// Converts this type into the C type it interoperates with, if any,
+    /// which excludes the intrinsic types of default kind.
+    pub fn to_c(&self) -> Option<c::Type> {
+        match self {
+            Type::Ffi(t) => Some(t.clone()),
+            Type::Array(t, n) => Some(c::Type::Array(Box::new(t.to_c()?), *n)),
+            _ => None,
+        }
+    }
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let attributes = split(input, ',');
+        if let [t, attributes @ ..] = &attributes[..] {
+            if let Some(extents) = attributes
+                .iter()
+                .find_map(|a| applied(a, "dimension", '(', ')'))
+            {
+                let mut t: Type = t.parse()?;
+                for extent in split(extents, ',') {
+                    let n = match extent {
+                        "*" => None,
+                        n => Some(n.parse().map_err(|_| ())?),
+                    };
+                    t = match t {
+                        Ffi(t) => Ffi(c::Type::Array(Box::new(t), n)),
+                        t => Array(Box::new(t), n),
+                    };
+                }
+                return Ok(t);
+            }
+        }
+        Ok(match input {
+            "integer" => Integer,
+            "real" => Real,
+            "double precision" => DoublePrecision,
+            "complex" => Complex,
+            "logical" => Logical,
+            "character" => Character,
+            "type(c_ptr)" => Ffi(c::Type::PtrMut(Box::new(c::Type::Void))),
+            "type(c_funptr)" => CFunPtr,
+            "type(*)" => Ffi(c::Type::Void),
+            _ => {
+                let (intrinsic, parameter) = input.split_once('(').ok_or(())?;
+                let parameter = parameter.strip_suffix(')').ok_or(())?;
+                let parameter = parameter.strip_prefix("kind=").unwrap_or(parameter);
+                let declared = Some((intrinsic.trim(), parameter.trim()));
+                let t = SCALARS.iter().find(|t| kind(t) == declared).ok_or(())?;
+                Ffi(t.clone())
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Integer => write!(f, "integer"),
+            Real => write!(f, "real"),
+            DoublePrecision => write!(f, "double precision"),
+            Complex => write!(f, "complex"),
+            Logical => write!(f, "logical"),
+            Character => write!(f, "character"),
+            CFunPtr => write!(f, "type(c_funptr)"),
+            Array(_, _) | Ffi(c::Type::Array(_, _)) => {
+                let (t, dimensions) = self.dimensions();
+                let dimensions = dimensions.iter().format_with(", ", |n, f| match n {
+                    Some(n) => f(n),
+                    None => f(&"*"),
+                });
+                write!(f, "{}, dimension({})", t, dimensions)
+            }
+            Ffi(c::Type::Ptr(_) | c::Type::PtrMut(_)) => write!(f, "type(c_ptr)"),
+            Ffi(t) => match kind(t) {
+                Some(("character", kind)) => write!(f, "character(kind={})", kind),
+                Some((intrinsic, kind)) => write!(f, "{}({})", intrinsic, kind),
+                // Only `void` remains, as pointers and arrays are matched above:
+                None => write!(f, "type(*)"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        Ok(match input {
+            // Rust's `char` is a Unicode code point, not a C `char`:
+            rust::Type::Char => return Err(()),
+            rust::Type::Ffi(t) => Ffi(t),
+            t => Ffi(c::Type::try_from(t)?),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        Some(match self {
+            Integer => rust::Type::I32,
+            Real => rust::Type::F32,
+            DoublePrecision => rust::Type::F64,
+            Logical => rust::Type::Bool,
+            Complex | Character | CFunPtr => return None,
+            Array(t, n) => rust::Type::Ffi(c::Type::Array(Box::new(t.to_c()?), *n)),
+            Ffi(t) => crate::ToRust::to_rust(t)?,
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -1,22 +1,67 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    prelude::{fmt, format, Box, Cow, Named},
     rust,
 };
 
 /// See: https://go.dev/ref/spec#Types
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
     /// See: https://go.dev/ref/spec#Boolean_types
     Bool,
 
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Int,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Int8,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Int16,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Int32,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Int64,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Uint,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Uint8,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Uint16,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Uint32,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Uint64,
+
     /// See: https://go.dev/ref/spec#Numeric_types
     Float32,
 
     /// See: https://go.dev/ref/spec#Numeric_types
     Float64,
+
+    /// See: https://go.dev/ref/spec#Numeric_types
+    Rune,
+
+    /// See: https://go.dev/ref/spec#String_types
+    String,
+
+    /// See: https://go.dev/ref/spec#Slice_types
+    Slice(Box<Type>),
+
+    /// See: https://go.dev/ref/spec#Map_types
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://go.dev/ref/spec#Pointer_types
+    Pointer(Box<Type>),
 }
 
 impl core::str::FromStr for Type {
@@ -24,11 +69,48 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
+        let input = input.trim();
         Ok(match input {
             "bool" => Bool,
+            "int" => Int,
+            "int8" => Int8,
+            "int16" => Int16,
+            "int32" => Int32,
+            "int64" => Int64,
+            "uint" => Uint,
+            "uint8" | "byte" => Uint8,
+            "uint16" => Uint16,
+            "uint32" => Uint32,
+            "uint64" => Uint64,
             "float32" => Float32,
             "float64" => Float64,
-            _ => return Err(()),
+            "rune" => Rune,
+            "string" => String,
+            _ => {
+                if let Some(t) = input.strip_prefix("[]") {
+                    Slice(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix('*') {
+                    Pointer(Box::new(t.parse()?))
+                } else if let Some(kv) = input.strip_prefix("map[") {
+                    // Find the bracket closing the key type, as in `map[[2]int]bool`:
+                    let mut depth = 1usize;
+                    let close = kv.char_indices().find_map(|(i, c)| {
+                        match c {
+                            '[' => depth += 1,
+                            ']' => depth -= 1,
+                            _ => {}
+                        }
+                        (depth == 0).then_some(i)
+                    });
+                    let close = close.ok_or(())?;
+                    Map(
+                        Box::new(kv[..close].parse()?),
+                        Box::new(kv[close + 1..].parse()?),
+                    )
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
@@ -38,14 +120,29 @@ impl fmt::Display for Type {
         use Type::*;
         match self {
             Bool => write!(f, "bool"),
+            Int => write!(f, "int"),
+            Int8 => write!(f, "int8"),
+            Int16 => write!(f, "int16"),
+            Int32 => write!(f, "int32"),
+            Int64 => write!(f, "int64"),
+            Uint => write!(f, "uint"),
+            Uint8 => write!(f, "uint8"),
+            Uint16 => write!(f, "uint16"),
+            Uint32 => write!(f, "uint32"),
+            Uint64 => write!(f, "uint64"),
             Float32 => write!(f, "float32"),
             Float64 => write!(f, "float64"),
+            Rune => write!(f, "rune"),
+            String => write!(f, "string"),
+            Slice(t) => write!(f, "[]{}", t),
+            Map(k, v) => write!(f, "map[{}]{}", k, v),
+            Pointer(t) => write!(f, "*{}", t),
         }
     }
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -55,10 +152,29 @@ impl TryFrom<rust::Type> for Type {
 
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
         use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
         Ok(match input {
             rust::Type::Bool => Bool,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::Isize => Int,
+            rust::Type::U8 => Uint8,
+            rust::Type::U16 => Uint16,
+            rust::Type::U32 => Uint32,
+            rust::Type::U64 => Uint64,
+            rust::Type::Usize => Uint,
             rust::Type::F32 => Float32,
             rust::Type::F64 => Float64,
+            rust::Type::Char => Rune,
+            rust::Type::Str | rust::Type::String => String,
+            // A nil pointer is the conventional absence of a value:
+            rust::Type::Option(t) | rust::Type::Box(t) => Pointer(convert(*t)?),
+            rust::Type::Vec(t) => Slice(convert(*t)?),
+            // A set is conventionally a map to `bool` values:
+            rust::Type::Set(t) => Map(convert(*t)?, Box::new(Bool)),
+            rust::Type::Map(k, v) => Map(convert(*k)?, convert(*v)?),
             _ => return Err(()),
         })
     }
@@ -67,10 +183,26 @@ impl TryFrom<rust::Type> for Type {
 impl crate::ToRust for Type {
     fn to_rust(&self) -> Option<rust::Type> {
         use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
         Some(match self {
             Bool => rust::Type::Bool,
+            Int => rust::Type::Isize,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            Uint => rust::Type::Usize,
+            Uint8 => rust::Type::U8,
+            Uint16 => rust::Type::U16,
+            Uint32 => rust::Type::U32,
+            Uint64 => rust::Type::U64,
             Float32 => rust::Type::F32,
             Float64 => rust::Type::F64,
+            Rune => rust::Type::Char,
+            String => rust::Type::String,
+            Slice(t) => rust::Type::Vec(convert(t)?),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Pointer(t) => rust::Type::Option(convert(t)?),
         })
     }
 }
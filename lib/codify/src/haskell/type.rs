@@ -0,0 +1,456 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{enclosed, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://hackage.haskell.org/package/base/docs/Prelude.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Tuple.html#t:Unit
+    Unit,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Bool
+    Bool,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Char
+    Char,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Int
+    Int,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Int.html#t:Int8
+    Int8,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Int.html#t:Int16
+    Int16,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Int.html#t:Int32
+    Int32,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Int.html#t:Int64
+    Int64,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Word
+    Word,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Word.html#t:Word8
+    Word8,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Word.html#t:Word16
+    Word16,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Word.html#t:Word32
+    Word32,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Data-Word.html#t:Word64
+    Word64,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Float
+    Float,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Double
+    Double,
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:String
+    String,
+
+    /// See: https://hackage.haskell.org/package/text/docs/Data-Text.html#t:Text
+    Text,
+
+    /// See: https://hackage.haskell.org/package/bytestring/docs/Data-ByteString.html#t:ByteString
+    ByteString,
+
+    /// See: https://www.haskell.org/onlinereport/haskell2010/haskellch4.html#x10-650004.1.2
+    List(Box<Type>),
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Maybe
+    Maybe(Box<Type>),
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:Either
+    Either(Box<Type>, Box<Type>),
+
+    /// See: https://www.haskell.org/onlinereport/haskell2010/haskellch4.html#x10-650004.1.2
+    Tuple(Vec<Type>),
+
+    /// See: https://hackage.haskell.org/package/containers/docs/Data-Map-Strict.html#t:Map
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://hackage.haskell.org/package/containers/docs/Data-Set.html#t:Set
+    Set(Box<Type>),
+
+    /// See: https://hackage.haskell.org/package/base/docs/Prelude.html#t:IO
+    IO(Box<Type>),
+
+    /// See: https://hackage.haskell.org/package/base/docs/Foreign-Ptr.html#t:Ptr
+    Ptr(Box<Type>),
+
+    /// A pointer to a foreign function, given as its parameter types and
+    /// result type.
+    ///
+    /// See: https://hackage.haskell.org/package/base/docs/Foreign-Ptr.html#t:FunPtr
+    FunPtr(Vec<Type>, Box<Type>),
+
+    /// See: https://hackage.haskell.org/package/base/docs/Foreign-C-Types.html
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl Type {
+    /// Checks whether this type is a type constructor application, which
+    /// must be parenthesized when passed as an argument to another.
+    fn is_applied(&self) -> bool {
+        use Type::*;
+        match self {
+            Maybe(_) | Either(_, _) | Map(_, _) | Set(_) | IO(_) | Ptr(_) | FunPtr(_, _) => true,
+            #[cfg(feature = "language-c")]
+            Ffi(crate::c::Type::Ptr(t)) => **t != crate::c::Type::Char,
+            #[cfg(feature = "language-c")]
+            Ffi(crate::c::Type::PtrMut(_) | crate::c::Type::Array(_, _)) => true,
+            _ => false,
+        }
+    }
+
+    /// Returns this type formatted as an argument to a type constructor.
+    fn arg(&self) -> Arg<'_> {
+        Arg(self)
+    }
+}
+
+/// A type formatted as an argument to a type constructor, as in the
+/// `(Maybe Int)` of `[Maybe Int]` versus `Either Text (Maybe Int)`.
+struct Arg<'a>(&'a Type);
+
+impl fmt::Display for Arg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.0.is_applied() {
+            write!(f, "({})", self.0)
+        } else {
+            write!(f, "{}", self.0)
+        }
+    }
+}
+
+/// Splits `input` on each `->` that is not nested inside brackets, as in
+/// `CInt -> Ptr (Ptr CChar) -> IO ()`.
+fn arrows(input: &str) -> Vec<&str> {
+    let mut result = Vec::new();
+    let (mut depth, mut start) = (0isize, 0);
+    let bytes = input.as_bytes();
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' | '[' => depth += 1,
+            ')' | ']' => depth -= 1,
+            '-' if depth == 0 && bytes.get(i + 1) == Some(&b'>') => {
+                result.push(input[start..i].trim());
+                start = i + 2;
+            }
+            _ => {}
+        }
+    }
+    result.push(input[start..].trim());
+    result
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        if input == "()" {
+            return Ok(Unit);
+        }
+        if let Some(ts) = enclosed(input, '(', ')') {
+            return match split(ts, ',')[..] {
+                [t] => t.parse(),
+                ref ts => Ok(Tuple(ts.iter().map(|t| t.parse()).try_collect()?)),
+            };
+        }
+        if let Some(t) = enclosed(input, '[', ']') {
+            return Ok(List(Box::new(t.parse()?)));
+        }
+        let terms: Vec<_> = split(input, ' ')
+            .into_iter()
+            .filter(|t| !t.is_empty())
+            .collect();
+        let (head, args) = terms.split_first().ok_or(())?;
+        // Drop any module qualifier, as in `Data.Map.Strict.Map`:
+        let head = head.rsplit('.').next().unwrap_or(head);
+        let arg = |i: usize| -> Result<Box<Type>, ()> { Ok(Box::new(args[i].parse()?)) };
+        Ok(match (head, args.len()) {
+            ("Bool", 0) => Bool,
+            ("Char", 0) => Char,
+            ("Int", 0) => Int,
+            ("Int8", 0) => Int8,
+            ("Int16", 0) => Int16,
+            ("Int32", 0) => Int32,
+            ("Int64", 0) => Int64,
+            ("Word", 0) => Word,
+            ("Word8", 0) => Word8,
+            ("Word16", 0) => Word16,
+            ("Word32", 0) => Word32,
+            ("Word64", 0) => Word64,
+            ("Float", 0) => Float,
+            ("Double", 0) => Double,
+            ("String", 0) => String,
+            ("Text", 0) => Text,
+            ("ByteString", 0) => ByteString,
+            ("Maybe", 1) => Maybe(arg(0)?),
+            ("Either", 2) => Either(arg(0)?, arg(1)?),
+            ("Map", 2) => Map(arg(0)?, arg(1)?),
+            ("Set", 1) => Set(arg(0)?),
+            ("IO", 1) => IO(arg(0)?),
+            ("Ptr", 1) => Ptr(arg(0)?),
+            ("FunPtr", 1) => {
+                let signature = enclosed(args[0], '(', ')').unwrap_or(args[0]);
+                let mut ts: Vec<Type> = arrows(signature)
+                    .into_iter()
+                    .map(str::parse)
+                    .try_collect()?;
+                let result = ts.pop().ok_or(())?;
+                FunPtr(ts, Box::new(result))
+            }
+            #[cfg(feature = "language-c")]
+            (name, 0) => Ffi(match name {
+                "CBool" => crate::c::Type::Bool,
+                "CFloat" => crate::c::Type::Float,
+                "CDouble" => crate::c::Type::Double,
+                "CChar" => crate::c::Type::Char,
+                "CSChar" => crate::c::Type::SChar,
+                "CShort" => crate::c::Type::Short,
+                "CInt" => crate::c::Type::Int,
+                "CLong" => crate::c::Type::Long,
+                "CLLong" => crate::c::Type::LongLong,
+                "CSsize" => crate::c::Type::SSize_t,
+                "CUChar" => crate::c::Type::UChar,
+                "CUShort" => crate::c::Type::UShort,
+                "CUInt" => crate::c::Type::UInt,
+                "CULong" => crate::c::Type::ULong,
+                "CULLong" => crate::c::Type::ULongLong,
+                "CSize" => crate::c::Type::Size_t,
+                "CString" => crate::c::Type::Ptr(Box::new(crate::c::Type::Char)),
+                #[cfg(feature = "libc")]
+                "CTime" => crate::c::Type::Time_t,
+                _ => return Err(()),
+            }),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Unit => write!(f, "()"),
+            Bool => write!(f, "Bool"),
+            Char => write!(f, "Char"),
+            Int => write!(f, "Int"),
+            Int8 => write!(f, "Int8"),
+            Int16 => write!(f, "Int16"),
+            Int32 => write!(f, "Int32"),
+            Int64 => write!(f, "Int64"),
+            Word => write!(f, "Word"),
+            Word8 => write!(f, "Word8"),
+            Word16 => write!(f, "Word16"),
+            Word32 => write!(f, "Word32"),
+            Word64 => write!(f, "Word64"),
+            Float => write!(f, "Float"),
+            Double => write!(f, "Double"),
+            String => write!(f, "String"),
+            Text => write!(f, "Text"),
+            ByteString => write!(f, "ByteString"),
+            List(t) => write!(f, "[{}]", t),
+            Maybe(t) => write!(f, "Maybe {}", t.arg()),
+            Either(e, t) => write!(f, "Either {} {}", e.arg(), t.arg()),
+            Tuple(ts) => write!(f, "({})", ts.iter().format(", ")),
+            Map(k, v) => write!(f, "Map {} {}", k.arg(), v.arg()),
+            Set(t) => write!(f, "Set {}", t.arg()),
+            IO(t) => write!(f, "IO {}", t.arg()),
+            Ptr(t) => write!(f, "Ptr {}", t.arg()),
+            FunPtr(params, result) => {
+                let params = params
+                    .iter()
+                    .format_with("", |t, f| f(&format_args!("{} -> ", t)));
+                write!(f, "FunPtr ({}{})", params, result)
+            }
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://hackage.haskell.org/package/base/docs/Foreign-C-Types.html
+                crate::c::Type::Void => write!(f, "()"),
+                crate::c::Type::Bool => write!(f, "CBool"),
+                crate::c::Type::Float => write!(f, "CFloat"),
+                crate::c::Type::Double => write!(f, "CDouble"),
+                crate::c::Type::Char => write!(f, "CChar"),
+                crate::c::Type::SChar => write!(f, "CSChar"),
+                crate::c::Type::Short => write!(f, "CShort"),
+                crate::c::Type::Int => write!(f, "CInt"),
+                crate::c::Type::Long => write!(f, "CLong"),
+                crate::c::Type::LongLong => write!(f, "CLLong"),
+                crate::c::Type::SSize_t => write!(f, "CSsize"),
+                crate::c::Type::UChar => write!(f, "CUChar"),
+                crate::c::Type::UShort => write!(f, "CUShort"),
+                crate::c::Type::UInt => write!(f, "CUInt"),
+                crate::c::Type::ULong => write!(f, "CULong"),
+                crate::c::Type::ULongLong => write!(f, "CULLong"),
+                crate::c::Type::Size_t => write!(f, "CSize"),
+                crate::c::Type::Int8_t => write!(f, "Int8"),
+                crate::c::Type::Int16_t => write!(f, "Int16"),
+                crate::c::Type::Int32_t => write!(f, "Int32"),
+                crate::c::Type::Int64_t => write!(f, "Int64"),
+                crate::c::Type::UInt8_t => write!(f, "Word8"),
+                crate::c::Type::UInt16_t => write!(f, "Word16"),
+                crate::c::Type::UInt32_t => write!(f, "Word32"),
+                crate::c::Type::UInt64_t => write!(f, "Word64"),
+                crate::c::Type::Ptr(t) if **t == crate::c::Type::Char => write!(f, "CString"),
+                crate::c::Type::Array(t, _)
+                | crate::c::Type::Ptr(t)
+                | crate::c::Type::PtrMut(t) => {
+                    write!(f, "Ptr {}", Ffi((**t).clone()).arg())
+                }
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "CTime"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Unit => Unit,
+            rust::Type::Tuple(ts) if ts.is_empty() => Unit,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Bool,
+            rust::Type::Char => Char,
+            rust::Type::F32 => Float,
+            rust::Type::F64 => Double,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::Isize => Int,
+            rust::Type::U8 => Word8,
+            rust::Type::U16 => Word16,
+            rust::Type::U32 => Word32,
+            rust::Type::U64 => Word64,
+            rust::Type::Usize => Word,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Vec(t) if *t == rust::Type::U8 => ByteString,
+            rust::Type::Vec(t) => List(convert(*t)?),
+            rust::Type::Option(t) => Maybe(convert(*t)?),
+            rust::Type::Set(t) => Set(convert(*t)?),
+            rust::Type::Map(k, v) => Map(convert(*k)?, convert(*v)?),
+            rust::Type::Ptr(t) | rust::Type::PtrMut(t) => Ptr(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+#[cfg(feature = "language-c")]
+impl TryFrom<crate::c::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: crate::c::Type) -> Result<Self, Self::Error> {
+        use crate::c::Type as C;
+        use Type::*;
+        let convert = |t: C| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            C::Void => Unit,
+            C::Int8_t => Int8,
+            C::Int16_t => Int16,
+            C::Int32_t => Int32,
+            C::Int64_t => Int64,
+            C::UInt8_t => Word8,
+            C::UInt16_t => Word16,
+            C::UInt32_t => Word32,
+            C::UInt64_t => Word64,
+            C::Ptr(t) if *t == C::Char => Ffi(C::Ptr(t)),
+            C::Array(t, _) | C::Ptr(t) | C::PtrMut(t) => Ptr(convert(*t)?),
+            t => Ffi(t),
+        })
+    }
+}
+
+#[cfg(feature = "language-c")]
+impl TryFrom<Type> for crate::c::Type {
+    type Error = ();
+
+    fn try_from(input: Type) -> Result<Self, Self::Error> {
+        use crate::c::Type as C;
+        let convert = |t: Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            Type::Unit => C::Void,
+            Type::Int => C::SSize_t,
+            Type::Int8 => C::Int8_t,
+            Type::Int16 => C::Int16_t,
+            Type::Int32 => C::Int32_t,
+            Type::Int64 => C::Int64_t,
+            Type::Word => C::Size_t,
+            Type::Word8 => C::UInt8_t,
+            Type::Word16 => C::UInt16_t,
+            Type::Word32 => C::UInt32_t,
+            Type::Word64 => C::UInt64_t,
+            Type::Float => C::Float,
+            Type::Double => C::Double,
+            Type::Ptr(t) => C::PtrMut(convert(*t)?),
+            Type::Ffi(t) => t,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Unit => rust::Type::Unit,
+            Bool => rust::Type::Bool,
+            Char => rust::Type::Char,
+            Int => rust::Type::Isize,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            Word => rust::Type::Usize,
+            Word8 => rust::Type::U8,
+            Word16 => rust::Type::U16,
+            Word32 => rust::Type::U32,
+            Word64 => rust::Type::U64,
+            Float => rust::Type::F32,
+            Double => rust::Type::F64,
+            String | Text => rust::Type::String,
+            ByteString => rust::Type::Vec(Box::new(rust::Type::U8)),
+            List(t) => rust::Type::Vec(convert(t)?),
+            Maybe(t) => rust::Type::Option(convert(t)?),
+            Either(_, _) => return None, // no `Result` in the Rust type model
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Set(t) => rust::Type::Set(convert(t)?),
+            IO(_) | FunPtr(_, _) => return None,
+            Ptr(t) => rust::Type::PtrMut(convert(t)?),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -45,7 +45,7 @@ impl fmt::Display for Type {
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -1,19 +1,175 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    parse::{enclosed, split},
+    prelude::{fmt, format, Box, Cow, Named, String, Vec},
     rust,
 };
+use itertools::Itertools;
 
+/// A JavaScript type, as written in a JSDoc type expression.
+///
 /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+/// See: https://jsdoc.app/tags-type
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
+    /// See: https://jsdoc.app/tags-type
+    Any,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#undefined_type
+    Undefined,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#null_type
+    Null,
+
     /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#boolean_type
     Boolean,
 
     /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#number_type
     Number,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#bigint_type
+    BigInt,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#string_type
+    String,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#symbol_type
+    Symbol,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Object
+    Object,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Array
+    Array(Box<Type>),
+
+    /// An object used as a dictionary, as in `Object<string, number>`.
+    ///
+    /// See: https://jsdoc.app/tags-type
+    ObjectOf(Box<Type>, Box<Type>),
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
+    Set(Box<Type>),
+
+    /// See: https://jsdoc.app/tags-type
+    Nullable(Box<Type>),
+
+    /// See: https://jsdoc.app/tags-type
+    Union(Vec<Type>),
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/ArrayBuffer
+    ArrayBuffer,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/DataView
+    DataView,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int8Array
+    Int8Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int16Array
+    Int16Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int32Array
+    Int32Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt64Array
+    BigInt64Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array
+    Uint8Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray
+    Uint8ClampedArray,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint16Array
+    Uint16Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint32Array
+    Uint32Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigUint64Array
+    BigUint64Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float32Array
+    Float32Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float64Array
+    Float64Array,
+}
+
+impl Type {
+    /// Returns a JavaScript expression that checks at runtime whether the
+    /// variable `name` holds a value of this type, as in
+    /// `typeof x === "bigint"`.
+    pub fn to_check(&self, name: &str) -> String {
+        use Type::*;
+        match self {
+            Any => "true".into(),
+            Undefined => format!("{} === undefined", name),
+            Null => format!("{} === null", name),
+            Boolean => format!("typeof {} === \"boolean\"", name),
+            Number => format!("typeof {} === \"number\"", name),
+            BigInt => format!("typeof {} === \"bigint\"", name),
+            String => format!("typeof {} === \"string\"", name),
+            Symbol => format!("typeof {} === \"symbol\"", name),
+            Object | ObjectOf(_, _) => {
+                format!("typeof {} === \"object\" && {} !== null", name, name)
+            }
+            Array(_) => format!("Array.isArray({})", name),
+            Nullable(t) => format!("({} === null || {})", name, t.to_check(name)),
+            Union(ts) => format!("({})", ts.iter().map(|t| t.to_check(name)).format(" || ")),
+            Map(_, _) => format!("{} instanceof Map", name),
+            Set(_) => format!("{} instanceof Set", name),
+            _ => format!("{} instanceof {}", name, Expr(self)),
+        }
+    }
+
+    fn fmt_expr(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Any => write!(f, "*"),
+            Undefined => write!(f, "undefined"),
+            Null => write!(f, "null"),
+            Boolean => write!(f, "boolean"),
+            Number => write!(f, "number"),
+            BigInt => write!(f, "bigint"),
+            String => write!(f, "string"),
+            Symbol => write!(f, "symbol"),
+            Object => write!(f, "Object"),
+            Array(t) => write!(f, "Array<{}>", Expr(t)),
+            ObjectOf(k, v) => write!(f, "Object<{}, {}>", Expr(k), Expr(v)),
+            Map(k, v) => write!(f, "Map<{}, {}>", Expr(k), Expr(v)),
+            Set(t) => write!(f, "Set<{}>", Expr(t)),
+            Nullable(t) => write!(f, "?{}", Expr(t)),
+            Union(ts) => write!(f, "({})", ts.iter().map(Expr).format("|")),
+            ArrayBuffer => write!(f, "ArrayBuffer"),
+            DataView => write!(f, "DataView"),
+            Int8Array => write!(f, "Int8Array"),
+            Int16Array => write!(f, "Int16Array"),
+            Int32Array => write!(f, "Int32Array"),
+            BigInt64Array => write!(f, "BigInt64Array"),
+            Uint8Array => write!(f, "Uint8Array"),
+            Uint8ClampedArray => write!(f, "Uint8ClampedArray"),
+            Uint16Array => write!(f, "Uint16Array"),
+            Uint32Array => write!(f, "Uint32Array"),
+            BigUint64Array => write!(f, "BigUint64Array"),
+            Float32Array => write!(f, "Float32Array"),
+            Float64Array => write!(f, "Float64Array"),
+        }
+    }
+}
+
+/// A type expression without the enclosing braces.
+struct Expr<'a>(&'a Type);
+
+impl fmt::Display for Expr<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_expr(f)
+    }
 }
 
 impl core::str::FromStr for Type {
@@ -21,26 +177,82 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
+        let input = input.trim();
+        let input = enclosed(input, '{', '}').unwrap_or(input);
+        let variants = split(input, '|');
+        if variants.len() > 1 {
+            return Ok(Union(variants.into_iter().map(str::parse).try_collect()?));
+        }
         Ok(match input {
-            "boolean" => Boolean,
-            "number" => Number,
-            _ => return Err(()),
+            "*" => Any,
+            "undefined" => Undefined,
+            "null" => Null,
+            "boolean" | "Boolean" => Boolean,
+            "number" | "Number" => Number,
+            "bigint" | "BigInt" => BigInt,
+            "string" | "String" => String,
+            "symbol" | "Symbol" => Symbol,
+            "Object" | "object" => Object,
+            "Array" => Array(Box::new(Any)),
+            "ArrayBuffer" => ArrayBuffer,
+            "DataView" => DataView,
+            "Int8Array" => Int8Array,
+            "Int16Array" => Int16Array,
+            "Int32Array" => Int32Array,
+            "BigInt64Array" => BigInt64Array,
+            "Uint8Array" => Uint8Array,
+            "Uint8ClampedArray" => Uint8ClampedArray,
+            "Uint16Array" => Uint16Array,
+            "Uint32Array" => Uint32Array,
+            "BigUint64Array" => BigUint64Array,
+            "Float32Array" => Float32Array,
+            "Float64Array" => Float64Array,
+            _ => {
+                if let Some(t) = input.strip_prefix('?') {
+                    Nullable(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix('!') {
+                    t.parse()? // non-nullable types are the default here
+                } else if let Some(t) = input.strip_suffix("[]") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = enclosed(input, '(', ')') {
+                    t.parse()?
+                } else if let Some(t) = generic(input, "Array") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Object") {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    ObjectOf(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = generic(input, "Map") {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = generic(input, "Set") {
+                    Set(Box::new(t.parse()?))
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
 
+/// Returns the type argument(s) of `input` if it is an application of the
+/// generic type `name`, accepting also the legacy `Array.<T>` syntax.
+fn generic<'a>(input: &'a str, name: &str) -> Option<&'a str> {
+    let input = input.strip_prefix(name)?;
+    crate::parse::generic(input.strip_prefix('.').unwrap_or(input), "")
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        use Type::*;
-        match self {
-            Boolean => write!(f, "boolean"),
-            Number => write!(f, "number"),
-        }
+        write!(f, "{{{}}}", Expr(self))
     }
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -51,8 +263,37 @@ impl TryFrom<rust::Type> for Type {
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
         use Type::*;
         Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Undefined,
             rust::Type::Bool => Boolean,
             rust::Type::F32 | rust::Type::F64 => Number,
+            rust::Type::I8 | rust::Type::U8 => Number,
+            rust::Type::I16 | rust::Type::U16 => Number,
+            rust::Type::I32 | rust::Type::U32 => Number,
+            rust::Type::I64 | rust::Type::U64 => Number,
+            rust::Type::I128 | rust::Type::U128 => BigInt,
+            rust::Type::Isize | rust::Type::Usize => Number,
+            rust::Type::Char | rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Nullable(Box::new(Self::try_from(*t)?)),
+            rust::Type::Box(t) | rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                Self::try_from(*t)?
+            }
+            rust::Type::Ref(t) => Self::try_from(*t)?,
+            rust::Type::Vec(t) => match *t {
+                rust::Type::I8 => Int8Array,
+                rust::Type::I16 => Int16Array,
+                rust::Type::I32 => Int32Array,
+                rust::Type::U8 => Uint8Array,
+                rust::Type::U16 => Uint16Array,
+                rust::Type::U32 => Uint32Array,
+                rust::Type::F32 => Float32Array,
+                rust::Type::F64 => Float64Array,
+                t => Array(Box::new(Self::try_from(t)?)),
+            },
+            rust::Type::Set(t) => Set(Box::new(Self::try_from(*t)?)),
+            rust::Type::Map(k, v) => {
+                ObjectOf(Box::new(Self::try_from(*k)?), Box::new(Self::try_from(*v)?))
+            }
             _ => return Err(()),
         })
     }
@@ -62,8 +303,30 @@ impl crate::ToRust for Type {
     fn to_rust(&self) -> Option<rust::Type> {
         use Type::*;
         Some(match self {
+            Any => rust::Type::Any,
+            Undefined | Null => rust::Type::Unit,
             Boolean => rust::Type::Bool,
             Number => rust::Type::F64,
+            BigInt => rust::Type::I128,
+            String => rust::Type::String,
+            Symbol | Object | Union(_) => return None,
+            Array(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
+            ObjectOf(k, v) | Map(k, v) => {
+                rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?))
+            }
+            Set(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Nullable(t) => rust::Type::Option(Box::new(t.to_rust()?)),
+            ArrayBuffer | DataView => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Int8Array => rust::Type::Vec(Box::new(rust::Type::I8)),
+            Int16Array => rust::Type::Vec(Box::new(rust::Type::I16)),
+            Int32Array => rust::Type::Vec(Box::new(rust::Type::I32)),
+            BigInt64Array => rust::Type::Vec(Box::new(rust::Type::I64)),
+            Uint8Array | Uint8ClampedArray => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Uint16Array => rust::Type::Vec(Box::new(rust::Type::U16)),
+            Uint32Array => rust::Type::Vec(Box::new(rust::Type::U32)),
+            BigUint64Array => rust::Type::Vec(Box::new(rust::Type::U64)),
+            Float32Array => rust::Type::Vec(Box::new(rust::Type::F32)),
+            Float64Array => rust::Type::Vec(Box::new(rust::Type::F64)),
         })
     }
 }
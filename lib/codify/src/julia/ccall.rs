@@ -0,0 +1,55 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::{
+    c,
+    prelude::{fmt, String},
+};
+use itertools::Itertools;
+
+/// A Julia method wrapping a `ccall` of a C function exported by `library`.
+///
+/// See: https://docs.julialang.org/en/v1/base/c/#ccall
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ccall {
+    pub library: String,
+    pub function: c::Function,
+}
+
+impl Ccall {
+    pub fn new(library: impl Into<String>, function: c::Function) -> Self {
+        Self {
+            library: library.into(),
+            function,
+        }
+    }
+}
+
+impl fmt::Display for Ccall {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let function = &self.function;
+        let args =
+            (0..function.params.len()).format_with(", ", |i, f| f(&format_args!("arg{}", i)));
+        let types = function.params.iter().map(|t| Type::Ffi(t.clone()));
+        write!(
+            f,
+            "{}({}) = ccall((:{}, \"{}\"), {}, ",
+            function.name,
+            args,
+            function.name,
+            self.library,
+            Type::Ffi(function.result.clone()),
+        )?;
+        // The argument types are a tuple, which needs a trailing comma if it
+        // has a single element:
+        match function.params.len() {
+            1 => write!(f, "({},)", types.format(", "))?,
+            _ => write!(f, "({})", types.format(", "))?,
+        }
+        for i in 0..function.params.len() {
+            write!(f, ", arg{}", i)?;
+        }
+        write!(f, ")")
+    }
+}
@@ -0,0 +1,369 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{applied, split},
+    prelude::{fmt, format, vec, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://docs.julialang.org/en/v1/manual/types/
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://docs.julialang.org/en/v1/base/base/#Core.Nothing
+    Nothing,
+
+    /// See: https://docs.julialang.org/en/v1/base/base/#Core.Any
+    Any,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Bool
+    Bool,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Int8
+    Int8,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Int16
+    Int16,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Int32
+    Int32,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Int64
+    Int64,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Int128
+    Int128,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Int
+    Int,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.UInt8
+    UInt8,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.UInt16
+    UInt16,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.UInt32
+    UInt32,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.UInt64
+    UInt64,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.UInt128
+    UInt128,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.UInt
+    UInt,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Float16
+    Float16,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Float32
+    Float32,
+
+    /// See: https://docs.julialang.org/en/v1/base/numbers/#Core.Float64
+    Float64,
+
+    /// See: https://docs.julialang.org/en/v1/base/strings/#Core.Char
+    Char,
+
+    /// See: https://docs.julialang.org/en/v1/base/strings/#Core.String
+    String,
+
+    /// See: https://docs.julialang.org/en/v1/base/arrays/#Base.Vector
+    Vector(Box<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/arrays/#Base.Matrix
+    Matrix(Box<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/collections/#Base.Set
+    Set(Box<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/collections/#Base.Dict
+    Dict(Box<Type>, Box<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/base/#Core.Tuple
+    Tuple(Vec<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/base/#Core.Union
+    Union(Vec<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/c/#Core.Ptr
+    Ptr(Box<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/base/c/#Core.Ref
+    Ref(Box<Type>),
+
+    /// See: https://docs.julialang.org/en/v1/manual/calling-c-and-fortran-code/#Bits-Types
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let input = ["Base.", "Core."]
+            .iter()
+            .find_map(|module| input.strip_prefix(module))
+            .unwrap_or(input);
+        // Type parameters are given in braces, as in `Vector{Int64}`:
+        let braced = |name| applied(input, name, '{', '}');
+        Ok(match input {
+            "Nothing" => Nothing,
+            "Any" => Any,
+            "Bool" => Bool,
+            "Int8" => Int8,
+            "Int16" => Int16,
+            "Int32" => Int32,
+            "Int64" => Int64,
+            "Int128" => Int128,
+            "Int" => Int,
+            "UInt8" => UInt8,
+            "UInt16" => UInt16,
+            "UInt32" => UInt32,
+            "UInt64" => UInt64,
+            "UInt128" => UInt128,
+            "UInt" => UInt,
+            "Float16" => Float16,
+            "Float32" => Float32,
+            "Float64" => Float64,
+            "Char" => Char,
+            "String" => String,
+            #[cfg(feature = "language-c")]
+            "Cvoid" => Ffi(crate::c::Type::Void),
+            #[cfg(feature = "language-c")]
+            "Cfloat" => Ffi(crate::c::Type::Float),
+            #[cfg(feature = "language-c")]
+            "Cdouble" => Ffi(crate::c::Type::Double),
+            #[cfg(feature = "language-c")]
+            "Cchar" => Ffi(crate::c::Type::Char),
+            #[cfg(feature = "language-c")]
+            "Cshort" => Ffi(crate::c::Type::Short),
+            #[cfg(feature = "language-c")]
+            "Cint" => Ffi(crate::c::Type::Int),
+            #[cfg(feature = "language-c")]
+            "Clong" => Ffi(crate::c::Type::Long),
+            #[cfg(feature = "language-c")]
+            "Clonglong" => Ffi(crate::c::Type::LongLong),
+            #[cfg(feature = "language-c")]
+            "Cssize_t" => Ffi(crate::c::Type::SSize_t),
+            #[cfg(feature = "language-c")]
+            "Cuchar" => Ffi(crate::c::Type::UChar),
+            #[cfg(feature = "language-c")]
+            "Cushort" => Ffi(crate::c::Type::UShort),
+            #[cfg(feature = "language-c")]
+            "Cuint" => Ffi(crate::c::Type::UInt),
+            #[cfg(feature = "language-c")]
+            "Culong" => Ffi(crate::c::Type::ULong),
+            #[cfg(feature = "language-c")]
+            "Culonglong" => Ffi(crate::c::Type::ULongLong),
+            #[cfg(feature = "language-c")]
+            "Csize_t" => Ffi(crate::c::Type::Size_t),
+            #[cfg(feature = "language-c")]
+            "Cstring" => Ffi(crate::c::Type::Ptr(Box::new(crate::c::Type::Char))),
+            _ => {
+                let parse_all = |ts: &str| -> Result<Vec<Type>, ()> {
+                    match ts {
+                        "" => Ok(Vec::new()),
+                        ts => split(ts, ',').into_iter().map(str::parse).try_collect(),
+                    }
+                };
+                if let Some(t) = braced("Vector") {
+                    Vector(Box::new(t.parse()?))
+                } else if let Some(t) = braced("Matrix") {
+                    Matrix(Box::new(t.parse()?))
+                } else if let Some(ts) = braced("Array") {
+                    match split(ts, ',')[..] {
+                        [t, "1"] => Vector(Box::new(t.parse()?)),
+                        [t, "2"] => Matrix(Box::new(t.parse()?)),
+                        _ => return Err(()),
+                    }
+                } else if let Some(t) = braced("Set") {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(ts) = braced("Dict") {
+                    let [k, v] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Dict(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(ts) = braced("Tuple") {
+                    Tuple(parse_all(ts)?)
+                } else if let Some(ts) = braced("Union") {
+                    Union(parse_all(ts)?)
+                } else if let Some(t) = braced("Ptr") {
+                    Ptr(Box::new(t.parse()?))
+                } else if let Some(t) = braced("Ref") {
+                    Ref(Box::new(t.parse()?))
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Nothing => write!(f, "Nothing"),
+            Any => write!(f, "Any"),
+            Bool => write!(f, "Bool"),
+            Int8 => write!(f, "Int8"),
+            Int16 => write!(f, "Int16"),
+            Int32 => write!(f, "Int32"),
+            Int64 => write!(f, "Int64"),
+            Int128 => write!(f, "Int128"),
+            Int => write!(f, "Int"),
+            UInt8 => write!(f, "UInt8"),
+            UInt16 => write!(f, "UInt16"),
+            UInt32 => write!(f, "UInt32"),
+            UInt64 => write!(f, "UInt64"),
+            UInt128 => write!(f, "UInt128"),
+            UInt => write!(f, "UInt"),
+            Float16 => write!(f, "Float16"),
+            Float32 => write!(f, "Float32"),
+            Float64 => write!(f, "Float64"),
+            Char => write!(f, "Char"),
+            String => write!(f, "String"),
+            Vector(t) => write!(f, "Vector{{{}}}", t),
+            Matrix(t) => write!(f, "Matrix{{{}}}", t),
+            Set(t) => write!(f, "Set{{{}}}", t),
+            Dict(k, v) => write!(f, "Dict{{{}, {}}}", k, v),
+            Tuple(ts) => write!(f, "Tuple{{{}}}", ts.iter().format(", ")),
+            Union(ts) => write!(f, "Union{{{}}}", ts.iter().format(", ")),
+            Ptr(t) => write!(f, "Ptr{{{}}}", t),
+            Ref(t) => write!(f, "Ref{{{}}}", t),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://docs.julialang.org/en/v1/base/c/#Core.Cvoid
+                crate::c::Type::Void => write!(f, "Cvoid"),
+                crate::c::Type::Bool => write!(f, "Bool"),
+                crate::c::Type::Float => write!(f, "Cfloat"),
+                crate::c::Type::Double => write!(f, "Cdouble"),
+                crate::c::Type::Char => write!(f, "Cchar"),
+                crate::c::Type::SChar => write!(f, "Int8"),
+                crate::c::Type::Short => write!(f, "Cshort"),
+                crate::c::Type::Int => write!(f, "Cint"),
+                crate::c::Type::Long => write!(f, "Clong"),
+                crate::c::Type::LongLong => write!(f, "Clonglong"),
+                crate::c::Type::SSize_t => write!(f, "Cssize_t"),
+                crate::c::Type::UChar => write!(f, "Cuchar"),
+                crate::c::Type::UShort => write!(f, "Cushort"),
+                crate::c::Type::UInt => write!(f, "Cuint"),
+                crate::c::Type::ULong => write!(f, "Culong"),
+                crate::c::Type::ULongLong => write!(f, "Culonglong"),
+                crate::c::Type::Size_t => write!(f, "Csize_t"),
+                crate::c::Type::Int8_t => write!(f, "Int8"),
+                crate::c::Type::Int16_t => write!(f, "Int16"),
+                crate::c::Type::Int32_t => write!(f, "Int32"),
+                crate::c::Type::Int64_t => write!(f, "Int64"),
+                crate::c::Type::UInt8_t => write!(f, "UInt8"),
+                crate::c::Type::UInt16_t => write!(f, "UInt16"),
+                crate::c::Type::UInt32_t => write!(f, "UInt32"),
+                crate::c::Type::UInt64_t => write!(f, "UInt64"),
+                crate::c::Type::Ptr(t) if **t == crate::c::Type::Char => write!(f, "Cstring"),
+                crate::c::Type::Array(t, _)
+                | crate::c::Type::Ptr(t)
+                | crate::c::Type::PtrMut(t) => {
+                    write!(f, "Ptr{{{}}}", Ffi((**t).clone()))
+                }
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "Clong"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Nothing,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => Float32,
+            rust::Type::F64 => Float64,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::I128 => Int128,
+            rust::Type::Isize => Int,
+            rust::Type::U8 => UInt8,
+            rust::Type::U16 => UInt16,
+            rust::Type::U32 => UInt32,
+            rust::Type::U64 => UInt64,
+            rust::Type::U128 => UInt128,
+            rust::Type::Usize => UInt,
+            rust::Type::Char => Char,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Union(vec![Self::try_from(*t)?, Nothing]),
+            rust::Type::Vec(t) => Vector(convert(*t)?),
+            rust::Type::Set(t) => Set(convert(*t)?),
+            rust::Type::Map(k, v) => Dict(convert(*k)?, convert(*v)?),
+            rust::Type::Ref(t) | rust::Type::RefMut(t) => Ref(convert(*t)?),
+            rust::Type::Ptr(t) | rust::Type::PtrMut(t) => Ptr(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Nothing => rust::Type::Unit,
+            Any => rust::Type::Any,
+            Bool => rust::Type::Bool,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            Int128 => rust::Type::I128,
+            Int => rust::Type::Isize,
+            UInt8 => rust::Type::U8,
+            UInt16 => rust::Type::U16,
+            UInt32 => rust::Type::U32,
+            UInt64 => rust::Type::U64,
+            UInt128 => rust::Type::U128,
+            UInt => rust::Type::Usize,
+            Float16 => return None, // no stable equivalent in Rust
+            Float32 => rust::Type::F32,
+            Float64 => rust::Type::F64,
+            Char => rust::Type::Char,
+            String => rust::Type::String,
+            Vector(t) => rust::Type::Vec(convert(t)?),
+            Matrix(_) => return None,
+            Set(t) => rust::Type::Set(convert(t)?),
+            Dict(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Union(ts) => match &ts[..] {
+                [t, Nothing] | [Nothing, t] => rust::Type::Option(convert(t)?),
+                _ => return None,
+            },
+            Ptr(t) => rust::Type::PtrMut(convert(t)?),
+            Ref(t) => rust::Type::RefMut(convert(t)?),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
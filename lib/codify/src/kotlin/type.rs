@@ -0,0 +1,365 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{generic, split},
+    prelude::{fmt, format, Box, Cow, Named},
+    rust,
+};
+
+/// See: https://kotlinlang.org/docs/basic-types.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://kotlinlang.org/api/core/kotlin-stdlib/kotlin/-unit/
+    Unit,
+
+    /// See: https://kotlinlang.org/api/core/kotlin-stdlib/kotlin/-any/
+    Any,
+
+    /// See: https://kotlinlang.org/api/core/kotlin-stdlib/kotlin/-nothing/
+    Nothing,
+
+    /// See: https://kotlinlang.org/docs/booleans.html
+    Boolean,
+
+    /// See: https://kotlinlang.org/docs/numbers.html#integer-types
+    Byte,
+
+    /// See: https://kotlinlang.org/docs/numbers.html#integer-types
+    Short,
+
+    /// See: https://kotlinlang.org/docs/numbers.html#integer-types
+    Int,
+
+    /// See: https://kotlinlang.org/docs/numbers.html#integer-types
+    Long,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html
+    UByte,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html
+    UShort,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html
+    UInt,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html
+    ULong,
+
+    /// See: https://kotlinlang.org/docs/numbers.html#floating-point-types
+    Float,
+
+    /// See: https://kotlinlang.org/docs/numbers.html#floating-point-types
+    Double,
+
+    /// See: https://kotlinlang.org/docs/characters.html
+    Char,
+
+    /// See: https://kotlinlang.org/docs/strings.html
+    String,
+
+    /// See: https://kotlinlang.org/docs/null-safety.html#nullable-types-and-non-nullable-types
+    Nullable(Box<Type>),
+
+    /// See: https://kotlinlang.org/api/core/kotlin-stdlib/kotlin/-pair/
+    Pair(Box<Type>, Box<Type>),
+
+    /// See: https://kotlinlang.org/api/core/kotlin-stdlib/kotlin/-triple/
+    Triple(Box<Type>, Box<Type>, Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/arrays.html
+    Array(Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    BooleanArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    ByteArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    ShortArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    IntArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    LongArray,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html#unsigned-arrays-and-ranges
+    UByteArray,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html#unsigned-arrays-and-ranges
+    UShortArray,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html#unsigned-arrays-and-ranges
+    UIntArray,
+
+    /// See: https://kotlinlang.org/docs/unsigned-integer-types.html#unsigned-arrays-and-ranges
+    ULongArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    FloatArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    DoubleArray,
+
+    /// See: https://kotlinlang.org/docs/arrays.html#primitive-type-arrays
+    CharArray,
+
+    /// See: https://kotlinlang.org/docs/collections-overview.html#list
+    List(Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/collections-overview.html#list
+    MutableList(Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/collections-overview.html#set
+    Set(Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/collections-overview.html#set
+    MutableSet(Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/collections-overview.html#map
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://kotlinlang.org/docs/collections-overview.html#map
+    MutableMap(Box<Type>, Box<Type>),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        if let Some(t) = input.strip_suffix('?') {
+            return Ok(Nullable(Box::new(t.parse()?)));
+        }
+        let input = ["kotlin.collections.", "kotlin."]
+            .iter()
+            .find_map(|package| input.strip_prefix(package))
+            .unwrap_or(input);
+        Ok(match input {
+            "Unit" => Unit,
+            "Any" => Any,
+            "Nothing" => Nothing,
+            "Boolean" => Boolean,
+            "Byte" => Byte,
+            "Short" => Short,
+            "Int" => Int,
+            "Long" => Long,
+            "UByte" => UByte,
+            "UShort" => UShort,
+            "UInt" => UInt,
+            "ULong" => ULong,
+            "Float" => Float,
+            "Double" => Double,
+            "Char" => Char,
+            "String" => String,
+            "BooleanArray" => BooleanArray,
+            "ByteArray" => ByteArray,
+            "ShortArray" => ShortArray,
+            "IntArray" => IntArray,
+            "LongArray" => LongArray,
+            "UByteArray" => UByteArray,
+            "UShortArray" => UShortArray,
+            "UIntArray" => UIntArray,
+            "ULongArray" => ULongArray,
+            "FloatArray" => FloatArray,
+            "DoubleArray" => DoubleArray,
+            "CharArray" => CharArray,
+            _ => {
+                if let Some(t) = generic(input, "Array") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "List") {
+                    List(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "MutableList") {
+                    MutableList(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Set") {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "MutableSet") {
+                    MutableSet(Box::new(t.parse()?))
+                } else if let Some(ts) = generic(input, "Map") {
+                    let [k, v] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(ts) = generic(input, "MutableMap") {
+                    let [k, v] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    MutableMap(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(ts) = generic(input, "Pair") {
+                    let [a, b] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Pair(Box::new(a.parse()?), Box::new(b.parse()?))
+                } else if let Some(ts) = generic(input, "Triple") {
+                    let [a, b, c] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Triple(
+                        Box::new(a.parse()?),
+                        Box::new(b.parse()?),
+                        Box::new(c.parse()?),
+                    )
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Unit => write!(f, "Unit"),
+            Any => write!(f, "Any"),
+            Nothing => write!(f, "Nothing"),
+            Boolean => write!(f, "Boolean"),
+            Byte => write!(f, "Byte"),
+            Short => write!(f, "Short"),
+            Int => write!(f, "Int"),
+            Long => write!(f, "Long"),
+            UByte => write!(f, "UByte"),
+            UShort => write!(f, "UShort"),
+            UInt => write!(f, "UInt"),
+            ULong => write!(f, "ULong"),
+            Float => write!(f, "Float"),
+            Double => write!(f, "Double"),
+            Char => write!(f, "Char"),
+            String => write!(f, "String"),
+            Nullable(t) => write!(f, "{}?", t),
+            Pair(a, b) => write!(f, "Pair<{}, {}>", a, b),
+            Triple(a, b, c) => write!(f, "Triple<{}, {}, {}>", a, b, c),
+            Array(t) => write!(f, "Array<{}>", t),
+            BooleanArray => write!(f, "BooleanArray"),
+            ByteArray => write!(f, "ByteArray"),
+            ShortArray => write!(f, "ShortArray"),
+            IntArray => write!(f, "IntArray"),
+            LongArray => write!(f, "LongArray"),
+            UByteArray => write!(f, "UByteArray"),
+            UShortArray => write!(f, "UShortArray"),
+            UIntArray => write!(f, "UIntArray"),
+            ULongArray => write!(f, "ULongArray"),
+            FloatArray => write!(f, "FloatArray"),
+            DoubleArray => write!(f, "DoubleArray"),
+            CharArray => write!(f, "CharArray"),
+            List(t) => write!(f, "List<{}>", t),
+            MutableList(t) => write!(f, "MutableList<{}>", t),
+            Set(t) => write!(f, "Set<{}>", t),
+            MutableSet(t) => write!(f, "MutableSet<{}>", t),
+            Map(k, v) => write!(f, "Map<{}, {}>", k, v),
+            MutableMap(k, v) => write!(f, "MutableMap<{}, {}>", k, v),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Unit,
+            rust::Type::Tuple(ts) => match <[_; 2]>::try_from(ts) {
+                Ok([a, b]) => Pair(convert(a)?, convert(b)?),
+                Err(ts) => match <[_; 3]>::try_from(ts) {
+                    Ok([a, b, c]) => Triple(convert(a)?, convert(b)?, convert(c)?),
+                    Err(_) => return Err(()),
+                },
+            },
+            rust::Type::Bool => Boolean,
+            rust::Type::F32 => Float,
+            rust::Type::F64 => Double,
+            rust::Type::I8 => Byte,
+            rust::Type::I16 => Short,
+            rust::Type::I32 => Int,
+            rust::Type::I64 | rust::Type::Isize => Long,
+            rust::Type::U8 => UByte,
+            rust::Type::U16 => UShort,
+            rust::Type::U32 => UInt,
+            rust::Type::U64 | rust::Type::Usize => ULong,
+            rust::Type::Char => Char,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Nullable(convert(*t)?),
+            rust::Type::Box(t) | rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                Self::try_from(*t)?
+            }
+            rust::Type::Ref(t) => Self::try_from(*t)?,
+            rust::Type::Vec(t) => match *t {
+                rust::Type::Bool => BooleanArray,
+                rust::Type::I8 => ByteArray,
+                rust::Type::I16 => ShortArray,
+                rust::Type::I32 => IntArray,
+                rust::Type::I64 => LongArray,
+                rust::Type::U8 => UByteArray,
+                rust::Type::U16 => UShortArray,
+                rust::Type::U32 => UIntArray,
+                rust::Type::U64 => ULongArray,
+                rust::Type::F32 => FloatArray,
+                rust::Type::F64 => DoubleArray,
+                t => List(convert(t)?),
+            },
+            rust::Type::Set(t) => Set(convert(*t)?),
+            rust::Type::Map(k, v) => Map(convert(*k)?, convert(*v)?),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let vec = |t| rust::Type::Vec(Box::new(t));
+        Some(match self {
+            Unit => rust::Type::Unit,
+            Any => rust::Type::Any,
+            Nothing => return None, // no stable equivalent in Rust
+            Boolean => rust::Type::Bool,
+            Byte => rust::Type::I8,
+            Short => rust::Type::I16,
+            Int => rust::Type::I32,
+            Long => rust::Type::I64,
+            UByte => rust::Type::U8,
+            UShort => rust::Type::U16,
+            UInt => rust::Type::U32,
+            ULong => rust::Type::U64,
+            Float => rust::Type::F32,
+            Double => rust::Type::F64,
+            Char => rust::Type::Char,
+            String => rust::Type::String,
+            Nullable(t) => rust::Type::Option(Box::new(t.to_rust()?)),
+            Pair(a, b) => rust::Type::Tuple([a.to_rust()?, b.to_rust()?].into()),
+            Triple(a, b, c) => rust::Type::Tuple([a.to_rust()?, b.to_rust()?, c.to_rust()?].into()),
+            Array(t) | List(t) | MutableList(t) => vec(t.to_rust()?),
+            BooleanArray => vec(rust::Type::Bool),
+            ByteArray => vec(rust::Type::I8),
+            ShortArray => vec(rust::Type::I16),
+            IntArray => vec(rust::Type::I32),
+            LongArray => vec(rust::Type::I64),
+            UByteArray => vec(rust::Type::U8),
+            UShortArray => vec(rust::Type::U16),
+            UIntArray => vec(rust::Type::U32),
+            ULongArray => vec(rust::Type::U64),
+            FloatArray => vec(rust::Type::F32),
+            DoubleArray => vec(rust::Type::F64),
+            CharArray => vec(rust::Type::Char),
+            Set(t) | MutableSet(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Map(k, v) | MutableMap(k, v) => {
+                rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?))
+            }
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -21,29 +21,80 @@ pub enum Language {
     #[cfg(feature = "language-csharp")]
     Csharp,
 
+    #[cfg(feature = "language-d")]
+    D,
+
     #[cfg(feature = "language-dart")]
     Dart,
 
+    #[cfg(feature = "language-elixir")]
+    Elixir,
+
+    #[cfg(feature = "language-fortran")]
+    Fortran,
+
     #[cfg(feature = "language-go")]
     Go,
 
+    #[cfg(feature = "language-haskell")]
+    Haskell,
+
     #[cfg(feature = "language-java")]
     Java,
 
     #[cfg(feature = "language-javascript")]
     JavaScript,
 
+    #[cfg(feature = "language-julia")]
+    Julia,
+
+    #[cfg(feature = "language-kotlin")]
+    Kotlin,
+
+    #[cfg(feature = "language-lua")]
+    Lua,
+
+    #[cfg(feature = "language-nim")]
+    Nim,
+
+    #[cfg(feature = "language-objc")]
+    ObjectiveC,
+
+    #[cfg(feature = "language-ocaml")]
+    OCaml,
+
+    #[cfg(feature = "language-odin")]
+    Odin,
+
+    #[cfg(feature = "language-php")]
+    Php,
+
+    #[cfg(feature = "language-proto")]
+    Proto,
+
     #[cfg(feature = "language-python")]
     Python,
 
     #[cfg(feature = "language-ruby")]
     Ruby,
 
+    #[cfg(feature = "language-scala")]
+    Scala,
+
     #[cfg(feature = "language-swift")]
     Swift,
 
     #[cfg(feature = "language-typescript")]
     TypeScript,
+
+    #[cfg(feature = "language-wasm")]
+    Wasm,
+
+    #[cfg(feature = "language-wit")]
+    Wit,
+
+    #[cfg(feature = "language-zig")]
+    Zig,
 }
 
 impl Language {
@@ -61,29 +112,80 @@ impl Language {
             #[cfg(feature = "language-csharp")]
             Csharp => "csharp",
 
+            #[cfg(feature = "language-d")]
+            D => "d",
+
             #[cfg(feature = "language-dart")]
             Dart => "dart",
 
+            #[cfg(feature = "language-elixir")]
+            Elixir => "elixir",
+
+            #[cfg(feature = "language-fortran")]
+            Fortran => "fortran",
+
             #[cfg(feature = "language-go")]
             Go => "go",
 
+            #[cfg(feature = "language-haskell")]
+            Haskell => "haskell",
+
             #[cfg(feature = "language-java")]
             Java => "java",
 
             #[cfg(feature = "language-javascript")]
             JavaScript => "javascript",
 
+            #[cfg(feature = "language-julia")]
+            Julia => "julia",
+
+            #[cfg(feature = "language-kotlin")]
+            Kotlin => "kotlin",
+
+            #[cfg(feature = "language-lua")]
+            Lua => "lua",
+
+            #[cfg(feature = "language-nim")]
+            Nim => "nim",
+
+            #[cfg(feature = "language-objc")]
+            ObjectiveC => "objc",
+
+            #[cfg(feature = "language-ocaml")]
+            OCaml => "ocaml",
+
+            #[cfg(feature = "language-odin")]
+            Odin => "odin",
+
+            #[cfg(feature = "language-php")]
+            Php => "php",
+
+            #[cfg(feature = "language-proto")]
+            Proto => "proto",
+
             #[cfg(feature = "language-python")]
             Python => "python",
 
             #[cfg(feature = "language-ruby")]
             Ruby => "ruby",
 
+            #[cfg(feature = "language-scala")]
+            Scala => "scala",
+
             #[cfg(feature = "language-swift")]
             Swift => "swift",
 
             #[cfg(feature = "language-typescript")]
             TypeScript => "typescript",
+
+            #[cfg(feature = "language-wasm")]
+            Wasm => "wasm",
+
+            #[cfg(feature = "language-wit")]
+            Wit => "wit",
+
+            #[cfg(feature = "language-zig")]
+            Zig => "zig",
         }
     }
 
@@ -101,29 +203,80 @@ impl Language {
             #[cfg(feature = "language-csharp")]
             Csharp => Box::new(crate::csharp::Type::from_str(input)?),
 
+            #[cfg(feature = "language-d")]
+            D => Box::new(crate::d::Type::from_str(input)?),
+
             #[cfg(feature = "language-dart")]
             Dart => Box::new(crate::dart::Type::from_str(input)?),
 
+            #[cfg(feature = "language-elixir")]
+            Elixir => Box::new(crate::elixir::Type::from_str(input)?),
+
+            #[cfg(feature = "language-fortran")]
+            Fortran => Box::new(crate::fortran::Type::from_str(input)?),
+
             #[cfg(feature = "language-go")]
             Go => Box::new(crate::go::Type::from_str(input)?),
 
+            #[cfg(feature = "language-haskell")]
+            Haskell => Box::new(crate::haskell::Type::from_str(input)?),
+
             #[cfg(feature = "language-java")]
             Java => Box::new(crate::java::Type::from_str(input)?),
 
             #[cfg(feature = "language-javascript")]
             JavaScript => Box::new(crate::javascript::Type::from_str(input)?),
 
+            #[cfg(feature = "language-julia")]
+            Julia => Box::new(crate::julia::Type::from_str(input)?),
+
+            #[cfg(feature = "language-kotlin")]
+            Kotlin => Box::new(crate::kotlin::Type::from_str(input)?),
+
+            #[cfg(feature = "language-lua")]
+            Lua => Box::new(crate::lua::Type::from_str(input)?),
+
+            #[cfg(feature = "language-nim")]
+            Nim => Box::new(crate::nim::Type::from_str(input)?),
+
+            #[cfg(feature = "language-objc")]
+            ObjectiveC => Box::new(crate::objc::Type::from_str(input)?),
+
+            #[cfg(feature = "language-ocaml")]
+            OCaml => Box::new(crate::ocaml::Type::from_str(input)?),
+
+            #[cfg(feature = "language-odin")]
+            Odin => Box::new(crate::odin::Type::from_str(input)?),
+
+            #[cfg(feature = "language-php")]
+            Php => Box::new(crate::php::Type::from_str(input)?),
+
+            #[cfg(feature = "language-proto")]
+            Proto => Box::new(crate::proto::Type::from_str(input)?),
+
             #[cfg(feature = "language-python")]
             Python => Box::new(crate::python::Type::from_str(input)?),
 
             #[cfg(feature = "language-ruby")]
             Ruby => Box::new(crate::ruby::Type::from_str(input)?),
 
+            #[cfg(feature = "language-scala")]
+            Scala => Box::new(crate::scala::Type::from_str(input)?),
+
             #[cfg(feature = "language-swift")]
             Swift => Box::new(crate::swift::Type::from_str(input)?),
 
             #[cfg(feature = "language-typescript")]
             TypeScript => Box::new(crate::typescript::Type::from_str(input)?),
+
+            #[cfg(feature = "language-wasm")]
+            Wasm => Box::new(crate::wasm::Type::from_str(input)?),
+
+            #[cfg(feature = "language-wit")]
+            Wit => Box::new(crate::wit::Type::from_str(input)?),
+
+            #[cfg(feature = "language-zig")]
+            Zig => Box::new(crate::zig::Type::from_str(input)?),
         })
     }
 
@@ -141,29 +294,80 @@ impl Language {
             #[cfg(feature = "language-csharp")]
             Csharp => Box::new(crate::csharp::Type::try_from(input)?),
 
+            #[cfg(feature = "language-d")]
+            D => Box::new(crate::d::Type::try_from(input)?),
+
             #[cfg(feature = "language-dart")]
             Dart => Box::new(crate::dart::Type::try_from(input)?),
 
+            #[cfg(feature = "language-elixir")]
+            Elixir => Box::new(crate::elixir::Type::try_from(input)?),
+
+            #[cfg(feature = "language-fortran")]
+            Fortran => Box::new(crate::fortran::Type::try_from(input)?),
+
             #[cfg(feature = "language-go")]
             Go => Box::new(crate::go::Type::try_from(input)?),
 
+            #[cfg(feature = "language-haskell")]
+            Haskell => Box::new(crate::haskell::Type::try_from(input)?),
+
             #[cfg(feature = "language-java")]
             Java => Box::new(crate::java::Type::try_from(input)?),
 
             #[cfg(feature = "language-javascript")]
             JavaScript => Box::new(crate::javascript::Type::try_from(input)?),
 
+            #[cfg(feature = "language-julia")]
+            Julia => Box::new(crate::julia::Type::try_from(input)?),
+
+            #[cfg(feature = "language-kotlin")]
+            Kotlin => Box::new(crate::kotlin::Type::try_from(input)?),
+
+            #[cfg(feature = "language-lua")]
+            Lua => Box::new(crate::lua::Type::try_from(input)?),
+
+            #[cfg(feature = "language-nim")]
+            Nim => Box::new(crate::nim::Type::try_from(input)?),
+
+            #[cfg(feature = "language-objc")]
+            ObjectiveC => Box::new(crate::objc::Type::try_from(input)?),
+
+            #[cfg(feature = "language-ocaml")]
+            OCaml => Box::new(crate::ocaml::Type::try_from(input)?),
+
+            #[cfg(feature = "language-odin")]
+            Odin => Box::new(crate::odin::Type::try_from(input)?),
+
+            #[cfg(feature = "language-php")]
+            Php => Box::new(crate::php::Type::try_from(input)?),
+
+            #[cfg(feature = "language-proto")]
+            Proto => Box::new(crate::proto::Type::try_from(input)?),
+
             #[cfg(feature = "language-python")]
             Python => Box::new(crate::python::Type::try_from(input)?),
 
             #[cfg(feature = "language-ruby")]
             Ruby => Box::new(crate::ruby::Type::try_from(input)?),
 
+            #[cfg(feature = "language-scala")]
+            Scala => Box::new(crate::scala::Type::try_from(input)?),
+
             #[cfg(feature = "language-swift")]
             Swift => Box::new(crate::swift::Type::try_from(input)?),
 
             #[cfg(feature = "language-typescript")]
             TypeScript => Box::new(crate::typescript::Type::try_from(input)?),
+
+            #[cfg(feature = "language-wasm")]
+            Wasm => Box::new(crate::wasm::Type::try_from(input)?),
+
+            #[cfg(feature = "language-wit")]
+            Wit => Box::new(crate::wit::Type::try_from(input)?),
+
+            #[cfg(feature = "language-zig")]
+            Zig => Box::new(crate::zig::Type::try_from(input)?),
         })
     }
 }
@@ -185,30 +389,81 @@ impl core::str::FromStr for Language {
             #[cfg(feature = "language-csharp")]
             "csharp" => Csharp,
 
+            #[cfg(feature = "language-d")]
+            "d" => D,
+
             #[cfg(feature = "language-dart")]
             "dart" => Dart,
 
+            #[cfg(feature = "language-elixir")]
+            "elixir" => Elixir,
+
+            #[cfg(feature = "language-fortran")]
+            "fortran" => Fortran,
+
             #[cfg(feature = "language-go")]
             "go" => Go,
 
+            #[cfg(feature = "language-haskell")]
+            "haskell" => Haskell,
+
             #[cfg(feature = "language-java")]
             "java" => Java,
 
             #[cfg(feature = "language-javascript")]
             "javascript" => JavaScript,
 
+            #[cfg(feature = "language-julia")]
+            "julia" => Julia,
+
+            #[cfg(feature = "language-kotlin")]
+            "kotlin" => Kotlin,
+
+            #[cfg(feature = "language-lua")]
+            "lua" => Lua,
+
+            #[cfg(feature = "language-nim")]
+            "nim" => Nim,
+
+            #[cfg(feature = "language-objc")]
+            "objc" => ObjectiveC,
+
+            #[cfg(feature = "language-ocaml")]
+            "ocaml" => OCaml,
+
+            #[cfg(feature = "language-odin")]
+            "odin" => Odin,
+
+            #[cfg(feature = "language-php")]
+            "php" => Php,
+
+            #[cfg(feature = "language-proto")]
+            "proto" => Proto,
+
             #[cfg(feature = "language-python")]
             "python" => Python,
 
             #[cfg(feature = "language-ruby")]
             "ruby" => Ruby,
 
+            #[cfg(feature = "language-scala")]
+            "scala" => Scala,
+
             #[cfg(feature = "language-swift")]
             "swift" => Swift,
 
             #[cfg(feature = "language-typescript")]
             "typescript" => TypeScript,
 
+            #[cfg(feature = "language-wasm")]
+            "wasm" => Wasm,
+
+            #[cfg(feature = "language-wit")]
+            "wit" => Wit,
+
+            #[cfg(feature = "language-zig")]
+            "zig" => Zig,
+
             _ => return Err(()),
         })
     }
@@ -221,7 +476,7 @@ impl core::fmt::Display for Language {
 }
 
 impl Named for Language {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Borrowed(self.as_str())
     }
 }
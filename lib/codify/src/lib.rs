@@ -17,12 +17,20 @@ pub use feature::*;
 mod language;
 pub use language::*;
 
+mod parse;
+
 mod r#type;
 pub use r#type::*;
 
 #[cfg(feature = "language-c")]
 /// Support for the C programming language.
 pub mod c {
+    pub mod r#enum;
+    pub use r#enum::*;
+    pub mod function;
+    pub use function::*;
+    pub mod r#struct;
+    pub use r#struct::*;
     pub mod r#type;
     pub use r#type::*;
 }
@@ -37,6 +45,17 @@ pub mod cpp {
 #[cfg(feature = "language-csharp")]
 /// Support for the C# programming language.
 pub mod csharp {
+    #[cfg(feature = "language-c")]
+    pub mod interop;
+    #[cfg(feature = "language-c")]
+    pub use interop::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-d")]
+/// Support for the D programming language.
+pub mod d {
     pub mod r#type;
     pub use r#type::*;
 }
@@ -48,6 +67,22 @@ pub mod dart {
     pub use r#type::*;
 }
 
+#[cfg(feature = "language-elixir")]
+/// Support for the Elixir programming language.
+pub mod elixir {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-fortran")]
+/// Support for the Fortran programming language.
+pub mod fortran {
+    pub mod interface;
+    pub use interface::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
 #[cfg(feature = "language-go")]
 /// Support for the Go programming language.
 pub mod go {
@@ -55,6 +90,13 @@ pub mod go {
     pub use r#type::*;
 }
 
+#[cfg(feature = "language-haskell")]
+/// Support for the Haskell programming language.
+pub mod haskell {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
 #[cfg(feature = "language-java")]
 /// Support for the Java programming language.
 pub mod java {
@@ -69,9 +111,88 @@ pub mod javascript {
     pub use r#type::*;
 }
 
+#[cfg(feature = "language-julia")]
+/// Support for the Julia programming language.
+pub mod julia {
+    #[cfg(feature = "language-c")]
+    pub mod ccall;
+    #[cfg(feature = "language-c")]
+    pub use ccall::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-kotlin")]
+/// Support for the Kotlin programming language.
+pub mod kotlin {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-lua")]
+/// Support for the Lua programming language.
+pub mod lua {
+    #[cfg(feature = "language-c")]
+    pub mod ffi;
+    #[cfg(feature = "language-c")]
+    pub use ffi::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-nim")]
+/// Support for the Nim programming language.
+pub mod nim {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-objc")]
+/// Support for the Objective-C programming language.
+pub mod objc {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-ocaml")]
+/// Support for the OCaml programming language.
+pub mod ocaml {
+    #[cfg(feature = "language-c")]
+    pub mod ctypes;
+    #[cfg(feature = "language-c")]
+    pub use ctypes::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-odin")]
+/// Support for the Odin programming language.
+pub mod odin {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-php")]
+/// Support for the PHP programming language.
+pub mod php {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-proto")]
+/// Support for the Protocol Buffers programming language.
+pub mod proto {
+    pub mod schema;
+    pub use schema::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
 #[cfg(feature = "language-python")]
 /// Support for the Python programming language.
 pub mod python {
+    pub mod dtype;
+    pub use dtype::*;
     pub mod r#type;
     pub use r#type::*;
 }
@@ -79,6 +200,10 @@ pub mod python {
 #[cfg(feature = "language-ruby")]
 /// Support for the Ruby programming language.
 pub mod ruby {
+    #[cfg(feature = "language-c")]
+    pub mod ffi;
+    #[cfg(feature = "language-c")]
+    pub use ffi::*;
     pub mod r#type;
     pub use r#type::*;
 }
@@ -89,6 +214,13 @@ pub mod rust {
     pub use r#type::*;
 }
 
+#[cfg(feature = "language-scala")]
+/// Support for the Scala programming language.
+pub mod scala {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
 #[cfg(feature = "language-swift")]
 /// Support for the Swift programming language.
 pub mod swift {
@@ -103,6 +235,29 @@ pub mod typescript {
     pub use r#type::*;
 }
 
+#[cfg(feature = "language-wasm")]
+/// Support for the WebAssembly programming language.
+pub mod wasm {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-wit")]
+/// Support for the WIT programming language.
+pub mod wit {
+    pub mod definition;
+    pub use definition::*;
+    pub mod r#type;
+    pub use r#type::*;
+}
+
+#[cfg(feature = "language-zig")]
+/// Support for the Zig programming language.
+pub mod zig {
+    pub mod r#type;
+    pub use r#type::*;
+}
+
 //#[doc = include_str!("../../../README.md")]
 //#[cfg(doctest)]
 //pub struct ReadmeDoctests;
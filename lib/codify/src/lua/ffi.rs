@@ -0,0 +1,56 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    c,
+    prelude::{fmt, Vec},
+};
+use itertools::Itertools;
+
+/// A C declaration for use in a LuaJIT `ffi.cdef` block.
+///
+/// See: https://luajit.org/ext_ffi_api.html#ffi_cdef
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Declaration {
+    Enum(c::Enum),
+    Struct(c::Struct),
+    /// A function pointer type, declared as a `typedef`.
+    Callback(c::Function),
+    /// A function exported by the library, declared as a prototype.
+    Function(c::Function),
+}
+
+/// An `ffi.cdef` block declaring the given C types and functions, in order.
+///
+/// See: https://luajit.org/ext_ffi_api.html#ffi_cdef
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cdef(pub Vec<Declaration>);
+
+impl fmt::Display for Declaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Declaration::Enum(e) => write!(f, "{}", e),
+            Declaration::Struct(s) => write!(f, "{}", s),
+            Declaration::Callback(function) => write!(f, "{}", function),
+            Declaration::Function(function) => {
+                write!(f, "{} {}(", function.result, function.name)?;
+                match &function.params[..] {
+                    [] => write!(f, "void")?,
+                    params => write!(f, "{}", params.iter().format(", "))?,
+                }
+                write!(f, ");")
+            }
+        }
+    }
+}
+
+impl fmt::Display for Cdef {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "ffi.cdef[[")?;
+        for declaration in &self.0 {
+            writeln!(f, "{}", declaration)?;
+        }
+        write!(f, "]]")
+    }
+}
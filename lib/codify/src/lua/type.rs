@@ -0,0 +1,251 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{enclosed, generic, split, union},
+    prelude::{fmt, format, Box, Cow, Named, String, ToString, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://luals.github.io/wiki/annotations/
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://www.lua.org/manual/5.4/manual.html#2.1
+    Nil,
+
+    /// See: https://luals.github.io/wiki/annotations/#documenting-types
+    Any,
+
+    /// See: https://www.lua.org/manual/5.4/manual.html#2.1
+    Boolean,
+
+    /// See: https://www.lua.org/manual/5.4/manual.html#2.1
+    Integer,
+
+    /// See: https://www.lua.org/manual/5.4/manual.html#2.1
+    Number,
+
+    /// See: https://www.lua.org/manual/5.4/manual.html#2.1
+    String,
+
+    /// See: https://www.lua.org/manual/5.4/manual.html#2.1
+    Table,
+
+    /// See: https://luals.github.io/wiki/annotations/#documenting-types
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://luals.github.io/wiki/annotations/#documenting-types
+    Array(Box<Type>),
+
+    /// See: https://luals.github.io/wiki/annotations/#documenting-types
+    Optional(Box<Type>),
+
+    /// See: https://luals.github.io/wiki/annotations/#documenting-types
+    Union(Vec<Type>),
+
+    /// A function type, given as its named parameters and its results.
+    ///
+    /// See: https://luals.github.io/wiki/annotations/#documenting-types
+    Function(Vec<(String, Type)>, Vec<Type>),
+
+    /// A C type for use with LuaJIT's `ffi.new`, `ffi.typeof`, and
+    /// `ffi.cast`.
+    ///
+    /// See: https://luajit.org/ext_ffi_semantics.html#clang
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl Type {
+    /// Returns this type formatted as the operand of a `?` or `[]` suffix,
+    /// as in the `(string|integer)` of `(string|integer)[]`.
+    fn operand(&self) -> Operand<'_> {
+        Operand(self)
+    }
+
+    /// Parses a function type, as in `fun(a: integer): boolean`.
+    fn parse_function(input: &str) -> Result<Option<Self>, ()> {
+        let Some(input) = input.strip_prefix("fun(") else {
+            return Ok(None);
+        };
+        let mut depth = 0usize;
+        let close = input.char_indices().find_map(|(i, c)| match c {
+            '(' | '[' | '<' => {
+                depth += 1;
+                None
+            }
+            ')' | ']' | '>' if depth > 0 => {
+                depth -= 1;
+                None
+            }
+            ')' if depth == 0 => Some(i),
+            _ => None,
+        });
+        let Some(close) = close else {
+            return Ok(None);
+        };
+        let (params, results) = (&input[..close], input[close + 1..].trim());
+        let results = match results.strip_prefix(':') {
+            Some(results) => split(results, ','),
+            None if results.is_empty() => Vec::new(),
+            None => return Ok(None),
+        };
+        let params = match params.trim() {
+            "" => Vec::new(),
+            params => split(params, ','),
+        };
+        let params = params.into_iter().map(|param| {
+            let (name, t) = param.split_once(':').ok_or(())?;
+            Ok((name.trim().to_string(), t.parse()?))
+        });
+        let results = results.into_iter().map(str::parse);
+        Ok(Some(Type::Function(
+            params.try_collect()?,
+            results.try_collect()?,
+        )))
+    }
+}
+
+struct Operand<'a>(&'a Type);
+
+impl fmt::Display for Operand<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self.0 {
+            Optional(_) | Union(_) | Function(_, _) => write!(f, "({})", self.0),
+            t => write!(f, "{}", t),
+        }
+    }
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        if let Some(function) = Self::parse_function(input)? {
+            return Ok(function);
+        }
+        let variants = split(input, '|');
+        if variants.len() > 1 {
+            let types = variants.into_iter().map(str::parse).try_collect()?;
+            return Ok(union(types, &Nil, |t| Optional(Box::new(t)), Union));
+        }
+        if let Some(t) = input.strip_suffix('?') {
+            return Ok(Optional(Box::new(t.parse()?)));
+        }
+        if let Some(t) = input.strip_suffix("[]") {
+            return Ok(Array(Box::new(t.parse()?)));
+        }
+        if let Some(t) = enclosed(input, '(', ')') {
+            return t.parse();
+        }
+        Ok(match input {
+            "nil" => Nil,
+            "any" => Any,
+            "boolean" => Boolean,
+            "integer" => Integer,
+            "number" => Number,
+            "string" => String,
+            "table" => Table,
+            _ => {
+                let kv = generic(input, "table").ok_or(())?;
+                let [k, v] = split(kv, ',')[..] else {
+                    return Err(());
+                };
+                Map(Box::new(k.parse()?), Box::new(v.parse()?))
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Nil => write!(f, "nil"),
+            Any => write!(f, "any"),
+            Boolean => write!(f, "boolean"),
+            Integer => write!(f, "integer"),
+            Number => write!(f, "number"),
+            String => write!(f, "string"),
+            Table => write!(f, "table"),
+            Map(k, v) => write!(f, "table<{}, {}>", k, v),
+            Array(t) => write!(f, "{}[]", t.operand()),
+            Optional(t) => write!(f, "{}?", t.operand()),
+            Union(ts) => write!(f, "{}", ts.iter().format("|")),
+            Function(params, results) => {
+                let params = params
+                    .iter()
+                    .format_with(", ", |(name, t), f| f(&format_args!("{}: {}", name, t)));
+                write!(f, "fun({})", params)?;
+                if !results.is_empty() {
+                    write!(f, ": {}", results.iter().format(", "))?;
+                }
+                Ok(())
+            }
+            #[cfg(feature = "language-c")]
+            Ffi(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Nil,
+            rust::Type::Bool => Boolean,
+            rust::Type::F32 | rust::Type::F64 => Number,
+            rust::Type::I8 | rust::Type::I16 | rust::Type::I32 | rust::Type::I64 => Integer,
+            rust::Type::U8 | rust::Type::U16 | rust::Type::U32 => Integer,
+            rust::Type::Isize => Integer,
+            rust::Type::Char | rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Optional(convert(*t)?),
+            rust::Type::Vec(t) => Array(convert(*t)?),
+            // A set is conventionally a table mapping its members to `true`:
+            rust::Type::Set(t) => Map(convert(*t)?, Box::new(Boolean)),
+            rust::Type::Map(k, v) => Map(convert(*k)?, convert(*v)?),
+            #[cfg(feature = "language-c")]
+            t @ (rust::Type::Ptr(_) | rust::Type::PtrMut(_)) => Ffi(crate::c::Type::try_from(t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Nil => rust::Type::Unit,
+            Any => rust::Type::Any,
+            Boolean => rust::Type::Bool,
+            Integer => rust::Type::I64,
+            Number => rust::Type::F64,
+            String => rust::Type::String,
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Array(t) => rust::Type::Vec(convert(t)?),
+            Optional(t) => rust::Type::Option(convert(t)?),
+            Table | Union(_) | Function(_, _) => return None,
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
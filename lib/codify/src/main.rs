@@ -84,7 +84,7 @@ pub fn convert(
 }
 
 fn parse_language(input: &str) -> Result<Language, TypeParseError> {
-    Ok(Language::from_str(input).map_err(|_| TypeParseError::InvalidLanguage)?)
+    Language::from_str(input).map_err(|_| TypeParseError::InvalidLanguage)
 }
 
 fn parse_type(input: &str) -> Result<(Language, String), TypeParseError> {
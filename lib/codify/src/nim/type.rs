@@ -0,0 +1,361 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{applied, enclosed, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://nim-lang.org/docs/manual.html#types
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://nim-lang.org/docs/manual.html#statements-and-expressions-discard-statement
+    Void,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-boolean-type
+    Bool,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-character-type
+    Char,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    Int,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    Int8,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    Int16,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    Int32,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    Int64,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    UInt,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    UInt8,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    UInt16,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    UInt32,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-integer-types
+    UInt64,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-floatingminuspoint-types
+    Float,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-floatingminuspoint-types
+    Float32,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-preminusdefined-floatingminuspoint-types
+    Float64,
+
+    /// See: https://nim-lang.org/docs/unicode.html#Rune
+    Rune,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-string-type
+    String,
+
+    /// See: https://nim-lang.org/docs/manual.html#types-array-and-sequence-types
+    Array(Box<Type>, usize),
+
+    /// See: https://nim-lang.org/docs/manual.html#types-array-and-sequence-types
+    Seq(Box<Type>),
+
+    /// See: https://nim-lang.org/docs/sets.html#HashSet
+    HashSet(Box<Type>),
+
+    /// See: https://nim-lang.org/docs/tables.html#Table
+    Table(Box<Type>, Box<Type>),
+
+    /// See: https://nim-lang.org/docs/options.html#Option
+    Option(Box<Type>),
+
+    /// See: https://nim-lang.org/docs/manual.html#types-tuples-and-object-types
+    Tuple(Vec<Type>),
+
+    /// See: https://nim-lang.org/docs/manual.html#types-reference-and-pointer-types
+    Ptr(Box<Type>),
+
+    /// A C type from the `system` module's C-compatible aliases, such as
+    /// `cint`, `csize_t`, and `cstring`.
+    ///
+    /// See: https://nim-lang.org/docs/system.html#cint
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        // Type arguments are given in square brackets, as in `seq[int]`:
+        let subscript = |name| applied(input, name, '[', ']');
+        Ok(match input {
+            "void" => Void,
+            "bool" => Bool,
+            "char" => Char,
+            "int" => Int,
+            "int8" => Int8,
+            "int16" => Int16,
+            "int32" => Int32,
+            "int64" => Int64,
+            "uint" => UInt,
+            "uint8" | "byte" => UInt8,
+            "uint16" => UInt16,
+            "uint32" => UInt32,
+            "uint64" => UInt64,
+            "float" => Float,
+            "float32" => Float32,
+            "float64" => Float64,
+            "Rune" => Rune,
+            "string" => String,
+            #[cfg(feature = "language-c")]
+            "cchar" => Ffi(crate::c::Type::Char),
+            #[cfg(feature = "language-c")]
+            "cschar" => Ffi(crate::c::Type::SChar),
+            #[cfg(feature = "language-c")]
+            "cshort" => Ffi(crate::c::Type::Short),
+            #[cfg(feature = "language-c")]
+            "cint" => Ffi(crate::c::Type::Int),
+            #[cfg(feature = "language-c")]
+            "clong" => Ffi(crate::c::Type::Long),
+            #[cfg(feature = "language-c")]
+            "clonglong" => Ffi(crate::c::Type::LongLong),
+            #[cfg(feature = "language-c")]
+            "cuchar" => Ffi(crate::c::Type::UChar),
+            #[cfg(feature = "language-c")]
+            "cushort" => Ffi(crate::c::Type::UShort),
+            #[cfg(feature = "language-c")]
+            "cuint" => Ffi(crate::c::Type::UInt),
+            #[cfg(feature = "language-c")]
+            "culong" => Ffi(crate::c::Type::ULong),
+            #[cfg(feature = "language-c")]
+            "culonglong" => Ffi(crate::c::Type::ULongLong),
+            #[cfg(feature = "language-c")]
+            "csize_t" => Ffi(crate::c::Type::Size_t),
+            #[cfg(feature = "language-c")]
+            "cfloat" => Ffi(crate::c::Type::Float),
+            #[cfg(feature = "language-c")]
+            "cdouble" => Ffi(crate::c::Type::Double),
+            #[cfg(feature = "language-c")]
+            "cstring" => Ffi(crate::c::Type::Ptr(Box::new(crate::c::Type::Char))),
+            #[cfg(feature = "language-c")]
+            "pointer" => Ffi(crate::c::Type::PtrMut(Box::new(crate::c::Type::Void))),
+            _ => {
+                if let Some(t) = input.strip_prefix("ptr ") {
+                    Ptr(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("seq") {
+                    Seq(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("HashSet") {
+                    HashSet(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("Option") {
+                    Option(Box::new(t.parse()?))
+                } else if let Some(kv) = subscript("Table") {
+                    let [k, v] = split(kv, ',')[..] else {
+                        return Err(());
+                    };
+                    Table(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(nt) = subscript("array") {
+                    let [n, t] = split(nt, ',')[..] else {
+                        return Err(());
+                    };
+                    Array(Box::new(t.parse()?), n.parse().map_err(|_| ())?)
+                } else if let Some(ts) = enclosed(input, '(', ')') {
+                    let ts = split(ts, ',');
+                    if let [t] = ts[..] {
+                        if !t.is_empty() {
+                            return t.parse(); // a parenthesized type, as in `(int)`
+                        }
+                    }
+                    Tuple(
+                        ts.into_iter()
+                            .filter(|t| !t.is_empty())
+                            .map(str::parse)
+                            .try_collect()?,
+                    )
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Void => write!(f, "void"),
+            Bool => write!(f, "bool"),
+            Char => write!(f, "char"),
+            Int => write!(f, "int"),
+            Int8 => write!(f, "int8"),
+            Int16 => write!(f, "int16"),
+            Int32 => write!(f, "int32"),
+            Int64 => write!(f, "int64"),
+            UInt => write!(f, "uint"),
+            UInt8 => write!(f, "uint8"),
+            UInt16 => write!(f, "uint16"),
+            UInt32 => write!(f, "uint32"),
+            UInt64 => write!(f, "uint64"),
+            Float => write!(f, "float"),
+            Float32 => write!(f, "float32"),
+            Float64 => write!(f, "float64"),
+            Rune => write!(f, "Rune"),
+            String => write!(f, "string"),
+            Array(t, n) => write!(f, "array[{}, {}]", n, t),
+            Seq(t) => write!(f, "seq[{}]", t),
+            HashSet(t) => write!(f, "HashSet[{}]", t),
+            Table(k, v) => write!(f, "Table[{}, {}]", k, v),
+            Option(t) => write!(f, "Option[{}]", t),
+            // A one-element tuple needs a trailing comma, as in `(int,)`:
+            Tuple(ts) if ts.len() == 1 => write!(f, "({},)", ts[0]),
+            Tuple(ts) => write!(f, "({})", ts.iter().format(", ")),
+            Ptr(t) => write!(f, "ptr {}", t),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://nim-lang.org/docs/system.html#cint
+                crate::c::Type::Void => write!(f, "void"),
+                crate::c::Type::Bool => write!(f, "bool"),
+                crate::c::Type::Float => write!(f, "cfloat"),
+                crate::c::Type::Double => write!(f, "cdouble"),
+                crate::c::Type::Char => write!(f, "cchar"),
+                crate::c::Type::SChar => write!(f, "cschar"),
+                crate::c::Type::Short => write!(f, "cshort"),
+                crate::c::Type::Int => write!(f, "cint"),
+                crate::c::Type::Long => write!(f, "clong"),
+                crate::c::Type::LongLong => write!(f, "clonglong"),
+                crate::c::Type::SSize_t => write!(f, "int"),
+                crate::c::Type::UChar => write!(f, "cuchar"),
+                crate::c::Type::UShort => write!(f, "cushort"),
+                crate::c::Type::UInt => write!(f, "cuint"),
+                crate::c::Type::ULong => write!(f, "culong"),
+                crate::c::Type::ULongLong => write!(f, "culonglong"),
+                crate::c::Type::Size_t => write!(f, "csize_t"),
+                crate::c::Type::Int8_t => write!(f, "int8"),
+                crate::c::Type::Int16_t => write!(f, "int16"),
+                crate::c::Type::Int32_t => write!(f, "int32"),
+                crate::c::Type::Int64_t => write!(f, "int64"),
+                crate::c::Type::UInt8_t => write!(f, "uint8"),
+                crate::c::Type::UInt16_t => write!(f, "uint16"),
+                crate::c::Type::UInt32_t => write!(f, "uint32"),
+                crate::c::Type::UInt64_t => write!(f, "uint64"),
+                crate::c::Type::Array(t, None) => {
+                    write!(f, "ptr UncheckedArray[{}]", Ffi((**t).clone()))
+                }
+                crate::c::Type::Array(t, Some(n)) => {
+                    write!(f, "array[{}, {}]", n, Ffi((**t).clone()))
+                }
+                crate::c::Type::Ptr(t) | crate::c::Type::PtrMut(t) => match **t {
+                    crate::c::Type::Char => write!(f, "cstring"),
+                    crate::c::Type::Void => write!(f, "pointer"),
+                    _ => write!(f, "ptr {}", Ffi((**t).clone())),
+                },
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "Time"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => Float32,
+            rust::Type::F64 => Float64,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::Isize => Int,
+            rust::Type::U8 => UInt8,
+            rust::Type::U16 => UInt16,
+            rust::Type::U32 => UInt32,
+            rust::Type::U64 => UInt64,
+            rust::Type::Usize => UInt,
+            rust::Type::Char => Rune,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Option(convert(*t)?),
+            rust::Type::Vec(t) => Seq(convert(*t)?),
+            rust::Type::Set(t) => HashSet(convert(*t)?),
+            rust::Type::Map(k, v) => Table(convert(*k)?, convert(*v)?),
+            rust::Type::Ptr(t) | rust::Type::PtrMut(t) => Ptr(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Void => rust::Type::Unit,
+            Bool => rust::Type::Bool,
+            Char => rust::Type::U8,
+            Int => rust::Type::Isize,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            UInt => rust::Type::Usize,
+            UInt8 => rust::Type::U8,
+            UInt16 => rust::Type::U16,
+            UInt32 => rust::Type::U32,
+            UInt64 => rust::Type::U64,
+            Float32 => rust::Type::F32,
+            Float | Float64 => rust::Type::F64,
+            Rune => rust::Type::Char,
+            String => rust::Type::String,
+            #[cfg(feature = "language-c")]
+            Array(t, n) => rust::Type::Ffi(crate::c::Type::Array(
+                Box::new(crate::c::Type::try_from(t.to_rust()?).ok()?),
+                Some(*n),
+            )),
+            #[cfg(not(feature = "language-c"))]
+            Array(_, _) => return None,
+            Seq(t) => rust::Type::Vec(convert(t)?),
+            HashSet(t) => rust::Type::Set(convert(t)?),
+            Table(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Option(t) => rust::Type::Option(convert(t)?),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(
+                ts.iter()
+                    .map(|t| t.to_rust())
+                    .collect::<core::option::Option<_>>()?,
+            ),
+            Ptr(t) => rust::Type::PtrMut(convert(t)?),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -0,0 +1,238 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    c,
+    parse::{generic, split},
+    prelude::{fmt, format, Box, Cow, Named},
+    rust,
+};
+
+/// See: https://developer.apple.com/documentation/objectivec
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://en.cppreference.com/w/c/language/type
+    Void,
+
+    /// See: https://developer.apple.com/documentation/objectivec/id
+    Id,
+
+    /// See: https://developer.apple.com/documentation/objectivec/class
+    Class,
+
+    /// See: https://developer.apple.com/documentation/objectivec/sel
+    Sel,
+
+    /// See: https://developer.apple.com/documentation/objectivec/bool
+    Bool,
+
+    /// See: https://developer.apple.com/documentation/objectivec/nsinteger
+    NSInteger,
+
+    /// See: https://developer.apple.com/documentation/objectivec/nsuinteger
+    NSUInteger,
+
+    /// See: https://developer.apple.com/documentation/corefoundation/cgfloat
+    CGFloat,
+
+    /// See: https://developer.apple.com/documentation/foundation/nsnumber
+    NSNumber,
+
+    /// See: https://developer.apple.com/documentation/foundation/nsstring
+    NSString,
+
+    /// See: https://developer.apple.com/documentation/foundation/nsdata
+    NSData,
+
+    /// See: https://developer.apple.com/documentation/foundation/nsarray
+    NSArray(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/foundation/nsset
+    NSSet(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/foundation/nsdictionary
+    NSDictionary(Box<Type>, Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/designating-nullability-in-objective-c-apis
+    Nullable(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/designating-nullability-in-objective-c-apis
+    Nonnull(Box<Type>),
+
+    /// A plain C type, which Objective-C includes as a strict superset of C.
+    Ffi(c::Type),
+}
+
+impl Type {
+    /// Checks whether this is an object type, which can be stored in a
+    /// Foundation collection and qualified as `nullable` or `nonnull`.
+    pub fn is_object(&self) -> bool {
+        use Type::*;
+        matches!(
+            self,
+            Id | Class | NSNumber | NSString | NSData | NSArray(_) | NSSet(_) | NSDictionary(_, _)
+        )
+    }
+
+    /// Converts a Rust type into an object type, boxing scalars as
+    /// `NSNumber`, for use in collections and nullable positions.
+    fn object(input: rust::Type) -> Result<Self, ()> {
+        use Type::*;
+        match Self::try_from(input)? {
+            t if t.is_object() => Ok(t),
+            Bool | NSInteger | NSUInteger | CGFloat => Ok(NSNumber),
+            Ffi(c::Type::Array(_, _) | c::Type::Ptr(_) | c::Type::PtrMut(_) | c::Type::Void) => {
+                Err(())
+            }
+            Ffi(_) => Ok(NSNumber),
+            _ => Err(()),
+        }
+    }
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        if let Some(t) = input.strip_prefix("nullable ") {
+            return Ok(Nullable(Box::new(t.parse()?)));
+        }
+        if let Some(t) = input.strip_prefix("nonnull ") {
+            return Ok(Nonnull(Box::new(t.parse()?)));
+        }
+        if let Some(t) = ["_Nullable", "__nullable"]
+            .iter()
+            .find_map(|q| input.strip_suffix(q))
+        {
+            return Ok(Nullable(Box::new(t.parse()?)));
+        }
+        if let Some(t) = ["_Nonnull", "__nonnull"]
+            .iter()
+            .find_map(|q| input.strip_suffix(q))
+        {
+            return Ok(Nonnull(Box::new(t.parse()?)));
+        }
+        Ok(match input {
+            "void" => Void,
+            "id" => Id,
+            "Class" => Class,
+            "SEL" => Sel,
+            "BOOL" => Bool,
+            "NSInteger" => NSInteger,
+            "NSUInteger" => NSUInteger,
+            "CGFloat" => CGFloat,
+            _ => {
+                let object = input.strip_suffix('*').map(str::trim_end);
+                match object {
+                    Some("NSNumber") => NSNumber,
+                    Some("NSString") => NSString,
+                    Some("NSData") => NSData,
+                    Some("NSArray") => NSArray(Box::new(Id)),
+                    Some("NSSet") => NSSet(Box::new(Id)),
+                    Some("NSDictionary") => NSDictionary(Box::new(Id), Box::new(Id)),
+                    Some(t) => {
+                        if let Some(t) = generic(t, "NSArray") {
+                            NSArray(Box::new(t.parse()?))
+                        } else if let Some(t) = generic(t, "NSSet") {
+                            NSSet(Box::new(t.parse()?))
+                        } else if let Some(kv) = generic(t, "NSDictionary") {
+                            match split(kv, ',')[..] {
+                                [k, v] => NSDictionary(Box::new(k.parse()?), Box::new(v.parse()?)),
+                                _ => return Err(()),
+                            }
+                        } else {
+                            Ffi(input.parse()?)
+                        }
+                    }
+                    None => Ffi(input.parse()?),
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Void => write!(f, "void"),
+            Id => write!(f, "id"),
+            Class => write!(f, "Class"),
+            Sel => write!(f, "SEL"),
+            Bool => write!(f, "BOOL"),
+            NSInteger => write!(f, "NSInteger"),
+            NSUInteger => write!(f, "NSUInteger"),
+            CGFloat => write!(f, "CGFloat"),
+            NSNumber => write!(f, "NSNumber *"),
+            NSString => write!(f, "NSString *"),
+            NSData => write!(f, "NSData *"),
+            NSArray(t) => write!(f, "NSArray<{}> *", t),
+            NSSet(t) => write!(f, "NSSet<{}> *", t),
+            NSDictionary(k, v) => write!(f, "NSDictionary<{}, {}> *", k, v),
+            Nullable(t) => write!(f, "nullable {}", t),
+            Nonnull(t) => write!(f, "nonnull {}", t),
+            Ffi(t) => write!(f, "{}", t),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        Ok(match input {
+            rust::Type::Any => Id,
+            rust::Type::Unit => Void,
+            rust::Type::Bool => Bool,
+            rust::Type::Isize => NSInteger,
+            rust::Type::Usize => NSUInteger,
+            rust::Type::Str | rust::Type::String => NSString,
+            rust::Type::Vec(t) if *t == rust::Type::U8 => NSData,
+            rust::Type::Vec(t) => NSArray(Box::new(Self::object(*t)?)),
+            rust::Type::Set(t) => NSSet(Box::new(Self::object(*t)?)),
+            rust::Type::Map(k, v) => {
+                NSDictionary(Box::new(Self::object(*k)?), Box::new(Self::object(*v)?))
+            }
+            rust::Type::Option(t) => Nullable(Box::new(Self::object(*t)?)),
+            rust::Type::Ffi(t) => Ffi(t),
+            t => Ffi(c::Type::try_from(t)?),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Void => rust::Type::Unit,
+            Id => rust::Type::Any,
+            Class | Sel | NSNumber => return None,
+            Bool => rust::Type::Bool,
+            NSInteger => rust::Type::Isize,
+            NSUInteger => rust::Type::Usize,
+            CGFloat => rust::Type::F64, // on 64-bit platforms
+            NSString => rust::Type::String,
+            NSData => rust::Type::Vec(Box::new(rust::Type::U8)),
+            NSArray(t) => rust::Type::Vec(convert(t)?),
+            NSSet(t) => rust::Type::Set(convert(t)?),
+            NSDictionary(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Nullable(t) => rust::Type::Option(convert(t)?),
+            Nonnull(t) => t.to_rust()?,
+            Ffi(c::Type::Float) => rust::Type::F32,
+            Ffi(c::Type::Double) => rust::Type::F64,
+            Ffi(t) => t.to_rust()?,
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -0,0 +1,130 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    c,
+    parse::enclosed,
+    prelude::{fmt, Box},
+};
+
+/// A `ctypes` type description for a C type, as in `ptr char` or
+/// `array 4 uint8_t`.
+///
+/// See: https://ocaml.org/p/ctypes/latest/doc/Ctypes_types/module-type-TYPE/index.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Ctypes(pub c::Type);
+
+impl Ctypes {
+    /// Returns this type description formatted as a function argument,
+    /// as in the `(ptr int)` of `ptr (ptr int)`.
+    fn arg(&self) -> Arg<'_> {
+        Arg(self)
+    }
+}
+
+struct Arg<'a>(&'a Ctypes);
+
+impl fmt::Display for Arg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use c::Type::*;
+        match &self.0 .0 {
+            Ptr(t) if **t == Char => write!(f, "{}", self.0),
+            Array(_, _) | Ptr(_) | PtrMut(_) => write!(f, "({})", self.0),
+            _ => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl core::str::FromStr for Ctypes {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use c::Type::*;
+        let input = input.trim();
+        if let Some(t) = enclosed(input, '(', ')') {
+            return t.parse();
+        }
+        let parse = |t: &str| -> Result<c::Type, ()> { Ok(t.parse::<Ctypes>()?.0) };
+        Ok(Ctypes(match input {
+            "void" => Void,
+            "bool" => Bool,
+            "float" => Float,
+            "double" => Double,
+            "char" => Char,
+            "schar" => SChar,
+            "short" => Short,
+            "int" => Int,
+            "long" => Long,
+            "llong" => LongLong,
+            "PosixTypes.ssize_t" => SSize_t,
+            "uchar" => UChar,
+            "ushort" => UShort,
+            "uint" => UInt,
+            "ulong" => ULong,
+            "ullong" => ULongLong,
+            "size_t" => Size_t,
+            "int8_t" => Int8_t,
+            "int16_t" => Int16_t,
+            "int32_t" => Int32_t,
+            "int64_t" => Int64_t,
+            "uint8_t" => UInt8_t,
+            "uint16_t" => UInt16_t,
+            "uint32_t" => UInt32_t,
+            "uint64_t" => UInt64_t,
+            "string" => Ptr(Box::new(Char)),
+            #[cfg(feature = "libc")]
+            "PosixTypes.time_t" => Time_t,
+            _ => {
+                if let Some(t) = input.strip_prefix("ptr ") {
+                    PtrMut(Box::new(parse(t)?))
+                } else if let Some((n, t)) = input
+                    .strip_prefix("array ")
+                    .and_then(|t| t.trim_start().split_once(' '))
+                {
+                    Array(Box::new(parse(t)?), Some(n.parse().map_err(|_| ())?))
+                } else {
+                    return Err(());
+                }
+            }
+        }))
+    }
+}
+
+impl fmt::Display for Ctypes {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use c::Type::*;
+        let ctypes = |t: &c::Type| Ctypes(t.clone());
+        match &self.0 {
+            Void => write!(f, "void"),
+            Bool => write!(f, "bool"),
+            Float => write!(f, "float"),
+            Double => write!(f, "double"),
+            Char => write!(f, "char"),
+            SChar => write!(f, "schar"),
+            Short => write!(f, "short"),
+            Int => write!(f, "int"),
+            Long => write!(f, "long"),
+            LongLong => write!(f, "llong"),
+            SSize_t => write!(f, "PosixTypes.ssize_t"),
+            UChar => write!(f, "uchar"),
+            UShort => write!(f, "ushort"),
+            UInt => write!(f, "uint"),
+            ULong => write!(f, "ulong"),
+            ULongLong => write!(f, "ullong"),
+            Size_t => write!(f, "size_t"),
+            Int8_t => write!(f, "int8_t"),
+            Int16_t => write!(f, "int16_t"),
+            Int32_t => write!(f, "int32_t"),
+            Int64_t => write!(f, "int64_t"),
+            UInt8_t => write!(f, "uint8_t"),
+            UInt16_t => write!(f, "uint16_t"),
+            UInt32_t => write!(f, "uint32_t"),
+            UInt64_t => write!(f, "uint64_t"),
+            Ptr(t) if **t == Char => write!(f, "string"),
+            Array(t, Some(n)) => write!(f, "array {} {}", n, ctypes(t).arg()),
+            Array(t, None) | Ptr(t) | PtrMut(t) => write!(f, "ptr {}", ctypes(t).arg()),
+            #[cfg(feature = "libc")]
+            Time_t => write!(f, "PosixTypes.time_t"),
+        }
+    }
+}
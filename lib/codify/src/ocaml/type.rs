@@ -0,0 +1,309 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{enclosed, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://ocaml.org/manual/latest/coreexamples.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://ocaml.org/manual/latest/api/Unit.html
+    Unit,
+
+    /// See: https://ocaml.org/manual/latest/api/Bool.html
+    Bool,
+
+    /// See: https://ocaml.org/manual/latest/api/Char.html
+    Char,
+
+    /// See: https://ocaml.org/manual/latest/api/Int.html
+    Int,
+
+    /// See: https://ocaml.org/manual/latest/api/Int32.html
+    Int32,
+
+    /// See: https://ocaml.org/manual/latest/api/Int64.html
+    Int64,
+
+    /// See: https://ocaml.org/manual/latest/api/Nativeint.html
+    NativeInt,
+
+    /// See: https://ocaml.org/manual/latest/api/Float.html
+    Float,
+
+    /// See: https://ocaml.org/manual/latest/api/String.html
+    String,
+
+    /// See: https://ocaml.org/manual/latest/api/Bytes.html
+    Bytes,
+
+    /// See: https://ocaml.org/manual/latest/api/List.html
+    List(Box<Type>),
+
+    /// See: https://ocaml.org/manual/latest/api/Array.html
+    Array(Box<Type>),
+
+    /// See: https://ocaml.org/manual/latest/api/Option.html
+    Option(Box<Type>),
+
+    /// See: https://ocaml.org/manual/latest/types.html#sss:typexpr-tuple
+    Tuple(Vec<Type>),
+
+    /// See: https://ocaml.org/manual/latest/api/Result.html
+    Result(Box<Type>, Box<Type>),
+
+    /// The OCaml type of a C value as seen through `ctypes`, as in
+    /// `Unsigned.uint32` or `int ptr`.
+    ///
+    /// See: https://ocaml.org/p/ctypes/latest/doc/Ctypes_types/module-type-TYPE/index.html
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl Type {
+    /// Returns this type formatted as an operand of a tuple or of a type
+    /// constructor, as in the `(int * int)` of `(int * int) list`.
+    fn arg(&self) -> Arg<'_> {
+        Arg(self)
+    }
+}
+
+struct Arg<'a>(&'a Type);
+
+impl fmt::Display for Arg<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Type::Tuple(_) => write!(f, "({})", self.0),
+            t => write!(f, "{}", t),
+        }
+    }
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let ts = split(input, '*');
+        if ts.len() > 1 {
+            return Ok(Tuple(ts.into_iter().map(str::parse).try_collect()?));
+        }
+        if let Some(t) = enclosed(input, '(', ')') {
+            return t.parse();
+        }
+        let words: Vec<_> = split(input, ' ')
+            .into_iter()
+            .filter(|w| !w.is_empty())
+            .collect();
+        if let [.., constructor] = words[..] {
+            if words.len() > 1 {
+                let arg = input[..input.len() - constructor.len()].trim();
+                let boxed =
+                    |t: &str| -> core::result::Result<Box<Type>, ()> { Ok(Box::new(t.parse()?)) };
+                return Ok(match constructor {
+                    "list" => List(boxed(arg)?),
+                    "array" => Array(boxed(arg)?),
+                    "option" => Option(boxed(arg)?),
+                    "result" => match split(enclosed(arg, '(', ')').ok_or(())?, ',')[..] {
+                        [t, e] => Result(boxed(t)?, boxed(e)?),
+                        _ => return Err(()),
+                    },
+                    #[cfg(feature = "language-c")]
+                    "ptr" | "carray" => {
+                        let t = arg.parse::<Type>()?.to_c().ok_or(())?;
+                        match constructor {
+                            "ptr" => Ffi(crate::c::Type::PtrMut(Box::new(t))),
+                            _ => Ffi(crate::c::Type::Array(Box::new(t), None)),
+                        }
+                    }
+                    _ => return Err(()),
+                });
+            }
+        }
+        Ok(match input {
+            "unit" => Unit,
+            "bool" => Bool,
+            "char" => Char,
+            "int" => Int,
+            "int32" => Int32,
+            "int64" => Int64,
+            "nativeint" => NativeInt,
+            "float" => Float,
+            "string" => String,
+            "bytes" => Bytes,
+            #[cfg(feature = "language-c")]
+            "Signed.long" => Ffi(crate::c::Type::Long),
+            #[cfg(feature = "language-c")]
+            "Signed.llong" => Ffi(crate::c::Type::LongLong),
+            #[cfg(feature = "language-c")]
+            "PosixTypes.ssize_t" => Ffi(crate::c::Type::SSize_t),
+            #[cfg(feature = "language-c")]
+            "Unsigned.uchar" => Ffi(crate::c::Type::UChar),
+            #[cfg(feature = "language-c")]
+            "Unsigned.ushort" => Ffi(crate::c::Type::UShort),
+            #[cfg(feature = "language-c")]
+            "Unsigned.uint" => Ffi(crate::c::Type::UInt),
+            #[cfg(feature = "language-c")]
+            "Unsigned.ulong" => Ffi(crate::c::Type::ULong),
+            #[cfg(feature = "language-c")]
+            "Unsigned.ullong" => Ffi(crate::c::Type::ULongLong),
+            #[cfg(feature = "language-c")]
+            "Unsigned.size_t" => Ffi(crate::c::Type::Size_t),
+            #[cfg(feature = "language-c")]
+            "Unsigned.uint32" => Ffi(crate::c::Type::UInt32_t),
+            #[cfg(feature = "language-c")]
+            "Unsigned.uint64" => Ffi(crate::c::Type::UInt64_t),
+            #[cfg(all(feature = "language-c", feature = "libc"))]
+            "PosixTypes.time_t" => Ffi(crate::c::Type::Time_t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Unit => write!(f, "unit"),
+            Bool => write!(f, "bool"),
+            Char => write!(f, "char"),
+            Int => write!(f, "int"),
+            Int32 => write!(f, "int32"),
+            Int64 => write!(f, "int64"),
+            NativeInt => write!(f, "nativeint"),
+            Float => write!(f, "float"),
+            String => write!(f, "string"),
+            Bytes => write!(f, "bytes"),
+            List(t) => write!(f, "{} list", t.arg()),
+            Array(t) => write!(f, "{} array", t.arg()),
+            Option(t) => write!(f, "{} option", t.arg()),
+            Tuple(ts) => write!(f, "{}", ts.iter().map(Type::arg).format(" * ")),
+            Result(t, e) => write!(f, "({}, {}) result", t, e),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://ocaml.org/p/ctypes/latest/doc/Ctypes_types/module-type-TYPE/index.html
+                crate::c::Type::Void => write!(f, "unit"),
+                crate::c::Type::Bool => write!(f, "bool"),
+                crate::c::Type::Float | crate::c::Type::Double => write!(f, "float"),
+                crate::c::Type::Char => write!(f, "char"),
+                crate::c::Type::SChar | crate::c::Type::Short | crate::c::Type::Int => {
+                    write!(f, "int")
+                }
+                crate::c::Type::Long => write!(f, "Signed.long"),
+                crate::c::Type::LongLong => write!(f, "Signed.llong"),
+                crate::c::Type::SSize_t => write!(f, "PosixTypes.ssize_t"),
+                crate::c::Type::UChar => write!(f, "Unsigned.uchar"),
+                crate::c::Type::UShort => write!(f, "Unsigned.ushort"),
+                crate::c::Type::UInt => write!(f, "Unsigned.uint"),
+                crate::c::Type::ULong => write!(f, "Unsigned.ulong"),
+                crate::c::Type::ULongLong => write!(f, "Unsigned.ullong"),
+                crate::c::Type::Size_t => write!(f, "Unsigned.size_t"),
+                crate::c::Type::Int8_t
+                | crate::c::Type::Int16_t
+                | crate::c::Type::UInt8_t
+                | crate::c::Type::UInt16_t => write!(f, "int"),
+                crate::c::Type::Int32_t => write!(f, "int32"),
+                crate::c::Type::Int64_t => write!(f, "int64"),
+                crate::c::Type::UInt32_t => write!(f, "Unsigned.uint32"),
+                crate::c::Type::UInt64_t => write!(f, "Unsigned.uint64"),
+                crate::c::Type::Ptr(t) if **t == crate::c::Type::Char => write!(f, "string"),
+                crate::c::Type::Ptr(t) | crate::c::Type::PtrMut(t) => {
+                    write!(f, "{} ptr", Ffi((**t).clone()))
+                }
+                crate::c::Type::Array(t, _) => write!(f, "{} carray", Ffi((**t).clone())),
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "PosixTypes.time_t"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Unit => Unit,
+            rust::Type::Tuple(ts) if ts.is_empty() => Unit,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Bool,
+            rust::Type::F64 => Float,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::Isize => NativeInt,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Vec(t) if *t == rust::Type::U8 => Bytes,
+            rust::Type::Vec(t) => List(convert(*t)?),
+            rust::Type::Option(t) => Option(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            #[cfg(feature = "language-c")]
+            t => Ffi(crate::c::Type::try_from(t)?),
+            #[cfg(not(feature = "language-c"))]
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Unit => rust::Type::Unit,
+            Bool => rust::Type::Bool,
+            Char => rust::Type::U8, // an 8-bit byte, not a Unicode scalar value
+            Int => rust::Type::I64, // a 63-bit integer on 64-bit platforms
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            NativeInt => rust::Type::Isize,
+            Float => rust::Type::F64,
+            String => rust::Type::String,
+            Bytes => rust::Type::Vec(Box::new(rust::Type::U8)),
+            List(t) | Array(t) => rust::Type::Vec(convert(t)?),
+            Option(t) => rust::Type::Option(convert(t)?),
+            Tuple(ts) => rust::Type::Tuple(
+                ts.iter()
+                    .map(|t| t.to_rust())
+                    .collect::<core::option::Option<_>>()?,
+            ),
+            Result(_, _) => return None, // no `Result` in the Rust type model
+            #[cfg(feature = "language-c")]
+            Ffi(crate::c::Type::Float) => rust::Type::F32,
+            #[cfg(feature = "language-c")]
+            Ffi(t) => crate::ToRust::to_rust(t)?,
+        })
+    }
+}
+
+#[cfg(feature = "language-c")]
+impl Type {
+    /// Converts this type into the C type that `ctypes` would expose as it,
+    /// if any.
+    pub fn to_c(&self) -> Option<crate::c::Type> {
+        use crate::ToRust;
+        match self {
+            Type::Int => Some(crate::c::Type::Int),
+            Type::Char => Some(crate::c::Type::Char),
+            Type::Float => Some(crate::c::Type::Double),
+            Type::String => Some(crate::c::Type::Ptr(Box::new(crate::c::Type::Char))),
+            Type::Ffi(t) => Some(t.clone()),
+            t => crate::c::Type::try_from(t.to_rust()?).ok(),
+        }
+    }
+}
+
+impl crate::Type for Type {}
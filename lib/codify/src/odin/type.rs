@@ -0,0 +1,371 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::applied,
+    prelude::{fmt, format, Box, Cow, Named},
+    rust,
+};
+
+/// See: https://odin-lang.org/docs/overview/#basic-types
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Bool,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Int,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    I8,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    I16,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    I32,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    I64,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    I128,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Uint,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    U8,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    U16,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    U32,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    U64,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    U128,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Uintptr,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    F16,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    F32,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    F64,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Rune,
+
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    String,
+
+    /// A NUL-terminated string, as in C's `const char *`.
+    ///
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Cstring,
+
+    /// An untyped pointer, as in C's `void *`.
+    ///
+    /// See: https://odin-lang.org/docs/overview/#basic-types
+    Rawptr,
+
+    /// See: https://odin-lang.org/docs/overview/#fixed-arrays
+    Array(Box<Type>, usize),
+
+    /// See: https://odin-lang.org/docs/overview/#slices
+    Slice(Box<Type>),
+
+    /// See: https://odin-lang.org/docs/overview/#dynamic-arrays
+    Dynamic(Box<Type>),
+
+    /// See: https://odin-lang.org/docs/overview/#maps
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://odin-lang.org/docs/overview/#maybet
+    Maybe(Box<Type>),
+
+    /// See: https://odin-lang.org/docs/overview/#pointers
+    Ptr(Box<Type>),
+
+    /// See: https://odin-lang.org/docs/overview/#multi-pointers
+    MultiPtr(Box<Type>),
+
+    /// A C type from the `core:c` package, as in `c.int` or `c.size_t`.
+    ///
+    /// See: https://pkg.odin-lang.org/core/c/
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        Ok(match input {
+            "bool" => Bool,
+            "int" => Int,
+            "i8" => I8,
+            "i16" => I16,
+            "i32" => I32,
+            "i64" => I64,
+            "i128" => I128,
+            "uint" => Uint,
+            "u8" | "byte" => U8,
+            "u16" => U16,
+            "u32" => U32,
+            "u64" => U64,
+            "u128" => U128,
+            "uintptr" => Uintptr,
+            "f16" => F16,
+            "f32" => F32,
+            "f64" => F64,
+            "rune" => Rune,
+            "string" => String,
+            "cstring" => Cstring,
+            "rawptr" => Rawptr,
+            _ => {
+                if let Some(t) = input.strip_prefix("[^]") {
+                    MultiPtr(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix('^') {
+                    Ptr(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix("[]") {
+                    Slice(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix("[dynamic]") {
+                    Dynamic(Box::new(t.parse()?))
+                } else if let Some(t) = applied(input, "Maybe", '(', ')') {
+                    Maybe(Box::new(t.parse()?))
+                } else if let Some((k, v)) =
+                    input.strip_prefix("map[").and_then(|kv| kv.split_once(']'))
+                {
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some((n, t)) = input.strip_prefix('[').and_then(|t| t.split_once(']'))
+                {
+                    Array(Box::new(t.parse()?), n.trim().parse().map_err(|_| ())?)
+                } else if input.starts_with("c.") {
+                    #[cfg(not(feature = "language-c"))]
+                    return Err(());
+                    #[cfg(feature = "language-c")]
+                    Ffi(match input {
+                        "c.bool" => crate::c::Type::Bool,
+                        "c.char" => crate::c::Type::Char,
+                        "c.schar" => crate::c::Type::SChar,
+                        "c.short" => crate::c::Type::Short,
+                        "c.int" => crate::c::Type::Int,
+                        "c.long" => crate::c::Type::Long,
+                        "c.longlong" => crate::c::Type::LongLong,
+                        "c.uchar" => crate::c::Type::UChar,
+                        "c.ushort" => crate::c::Type::UShort,
+                        "c.uint" => crate::c::Type::UInt,
+                        "c.ulong" => crate::c::Type::ULong,
+                        "c.ulonglong" => crate::c::Type::ULongLong,
+                        "c.size_t" => crate::c::Type::Size_t,
+                        "c.ssize_t" => crate::c::Type::SSize_t,
+                        "c.float" => crate::c::Type::Float,
+                        "c.double" => crate::c::Type::Double,
+                        "c.int8_t" => crate::c::Type::Int8_t,
+                        "c.int16_t" => crate::c::Type::Int16_t,
+                        "c.int32_t" => crate::c::Type::Int32_t,
+                        "c.int64_t" => crate::c::Type::Int64_t,
+                        "c.uint8_t" => crate::c::Type::UInt8_t,
+                        "c.uint16_t" => crate::c::Type::UInt16_t,
+                        "c.uint32_t" => crate::c::Type::UInt32_t,
+                        "c.uint64_t" => crate::c::Type::UInt64_t,
+                        _ => return Err(()),
+                    })
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Bool => write!(f, "bool"),
+            Int => write!(f, "int"),
+            I8 => write!(f, "i8"),
+            I16 => write!(f, "i16"),
+            I32 => write!(f, "i32"),
+            I64 => write!(f, "i64"),
+            I128 => write!(f, "i128"),
+            Uint => write!(f, "uint"),
+            U8 => write!(f, "u8"),
+            U16 => write!(f, "u16"),
+            U32 => write!(f, "u32"),
+            U64 => write!(f, "u64"),
+            U128 => write!(f, "u128"),
+            Uintptr => write!(f, "uintptr"),
+            F16 => write!(f, "f16"),
+            F32 => write!(f, "f32"),
+            F64 => write!(f, "f64"),
+            Rune => write!(f, "rune"),
+            String => write!(f, "string"),
+            Cstring => write!(f, "cstring"),
+            Rawptr => write!(f, "rawptr"),
+            Array(t, n) => write!(f, "[{}]{}", n, t),
+            Slice(t) => write!(f, "[]{}", t),
+            Dynamic(t) => write!(f, "[dynamic]{}", t),
+            Map(k, v) => write!(f, "map[{}]{}", k, v),
+            Maybe(t) => write!(f, "Maybe({})", t),
+            Ptr(t) => write!(f, "^{}", t),
+            MultiPtr(t) => write!(f, "[^]{}", t),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://pkg.odin-lang.org/core/c/
+                // Odin has no `void`, so use its zero-sized type instead:
+                crate::c::Type::Void => write!(f, "struct{{}}"),
+                crate::c::Type::Bool => write!(f, "c.bool"),
+                crate::c::Type::Float => write!(f, "c.float"),
+                crate::c::Type::Double => write!(f, "c.double"),
+                crate::c::Type::Char => write!(f, "c.char"),
+                crate::c::Type::SChar => write!(f, "c.schar"),
+                crate::c::Type::Short => write!(f, "c.short"),
+                crate::c::Type::Int => write!(f, "c.int"),
+                crate::c::Type::Long => write!(f, "c.long"),
+                crate::c::Type::LongLong => write!(f, "c.longlong"),
+                crate::c::Type::SSize_t => write!(f, "c.ssize_t"),
+                crate::c::Type::UChar => write!(f, "c.uchar"),
+                crate::c::Type::UShort => write!(f, "c.ushort"),
+                crate::c::Type::UInt => write!(f, "c.uint"),
+                crate::c::Type::ULong => write!(f, "c.ulong"),
+                crate::c::Type::ULongLong => write!(f, "c.ulonglong"),
+                crate::c::Type::Size_t => write!(f, "c.size_t"),
+                crate::c::Type::Int8_t => write!(f, "c.int8_t"),
+                crate::c::Type::Int16_t => write!(f, "c.int16_t"),
+                crate::c::Type::Int32_t => write!(f, "c.int32_t"),
+                crate::c::Type::Int64_t => write!(f, "c.int64_t"),
+                crate::c::Type::UInt8_t => write!(f, "c.uint8_t"),
+                crate::c::Type::UInt16_t => write!(f, "c.uint16_t"),
+                crate::c::Type::UInt32_t => write!(f, "c.uint32_t"),
+                crate::c::Type::UInt64_t => write!(f, "c.uint64_t"),
+                crate::c::Type::Array(t, None) => write!(f, "[^]{}", Ffi((**t).clone())),
+                crate::c::Type::Array(t, Some(n)) => write!(f, "[{}]{}", n, Ffi((**t).clone())),
+                crate::c::Type::Ptr(t) | crate::c::Type::PtrMut(t) => match **t {
+                    crate::c::Type::Char => write!(f, "cstring"),
+                    crate::c::Type::Void => write!(f, "rawptr"),
+                    _ => write!(f, "^{}", Ffi((**t).clone())),
+                },
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "libc.time_t"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => F32,
+            rust::Type::F64 => F64,
+            rust::Type::I8 => I8,
+            rust::Type::I16 => I16,
+            rust::Type::I32 => I32,
+            rust::Type::I64 => I64,
+            rust::Type::I128 => I128,
+            rust::Type::Isize => Int,
+            rust::Type::U8 => U8,
+            rust::Type::U16 => U16,
+            rust::Type::U32 => U32,
+            rust::Type::U64 => U64,
+            rust::Type::U128 => U128,
+            rust::Type::Usize => Uint,
+            rust::Type::Char => Rune,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Maybe(convert(*t)?),
+            rust::Type::Vec(t) => Dynamic(convert(*t)?),
+            rust::Type::Map(k, v) => Map(convert(*k)?, convert(*v)?),
+            // Odin strings already pair a data pointer with a length:
+            rust::Type::Box(t) | rust::Type::Ref(t) if *t == rust::Type::Str => String,
+            rust::Type::Box(t) | rust::Type::Ref(t) | rust::Type::RefMut(t) => Ptr(convert(*t)?),
+            rust::Type::Ptr(t) | rust::Type::PtrMut(t) => MultiPtr(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(crate::c::Type::Ptr(t) | crate::c::Type::PtrMut(t))
+                if *t == crate::c::Type::Char =>
+            {
+                Cstring
+            }
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(crate::c::Type::Ptr(t) | crate::c::Type::PtrMut(t))
+                if *t == crate::c::Type::Void =>
+            {
+                Rawptr
+            }
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Bool => rust::Type::Bool,
+            Int => rust::Type::Isize,
+            I8 => rust::Type::I8,
+            I16 => rust::Type::I16,
+            I32 => rust::Type::I32,
+            I64 => rust::Type::I64,
+            I128 => rust::Type::I128,
+            Uint | Uintptr => rust::Type::Usize,
+            U8 => rust::Type::U8,
+            U16 => rust::Type::U16,
+            U32 => rust::Type::U32,
+            U64 => rust::Type::U64,
+            U128 => rust::Type::U128,
+            F16 => return None, // no stable equivalent in Rust
+            F32 => rust::Type::F32,
+            F64 => rust::Type::F64,
+            Rune => rust::Type::Char,
+            String => rust::Type::String,
+            #[cfg(feature = "language-c")]
+            Cstring => rust::Type::Ffi(crate::c::Type::Ptr(Box::new(crate::c::Type::Char))),
+            #[cfg(feature = "language-c")]
+            Rawptr => rust::Type::Ffi(crate::c::Type::PtrMut(Box::new(crate::c::Type::Void))),
+            #[cfg(not(feature = "language-c"))]
+            Cstring | Rawptr => return None,
+            #[cfg(feature = "language-c")]
+            Array(t, n) => rust::Type::Ffi(crate::c::Type::Array(
+                Box::new(crate::c::Type::try_from(t.to_rust()?).ok()?),
+                Some(*n),
+            )),
+            #[cfg(not(feature = "language-c"))]
+            Array(_, _) => return None,
+            Slice(t) | Dynamic(t) => rust::Type::Vec(convert(t)?),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Maybe(t) => rust::Type::Option(convert(t)?),
+            Ptr(t) => rust::Type::Box(convert(t)?),
+            MultiPtr(t) => rust::Type::PtrMut(convert(t)?),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -0,0 +1,91 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::prelude::Vec;
+
+/// Returns the type argument(s) of `input` if it is an application of the
+/// generic type `name`, as in `Vec<u8>`.
+pub(crate) fn generic<'a>(input: &'a str, name: &str) -> Option<&'a str> {
+    applied(input, name, '<', '>')
+}
+
+/// Returns the type argument(s) of `input` if it is an application of the
+/// generic type `name` with the brackets `open` and `close`, as in
+/// `Vector{Int64}`.
+pub(crate) fn applied<'a>(input: &'a str, name: &str, open: char, close: char) -> Option<&'a str> {
+    enclosed(input.strip_prefix(name)?, open, close)
+}
+
+/// Returns the contents of `input` if it is entirely enclosed by the
+/// brackets `open` and `close`, as in `[u8]` but not `[u8] | [u16]`.
+pub(crate) fn enclosed(input: &str, open: char, close: char) -> Option<&str> {
+    let input = input.trim().strip_prefix(open)?.strip_suffix(close)?;
+    let mut depth = 0usize;
+    for (prev, c) in chars(input) {
+        if c == open && !is_arrow(prev, c) {
+            depth += 1;
+        } else if c == close && !is_arrow(prev, c) {
+            depth = depth.checked_sub(1)?;
+        }
+    }
+    (depth == 0).then(|| input.trim())
+}
+
+/// Splits `input` on each occurrence of `separator` that is not nested
+/// inside brackets or quotes, as in `u8, Map<u16, u32>` or `'a|b' | 'c'`.
+pub(crate) fn split(input: &str, separator: char) -> Vec<&str> {
+    let mut result = Vec::new();
+    let (mut depth, mut start, mut prev, mut quote) = (0isize, 0, None, None);
+    for (i, c) in input.char_indices() {
+        match c {
+            _ if Some(c) == quote && prev != Some('\\') => quote = None,
+            _ if quote.is_some() => {}
+            '"' | '\'' => quote = Some(c),
+            _ if is_arrow(prev, c) => {}
+            '(' | '[' | '{' | '<' => depth += 1,
+            ')' | ']' | '}' | '>' => depth -= 1,
+            _ if c == separator && depth == 0 => {
+                result.push(input[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => {}
+        }
+        prev = Some(c);
+    }
+    result.push(input[start..].trim());
+    result
+}
+
+/// Combines the alternatives of a union type, as in `A | B`, collapsing a
+/// single alternative to itself, and exactly two alternatives of which one
+/// is `none` to the `optional` of the other.
+#[cfg(any(
+    feature = "language-lua",
+    feature = "language-php",
+    feature = "language-python"
+))]
+pub(crate) fn union<T: PartialEq>(
+    mut types: Vec<T>,
+    none: &T,
+    optional: impl FnOnce(T) -> T,
+    union: impl FnOnce(Vec<T>) -> T,
+) -> T {
+    match &types[..] {
+        [_] => types.remove(0),
+        [a, b] if (a == none) != (b == none) => {
+            types.retain(|t| t != none);
+            optional(types.remove(0))
+        }
+        _ => union(types),
+    }
+}
+
+/// Iterates over the characters of `input` along with their predecessors.
+fn chars(input: &str) -> impl Iterator<Item = (Option<char>, char)> + '_ {
+    let prevs = core::iter::once(None).chain(input.chars().map(Some));
+    prevs.zip(input.chars())
+}
+
+/// Checks whether `c` terminates an arrow such as `->` or `=>`.
+fn is_arrow(prev: Option<char>, c: char) -> bool {
+    c == '>' && matches!(prev, Some('-') | Some('='))
+}
@@ -0,0 +1,196 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{generic, split, union},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://www.php.net/manual/en/language.types.type-system.php
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://www.php.net/manual/en/language.types.void.php
+    Void,
+
+    /// See: https://www.php.net/manual/en/language.types.null.php
+    Null,
+
+    /// See: https://www.php.net/manual/en/language.types.mixed.php
+    Mixed,
+
+    /// See: https://www.php.net/manual/en/language.types.boolean.php
+    Bool,
+
+    /// See: https://www.php.net/manual/en/language.types.integer.php
+    Int,
+
+    /// See: https://www.php.net/manual/en/language.types.float.php
+    Float,
+
+    /// See: https://www.php.net/manual/en/language.types.string.php
+    String,
+
+    /// See: https://www.php.net/manual/en/language.types.array.php
+    Array,
+
+    /// An array of values with unspecified keys, as in `array<int>`.
+    ///
+    /// See: https://phpstan.org/writing-php-code/phpdoc-types#general-arrays
+    ArrayOf(Box<Type>),
+
+    /// An array of key-value pairs, as in `array<string, int>`.
+    ///
+    /// See: https://phpstan.org/writing-php-code/phpdoc-types#general-arrays
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://phpstan.org/writing-php-code/phpdoc-types#lists
+    List(Box<Type>),
+
+    /// See: https://www.php.net/manual/en/language.types.type-system.php#language.types.type-system.composite.nullable
+    Nullable(Box<Type>),
+
+    /// See: https://www.php.net/manual/en/language.types.type-system.php#language.types.type-system.composite.union
+    Union(Vec<Type>),
+
+    /// A C type for use with `FFI::cdef`, `FFI::new`, and `FFI::type`.
+    ///
+    /// See: https://www.php.net/manual/en/class.ffi.php
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let variants = split(input, '|');
+        if variants.len() > 1 {
+            let types = variants.into_iter().map(str::parse).try_collect()?;
+            return Ok(union(types, &Null, |t| Nullable(Box::new(t)), Union));
+        }
+        if let Some(t) = input.strip_prefix('?') {
+            return Ok(Nullable(Box::new(t.parse()?)));
+        }
+        Ok(match input {
+            "void" => Void,
+            "null" => Null,
+            "mixed" => Mixed,
+            "bool" | "boolean" => Bool,
+            "int" | "integer" => Int,
+            "float" | "double" => Float,
+            "string" => String,
+            "array" => Array,
+            _ => {
+                if let Some(t) = generic(input, "list") {
+                    List(Box::new(t.parse()?))
+                } else if let Some(ts) = generic(input, "array") {
+                    match split(ts, ',')[..] {
+                        [t] => ArrayOf(Box::new(t.parse()?)),
+                        [k, v] => Map(Box::new(k.parse()?), Box::new(v.parse()?)),
+                        _ => return Err(()),
+                    }
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Void => write!(f, "void"),
+            Null => write!(f, "null"),
+            Mixed => write!(f, "mixed"),
+            Bool => write!(f, "bool"),
+            Int => write!(f, "int"),
+            Float => write!(f, "float"),
+            String => write!(f, "string"),
+            Array => write!(f, "array"),
+            ArrayOf(t) => write!(f, "array<{}>", t),
+            Map(k, v) => write!(f, "array<{}, {}>", k, v),
+            List(t) => write!(f, "list<{}>", t),
+            // A union type cannot be made nullable with `?`:
+            Nullable(t) if matches!(**t, Union(_)) => write!(f, "{}|null", t),
+            Nullable(t) => write!(f, "?{}", t),
+            Union(ts) => write!(f, "{}", ts.iter().format("|")),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://www.php.net/manual/en/ffi.examples-basic.php
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "int64_t"),
+                crate::c::Type::Array(t, None) => write!(f, "{}[]", Ffi((**t).clone())),
+                crate::c::Type::Array(t, Some(n)) => write!(f, "{}[{}]", Ffi((**t).clone()), n),
+                crate::c::Type::Ptr(t) => write!(f, "const {}*", Ffi((**t).clone())),
+                crate::c::Type::PtrMut(t) => write!(f, "{}*", Ffi((**t).clone())),
+                t => write!(f, "{}", t),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Mixed,
+            rust::Type::Unit => Void,
+            rust::Type::Bool => Bool,
+            rust::Type::F32 | rust::Type::F64 => Float,
+            rust::Type::I8 | rust::Type::I16 | rust::Type::I32 | rust::Type::I64 => Int,
+            rust::Type::U8 | rust::Type::U16 | rust::Type::U32 => Int,
+            rust::Type::Isize => Int,
+            rust::Type::Char | rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Nullable(convert(*t)?),
+            rust::Type::Vec(t) => List(convert(*t)?),
+            rust::Type::Map(k, v) => match Self::try_from(*k)? {
+                // Array keys are always integers or strings:
+                k @ (Int | String) => Map(Box::new(k), convert(*v)?),
+                _ => return Err(()),
+            },
+            #[cfg(feature = "language-c")]
+            t @ (rust::Type::Ptr(_) | rust::Type::PtrMut(_)) => Ffi(crate::c::Type::try_from(t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Void => rust::Type::Unit,
+            Mixed => rust::Type::Any,
+            Bool => rust::Type::Bool,
+            Int => rust::Type::I64,
+            Float => rust::Type::F64,
+            String => rust::Type::String,
+            List(t) => rust::Type::Vec(convert(t)?),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Nullable(t) => rust::Type::Option(convert(t)?),
+            Null | Array | ArrayOf(_) | Union(_) => return None,
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
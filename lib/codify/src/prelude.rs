@@ -16,6 +16,7 @@ pub use alloc::{
     boxed::Box,
     fmt, format,
     string::{String, ToString},
+    vec,
     vec::Vec,
 };
 
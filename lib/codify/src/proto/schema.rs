@@ -0,0 +1,296 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::{
+    parse::split,
+    prelude::{fmt, format, String, ToString, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// A message declaration, given as its fields' names, types, and numbers.
+///
+/// See: https://protobuf.dev/programming-guides/proto3/#simple
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Message {
+    pub name: String,
+    pub fields: Vec<(String, Type, u32)>,
+}
+
+impl Message {
+    pub fn new(name: impl Into<String>, fields: Vec<(String, Type, u32)>) -> Self {
+        Self {
+            name: name.into(),
+            fields,
+        }
+    }
+
+    /// Converts the fields of a Rust structure into a message, numbering
+    /// them in declaration order.
+    pub fn from_rust(
+        name: impl Into<String>,
+        fields: Vec<(String, rust::Type)>,
+    ) -> Result<Self, ()> {
+        let fields = fields
+            .into_iter()
+            .zip(1..)
+            .map(|((name, t), number)| Ok((name, Type::try_from(t)?, number)))
+            .try_collect()?;
+        Ok(Self::new(name, fields))
+    }
+
+    /// Converts the fields of this message into the fields of a Rust
+    /// structure, in declaration order.
+    pub fn to_rust(&self) -> Option<Vec<(String, rust::Type)>> {
+        use crate::ToRust;
+        self.fields
+            .iter()
+            .map(|(name, t, _)| Some((name.clone(), t.to_rust()?)))
+            .collect()
+    }
+}
+
+impl fmt::Display for Message {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "message {} {{", self.name)?;
+        for (name, t, number) in &self.fields {
+            write!(f, " {} {} = {};", t, name, number)?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl core::str::FromStr for Message {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (name, body) = declaration(input, "message").ok_or(())?;
+        let fields = statements(body).map(|field| {
+            // Drop any field options, as in `[deprecated = true]`:
+            let field = match field.split_once('[') {
+                Some((field, _)) => field.trim(),
+                None => field,
+            };
+            let (field, number) = field.split_once('=').ok_or(())?;
+            let (t, name) = field.trim().rsplit_once(char::is_whitespace).ok_or(())?;
+            let number = number.trim().parse().map_err(|_| ())?;
+            Ok((name.to_string(), t.parse()?, number))
+        });
+        Ok(Self::new(name, fields.try_collect()?))
+    }
+}
+
+/// An enum declaration, given as its values' names and numbers.
+///
+/// See: https://protobuf.dev/programming-guides/proto3/#enum
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enum {
+    pub name: String,
+    pub values: Vec<(String, i32)>,
+}
+
+impl Enum {
+    pub fn new(name: impl Into<String>, values: Vec<(String, i32)>) -> Self {
+        Self {
+            name: name.into(),
+            values,
+        }
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "enum {} {{", self.name)?;
+        for (name, number) in &self.values {
+            write!(f, " {} = {};", name, number)?;
+        }
+        write!(f, " }}")
+    }
+}
+
+impl core::str::FromStr for Enum {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let (name, body) = declaration(input, "enum").ok_or(())?;
+        let values = statements(body).map(|value| {
+            let (name, number) = value.split_once('=').ok_or(())?;
+            let number = number.split('[').next().unwrap_or_default();
+            Ok((
+                name.trim().to_string(),
+                number.trim().parse().map_err(|_| ())?,
+            ))
+        });
+        Ok(Self::new(name, values.try_collect()?))
+    }
+}
+
+/// A top-level declaration in a `.proto` file.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Declaration {
+    Message(Message),
+    Enum(Enum),
+}
+
+impl fmt::Display for Declaration {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Declaration::Message(message) => write!(f, "{}", message),
+            Declaration::Enum(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+/// The messages and enums of a `.proto` file, in order.
+///
+/// Parsing ignores comments and the `syntax`, `package`, `import`, and
+/// `option` statements, and rejects nested declarations, `oneof` fields,
+/// and services.
+///
+/// See: https://protobuf.dev/programming-guides/proto3/
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Schema(pub Vec<Declaration>);
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "syntax = \"proto3\";")?;
+        let mut imports: Vec<_> = self
+            .0
+            .iter()
+            .filter_map(|declaration| match declaration {
+                Declaration::Message(message) => Some(&message.fields),
+                Declaration::Enum(_) => None,
+            })
+            .flatten()
+            .filter_map(|(_, t, _)| t.import())
+            .collect();
+        imports.sort();
+        imports.dedup();
+        if !imports.is_empty() {
+            writeln!(f)?;
+        }
+        for import in imports {
+            writeln!(f, "import \"{}\";", import)?;
+        }
+        for declaration in &self.0 {
+            write!(f, "\n{}\n", declaration)?;
+        }
+        Ok(())
+    }
+}
+
+impl core::str::FromStr for Schema {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let input = uncommented(input);
+        let mut declarations = Vec::new();
+        let mut rest = input.trim_start();
+        while !rest.is_empty() {
+            let end = if rest.starts_with("message ") || rest.starts_with("enum ") {
+                let end = rest.find('{').ok_or(())?;
+                end + closing(&rest[end..]).ok_or(())? + 1
+            } else {
+                // Skip over any `;` in a string literal, as in `"foo;bar"`:
+                let statement = split(rest, ';')[0];
+                statement.len() + rest[statement.len()..].find(';').ok_or(())? + 1
+            };
+            let (statement, tail) = rest.split_at(end);
+            if statement.starts_with("message ") {
+                declarations.push(Declaration::Message(statement.parse()?));
+            } else if statement.starts_with("enum ") {
+                declarations.push(Declaration::Enum(statement.parse()?));
+            } else if !["syntax", "edition", "package", "import", "option"]
+                .iter()
+                .any(|keyword| statement.starts_with(keyword))
+            {
+                return Err(());
+            }
+            rest = tail.trim_start();
+        }
+        Ok(Self(declarations))
+    }
+}
+
+/// Returns the name and body of `input` if it is a declaration of the given
+/// kind, as in `message Point { int32 x = 1; }`.
+fn declaration<'a>(input: &'a str, kind: &str) -> Option<(&'a str, &'a str)> {
+    let input = input.trim().strip_prefix(kind)?;
+    let (name, body) = input.split_once('{')?;
+    let body = body.trim_end().strip_suffix('}')?;
+    // Nested declarations and `oneof` fields aren't supported:
+    (!body.contains('{')).then(|| (name.trim(), body))
+}
+
+/// Iterates over the non-empty statements in the body of a declaration,
+/// skipping `option` and `reserved` statements, which don't declare fields
+/// or values.
+fn statements(body: &str) -> impl Iterator<Item = &str> {
+    split(body, ';')
+        .into_iter()
+        .filter(|s| !s.is_empty() && !s.starts_with("option ") && !s.starts_with("reserved "))
+}
+
+/// Returns `input` with its `//` line comments and `/* */` block comments
+/// replaced by whitespace, leaving string literals intact, as in
+/// `option go_package = "example.com/foo";`.
+fn uncommented(input: &str) -> String {
+    let mut result = String::with_capacity(input.len());
+    let mut chars = input.chars().peekable();
+    let mut quote = None;
+    while let Some(c) = chars.next() {
+        match (quote, c, chars.peek()) {
+            (Some(_), '\\', Some(_)) => {
+                result.push(c);
+                result.extend(chars.next());
+            }
+            (Some(q), _, _) => {
+                result.push(c);
+                quote = (c != q).then_some(q);
+            }
+            (None, '"' | '\'', _) => {
+                result.push(c);
+                quote = Some(c);
+            }
+            (None, '/', Some('/')) => {
+                // Keep the line break that ends the comment:
+                while chars.next_if(|&c| c != '\n').is_some() {}
+            }
+            (None, '/', Some('*')) => {
+                chars.next();
+                let mut prev = None;
+                for c in chars.by_ref() {
+                    if prev == Some('*') && c == '/' {
+                        break;
+                    }
+                    prev = Some(c);
+                }
+                result.push(' ');
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+/// Returns the index of the brace closing the one that `input` starts with.
+fn closing(input: &str) -> Option<usize> {
+    let mut depth = 0usize;
+    for (i, c) in input.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => {
+                depth -= 1;
+                if depth == 0 {
+                    return Some(i);
+                }
+            }
+            _ => {}
+        }
+    }
+    None
+}
@@ -0,0 +1,308 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{generic, split},
+    prelude::{fmt, format, Box, Cow, Named, String, ToString},
+    rust,
+};
+
+/// See: https://protobuf.dev/programming-guides/proto3/#scalar
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Double,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Float,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Int32,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Int64,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    UInt32,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    UInt64,
+
+    /// A signed integer that is efficiently encoded when negative.
+    ///
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    SInt32,
+
+    /// A signed integer that is efficiently encoded when negative.
+    ///
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    SInt64,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Fixed32,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Fixed64,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    SFixed32,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    SFixed64,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Bool,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    String,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#scalar
+    Bytes,
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#field-labels
+    Repeated(Box<Type>),
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#field-labels
+    Optional(Box<Type>),
+
+    /// See: https://protobuf.dev/programming-guides/proto3/#maps
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://protobuf.dev/reference/protobuf/google.protobuf/#any
+    Any,
+
+    /// See: https://protobuf.dev/reference/protobuf/google.protobuf/#duration
+    Duration,
+
+    /// See: https://protobuf.dev/reference/protobuf/google.protobuf/#empty
+    Empty,
+
+    /// See: https://protobuf.dev/reference/protobuf/google.protobuf/#timestamp
+    Timestamp,
+
+    /// A user-defined message or enum type, as in `Point` or `geo.Point`.
+    ///
+    /// See: https://protobuf.dev/programming-guides/proto3/#other
+    Named(String),
+}
+
+impl Type {
+    /// Returns the file to import for this type, if it is a well-known type.
+    ///
+    /// See: https://protobuf.dev/reference/protobuf/google.protobuf/
+    pub fn import(&self) -> Option<&'static str> {
+        use Type::*;
+        match self {
+            Any => Some("google/protobuf/any.proto"),
+            Duration => Some("google/protobuf/duration.proto"),
+            Empty => Some("google/protobuf/empty.proto"),
+            Timestamp => Some("google/protobuf/timestamp.proto"),
+            Repeated(t) | Optional(t) | Map(_, t) => t.import(),
+            _ => None,
+        }
+    }
+
+    /// Checks whether this type may be a map key, which excludes floating-
+    /// point, bytes, and message types.
+    fn is_key(&self) -> bool {
+        use Type::*;
+        matches!(
+            self,
+            Int32
+                | Int64
+                | UInt32
+                | UInt64
+                | SInt32
+                | SInt64
+                | Fixed32
+                | Fixed64
+                | SFixed32
+                | SFixed64
+                | Bool
+                | String
+        )
+    }
+
+    /// Checks whether this type may be given a field label or be a map
+    /// value, which excludes labeled and map types.
+    fn is_singular(&self) -> bool {
+        !matches!(
+            self,
+            Type::Repeated(_) | Type::Optional(_) | Type::Map(_, _)
+        )
+    }
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        Ok(match input {
+            "double" => Double,
+            "float" => Float,
+            "int32" => Int32,
+            "int64" => Int64,
+            "uint32" => UInt32,
+            "uint64" => UInt64,
+            "sint32" => SInt32,
+            "sint64" => SInt64,
+            "fixed32" => Fixed32,
+            "fixed64" => Fixed64,
+            "sfixed32" => SFixed32,
+            "sfixed64" => SFixed64,
+            "bool" => Bool,
+            "string" => String,
+            "bytes" => Bytes,
+            "google.protobuf.Any" => Any,
+            "google.protobuf.Duration" => Duration,
+            "google.protobuf.Empty" => Empty,
+            "google.protobuf.Timestamp" => Timestamp,
+            _ => {
+                if let Some(t) = input.strip_prefix("repeated ") {
+                    let t: Type = t.parse()?;
+                    if !t.is_singular() {
+                        return Err(());
+                    }
+                    Repeated(Box::new(t))
+                } else if let Some(t) = input.strip_prefix("optional ") {
+                    let t: Type = t.parse()?;
+                    if !t.is_singular() {
+                        return Err(());
+                    }
+                    Optional(Box::new(t))
+                } else if let Some(kv) = generic(input, "map") {
+                    let [k, v] = split(kv, ',')[..] else {
+                        return Err(());
+                    };
+                    let (k, v): (Type, Type) = (k.parse()?, v.parse()?);
+                    if !k.is_key() || !v.is_singular() {
+                        return Err(());
+                    }
+                    Map(Box::new(k), Box::new(v))
+                } else if input
+                    .split('.')
+                    .enumerate()
+                    .all(|(i, name)| is_identifier(name) || (i == 0 && name.is_empty()))
+                {
+                    Named(input.to_string())
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+/// Checks whether `input` is a valid identifier, as in `Point`.
+fn is_identifier(input: &str) -> bool {
+    let mut chars = input.chars();
+    chars.next().is_some_and(|c| c.is_ascii_alphabetic())
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Double => write!(f, "double"),
+            Float => write!(f, "float"),
+            Int32 => write!(f, "int32"),
+            Int64 => write!(f, "int64"),
+            UInt32 => write!(f, "uint32"),
+            UInt64 => write!(f, "uint64"),
+            SInt32 => write!(f, "sint32"),
+            SInt64 => write!(f, "sint64"),
+            Fixed32 => write!(f, "fixed32"),
+            Fixed64 => write!(f, "fixed64"),
+            SFixed32 => write!(f, "sfixed32"),
+            SFixed64 => write!(f, "sfixed64"),
+            Bool => write!(f, "bool"),
+            String => write!(f, "string"),
+            Bytes => write!(f, "bytes"),
+            Repeated(t) => write!(f, "repeated {}", t),
+            Optional(t) => write!(f, "optional {}", t),
+            Map(k, v) => write!(f, "map<{}, {}>", k, v),
+            Any => write!(f, "google.protobuf.Any"),
+            Duration => write!(f, "google.protobuf.Duration"),
+            Empty => write!(f, "google.protobuf.Empty"),
+            Timestamp => write!(f, "google.protobuf.Timestamp"),
+            Named(name) => write!(f, "{}", name),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+/// Follows the conventions of `prost`, the most widely used Protocol Buffers
+/// implementation for Rust.
+///
+/// See: https://github.com/tokio-rs/prost#scalar-values
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Empty,
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => Float,
+            rust::Type::F64 => Double,
+            // There are no integer types narrower than 32 bits:
+            rust::Type::I8 | rust::Type::I16 | rust::Type::I32 => Int32,
+            rust::Type::I64 | rust::Type::Isize => Int64,
+            rust::Type::U8 | rust::Type::U16 | rust::Type::U32 => UInt32,
+            rust::Type::U64 | rust::Type::Usize => UInt64,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Vec(t) if *t == rust::Type::U8 => Bytes,
+            rust::Type::Box(t) => Self::try_from(*t)?,
+            rust::Type::Option(t) => match Self::try_from(*t)? {
+                t if t.is_singular() => Optional(Box::new(t)),
+                _ => return Err(()),
+            },
+            rust::Type::Vec(t) | rust::Type::Set(t) => match Self::try_from(*t)? {
+                t if t.is_singular() => Repeated(Box::new(t)),
+                _ => return Err(()),
+            },
+            rust::Type::Map(k, v) => match (convert(*k)?, convert(*v)?) {
+                (k, v) if k.is_key() && v.is_singular() => Map(k, v),
+                _ => return Err(()),
+            },
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Double => rust::Type::F64,
+            Float => rust::Type::F32,
+            Int32 | SInt32 | SFixed32 => rust::Type::I32,
+            Int64 | SInt64 | SFixed64 => rust::Type::I64,
+            UInt32 | Fixed32 => rust::Type::U32,
+            UInt64 | Fixed64 => rust::Type::U64,
+            Bool => rust::Type::Bool,
+            String => rust::Type::String,
+            Bytes => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Repeated(t) => rust::Type::Vec(convert(t)?),
+            Optional(t) => rust::Type::Option(convert(t)?),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Any => rust::Type::Any,
+            Empty => rust::Type::Unit,
+            Duration | Timestamp => return None, // no time types in the Rust type model
+            Named(_) => return None,
+        })
+    }
+}
+
+impl crate::Type for Type {}
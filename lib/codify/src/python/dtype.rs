@@ -0,0 +1,302 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    prelude::{fmt, format, Box, Cow, Named, String, ToString, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// A NumPy data type, which doubles as a `struct` module format.
+///
+/// See: https://numpy.org/doc/stable/reference/arrays.dtypes.html
+/// See: https://docs.python.org/3/library/struct.html#format-characters
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dtype {
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.bool_
+    Bool,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.int8
+    Int8,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.int16
+    Int16,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.int32
+    Int32,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.int64
+    Int64,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.uint8
+    UInt8,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.uint16
+    UInt16,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.uint32
+    UInt32,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.uint64
+    UInt64,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.float32
+    Float32,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.scalars.html#numpy.float64
+    Float64,
+
+    /// See: https://numpy.org/doc/stable/reference/arrays.dtypes.html#specifying-and-constructing-data-types
+    Array(Box<Dtype>, usize),
+
+    /// A structured data type, given as `(name, dtype, offset)` fields and
+    /// the total item size.
+    ///
+    /// See: https://numpy.org/doc/stable/user/basics.rec.html
+    Struct(Vec<(String, Dtype, usize)>, usize),
+}
+
+impl Dtype {
+    /// Returns the size of this data type in bytes.
+    pub fn size(&self) -> usize {
+        use Dtype::*;
+        match self {
+            Bool | Int8 | UInt8 => 1,
+            Int16 | UInt16 => 2,
+            Int32 | UInt32 | Float32 => 4,
+            Int64 | UInt64 | Float64 => 8,
+            Array(t, n) => t.size() * n,
+            Struct(_, size) => *size,
+        }
+    }
+
+    /// Returns the `struct` module format string for this data type, using
+    /// little-endian byte order and explicit padding, as in `<H` or `<Bxxxf`.
+    ///
+    /// Fails if a structured data type has overlapping or unsorted fields,
+    /// or an item size smaller than the end of its last field.
+    ///
+    /// See: https://docs.python.org/3/library/struct.html#format-strings
+    pub fn format(&self) -> Result<String, ()> {
+        let mut codes = String::new();
+        self.push_codes(&mut codes)?;
+        Ok(if self.is_bytewise() {
+            codes
+        } else {
+            format!("<{}", codes)
+        })
+    }
+
+    /// Checks whether byte order is irrelevant for this data type.
+    fn is_bytewise(&self) -> bool {
+        use Dtype::*;
+        match self {
+            Array(t, _) => t.is_bytewise(),
+            Struct(fields, _) => fields.iter().all(|(_, t, _)| t.is_bytewise()),
+            t => t.size() == 1,
+        }
+    }
+
+    fn push_codes(&self, codes: &mut String) -> Result<(), ()> {
+        use Dtype::*;
+        let code = match self {
+            Bool => '?',
+            Int8 => 'b',
+            Int16 => 'h',
+            Int32 => 'i',
+            Int64 => 'q',
+            UInt8 => 'B',
+            UInt16 => 'H',
+            UInt32 => 'I',
+            UInt64 => 'Q',
+            Float32 => 'f',
+            Float64 => 'd',
+            Array(t, n) => {
+                match **t {
+                    Array(_, _) | Struct(_, _) => (0..*n).try_for_each(|_| t.push_codes(codes))?,
+                    _ => {
+                        codes.push_str(&n.to_string());
+                        t.push_codes(codes)?;
+                    }
+                }
+                return Ok(());
+            }
+            Struct(fields, size) => {
+                let mut cursor = 0;
+                for (_, t, offset) in fields {
+                    push_padding(codes, offset.checked_sub(cursor).ok_or(())?);
+                    t.push_codes(codes)?;
+                    cursor = offset + t.size();
+                }
+                push_padding(codes, size.checked_sub(cursor).ok_or(())?);
+                return Ok(());
+            }
+        };
+        codes.push(code);
+        Ok(())
+    }
+}
+
+/// Appends `n` pad bytes to a `struct` module format string.
+fn push_padding(codes: &mut String, n: usize) {
+    match n {
+        0 => {}
+        1 => codes.push('x'),
+        n => codes.push_str(&format!("{}x", n)),
+    }
+}
+
+impl core::str::FromStr for Dtype {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Dtype::*;
+        let input = input.trim();
+        let input = ["np.", "numpy."]
+            .iter()
+            .find_map(|prefix| input.strip_prefix(prefix))
+            .unwrap_or(input);
+        Ok(match input {
+            "bool_" | "bool" => Bool,
+            "int8" | "byte" => Int8,
+            "int16" | "short" => Int16,
+            "int32" | "intc" => Int32,
+            "int64" | "longlong" => Int64,
+            "uint8" | "ubyte" => UInt8,
+            "uint16" | "ushort" => UInt16,
+            "uint32" | "uintc" => UInt32,
+            "uint64" | "ulonglong" => UInt64,
+            "float32" | "single" => Float32,
+            "float64" | "double" => Float64,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl fmt::Display for Dtype {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Dtype::*;
+        match self {
+            Bool => write!(f, "np.bool_"),
+            Int8 => write!(f, "np.int8"),
+            Int16 => write!(f, "np.int16"),
+            Int32 => write!(f, "np.int32"),
+            Int64 => write!(f, "np.int64"),
+            UInt8 => write!(f, "np.uint8"),
+            UInt16 => write!(f, "np.uint16"),
+            UInt32 => write!(f, "np.uint32"),
+            UInt64 => write!(f, "np.uint64"),
+            Float32 => write!(f, "np.float32"),
+            Float64 => write!(f, "np.float64"),
+            Array(t, n) => write!(f, "({}, ({},))", t, n),
+            Struct(fields, size) => write!(
+                f,
+                "np.dtype({{'names': [{}], 'formats': [{}], 'offsets': [{}], 'itemsize': {}}})",
+                fields
+                    .iter()
+                    .format_with(", ", |(name, _, _), f| f(&format_args!("'{}'", name))),
+                fields.iter().map(|(_, t, _)| t).format(", "),
+                fields.iter().map(|(_, _, offset)| offset).format(", "),
+                size,
+            ),
+        }
+    }
+}
+
+impl Named for Dtype {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Dtype {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Dtype::*;
+        Ok(match input {
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => Float32,
+            rust::Type::F64 => Float64,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 | rust::Type::Isize => Int64,
+            rust::Type::U8 => UInt8,
+            rust::Type::U16 => UInt16,
+            rust::Type::U32 => UInt32,
+            rust::Type::U64 | rust::Type::Usize => UInt64,
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Dtype::try_from(t)?,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[cfg(feature = "language-c")]
+impl TryFrom<crate::c::Type> for Dtype {
+    type Error = ();
+
+    fn try_from(input: crate::c::Type) -> Result<Self, Self::Error> {
+        use crate::c::Type as C;
+        use Dtype::*;
+        Ok(match input {
+            C::Bool => Bool,
+            C::Float => Float32,
+            C::Double => Float64,
+            C::Char | C::SChar | C::Int8_t => Int8,
+            C::Short | C::Int16_t => Int16,
+            C::Int | C::Int32_t => Int32,
+            C::Long | C::LongLong | C::SSize_t | C::Int64_t => Int64,
+            C::UChar | C::UInt8_t => UInt8,
+            C::UShort | C::UInt16_t => UInt16,
+            C::UInt | C::UInt32_t => UInt32,
+            C::ULong | C::ULongLong | C::Size_t | C::UInt64_t => UInt64,
+            C::Array(t, Some(n)) => Array(Box::new(Dtype::try_from(*t)?), n),
+            #[cfg(feature = "libc")]
+            C::Time_t => Int64,
+            _ => return Err(()),
+        })
+    }
+}
+
+#[cfg(feature = "language-c")]
+impl TryFrom<&crate::c::Struct> for Dtype {
+    type Error = ();
+
+    fn try_from(input: &crate::c::Struct) -> Result<Self, Self::Error> {
+        let offsets = input.offsets().ok_or(())?;
+        let (size, _) = input.layout().ok_or(())?;
+        let fields = input.fields.iter().zip(offsets);
+        let fields = fields
+            .map(|((name, t), offset)| Ok((name.clone(), Dtype::try_from(t.clone())?, offset)));
+        Ok(Dtype::Struct(fields.try_collect()?, size))
+    }
+}
+
+impl crate::ToRust for Dtype {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Dtype::*;
+        Some(match self {
+            Bool => rust::Type::Bool,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            UInt8 => rust::Type::U8,
+            UInt16 => rust::Type::U16,
+            UInt32 => rust::Type::U32,
+            UInt64 => rust::Type::U64,
+            Float32 => rust::Type::F32,
+            Float64 => rust::Type::F64,
+            #[cfg(feature = "language-c")]
+            Array(t, n) => {
+                let t = crate::c::Type::try_from(t.to_rust()?).ok()?;
+                rust::Type::Ffi(crate::c::Type::Array(Box::new(t), Some(*n)))
+            }
+            _ => return None,
+        })
+    }
+}
+
+impl crate::Type for Dtype {}
@@ -1,22 +1,76 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    parse::{applied, enclosed, split, union},
+    prelude::{fmt, format, Box, Cow, Named, String, ToString, Vec},
     rust,
 };
+use itertools::Itertools;
 
-/// See: https://docs.python.org/3/library/stdtypes.html
+/// See: https://docs.python.org/3/library/typing.html
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
+    /// See: https://docs.python.org/3/library/typing.html#type-aliases
     NoneType,
 
+    /// See: https://docs.python.org/3/library/typing.html#typing.Any
+    Any,
+
     /// See: https://docs.python.org/3/library/stdtypes.html#boolean-type-bool
     Bool,
 
+    /// See: https://docs.python.org/3/library/stdtypes.html#numeric-types-int-float-complex
+    Int,
+
     /// See: https://docs.python.org/3/library/stdtypes.html#numeric-types-int-float-complex
     Float,
 
+    /// See: https://docs.python.org/3/library/stdtypes.html#text-sequence-type-str
+    Str,
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#bytes-objects
+    Bytes,
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#bytearray-objects
+    ByteArray,
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#lists
+    List(Box<Type>),
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#tuples
+    Tuple(Vec<Type>),
+
+    /// See: https://docs.python.org/3/library/typing.html#annotating-tuples
+    VariadicTuple(Box<Type>),
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#mapping-types-dict
+    Dict(Box<Type>, Box<Type>),
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#set-types-set-frozenset
+    Set(Box<Type>),
+
+    /// See: https://docs.python.org/3/library/stdtypes.html#set-types-set-frozenset
+    FrozenSet(Box<Type>),
+
+    /// See: https://docs.python.org/3/library/collections.abc.html#collections.abc.Sequence
+    Sequence(Box<Type>),
+
+    /// See: https://docs.python.org/3/library/collections.abc.html#collections.abc.Mapping
+    Mapping(Box<Type>, Box<Type>),
+
+    /// See: https://docs.python.org/3/library/typing.html#typing.Optional
+    Optional(Box<Type>),
+
+    /// See: https://docs.python.org/3/library/typing.html#typing.Union
+    Union(Vec<Type>),
+
+    /// See: https://docs.python.org/3/library/typing.html#annotating-callable-objects
+    Callable(Vec<Type>, Box<Type>),
+
+    /// See: https://docs.python.org/3/library/typing.html#typing.Literal
+    Literal(Vec<String>),
+
     /// See: https://docs.python.org/3/library/ctypes.html#fundamental-data-types
     #[cfg(feature = "language-c")]
     Ffi(crate::c::Type),
@@ -27,22 +81,117 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
+        let input = input.trim();
+        let variants = split(input, '|');
+        if variants.len() > 1 {
+            let types = variants.into_iter().map(str::parse).try_collect()?;
+            return Ok(union(types, &NoneType, |t| Optional(Box::new(t)), Union));
+        }
+        let input = ["typing.", "collections.abc."]
+            .iter()
+            .find_map(|prefix| input.strip_prefix(prefix))
+            .unwrap_or(input);
         Ok(match input {
-            "NoneType" => NoneType,
+            "None" | "NoneType" => NoneType,
+            "Any" => Any,
             "bool" => Bool,
+            "int" => Int,
             "float" => Float,
-            _ => return Err(()),
+            "str" => Str,
+            "bytes" => Bytes,
+            "bytearray" => ByteArray,
+            _ => {
+                let parse_all = |ts: &str| -> Result<Vec<Type>, ()> {
+                    split(ts, ',').into_iter().map(str::parse).try_collect()
+                };
+                let parse_pair = |ts: &str| -> Result<(Box<Type>, Box<Type>), ()> {
+                    let [k, v] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Ok((Box::new(k.parse()?), Box::new(v.parse()?)))
+                };
+                if let Some(t) = subscript(input, &["list", "List"]) {
+                    List(Box::new(t.parse()?))
+                } else if let Some(ts) = subscript(input, &["tuple", "Tuple"]) {
+                    match ts {
+                        "()" => Tuple(Vec::new()),
+                        ts => match split(ts, ',')[..] {
+                            [t, "..."] => VariadicTuple(Box::new(t.parse()?)),
+                            _ => Tuple(parse_all(ts)?),
+                        },
+                    }
+                } else if let Some(ts) = subscript(input, &["dict", "Dict"]) {
+                    let (k, v) = parse_pair(ts)?;
+                    Dict(k, v)
+                } else if let Some(t) = subscript(input, &["set", "Set"]) {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(t) = subscript(input, &["frozenset", "FrozenSet"]) {
+                    FrozenSet(Box::new(t.parse()?))
+                } else if let Some(t) = subscript(input, &["Sequence"]) {
+                    Sequence(Box::new(t.parse()?))
+                } else if let Some(ts) = subscript(input, &["Mapping"]) {
+                    let (k, v) = parse_pair(ts)?;
+                    Mapping(k, v)
+                } else if let Some(t) = subscript(input, &["Optional"]) {
+                    Optional(Box::new(t.parse()?))
+                } else if let Some(ts) = subscript(input, &["Union"]) {
+                    union(parse_all(ts)?, &NoneType, |t| Optional(Box::new(t)), Union)
+                } else if let Some(ts) = subscript(input, &["Callable"]) {
+                    let [params, result] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    let params = match enclosed(params, '[', ']').ok_or(())? {
+                        "" => Vec::new(),
+                        params => parse_all(params)?,
+                    };
+                    Callable(params, Box::new(result.parse()?))
+                } else if let Some(values) = subscript(input, &["Literal"]) {
+                    Literal(
+                        split(values, ',')
+                            .into_iter()
+                            .map(ToString::to_string)
+                            .collect(),
+                    )
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
 
+/// Returns the subscript of `input` if it is a parameterization of one of
+/// the generic types `names`, as in `list[int]` or `List[int]`.
+fn subscript<'a>(input: &'a str, names: &[&str]) -> Option<&'a str> {
+    names.iter().find_map(|name| applied(input, name, '[', ']'))
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Type::*;
         match self {
-            NoneType => write!(f, "NoneType"),
+            NoneType => write!(f, "None"),
+            Any => write!(f, "Any"),
             Bool => write!(f, "bool"),
+            Int => write!(f, "int"),
             Float => write!(f, "float"),
+            Str => write!(f, "str"),
+            Bytes => write!(f, "bytes"),
+            ByteArray => write!(f, "bytearray"),
+            List(t) => write!(f, "list[{}]", t),
+            Tuple(ts) if ts.is_empty() => write!(f, "tuple[()]"),
+            Tuple(ts) => write!(f, "tuple[{}]", ts.iter().format(", ")),
+            VariadicTuple(t) => write!(f, "tuple[{}, ...]", t),
+            Dict(k, v) => write!(f, "dict[{}, {}]", k, v),
+            Set(t) => write!(f, "set[{}]", t),
+            FrozenSet(t) => write!(f, "frozenset[{}]", t),
+            Sequence(t) => write!(f, "Sequence[{}]", t),
+            Mapping(k, v) => write!(f, "Mapping[{}, {}]", k, v),
+            Optional(t) if matches!(**t, Optional(_)) => write!(f, "{}", t),
+            Optional(t) => write!(f, "{} | None", t),
+            Union(ts) => write!(f, "{}", ts.iter().format(" | ")),
+            Callable(ps, r) => write!(f, "Callable[[{}], {}]", ps.iter().format(", "), r),
+            Literal(vs) => write!(f, "Literal[{}]", vs.iter().format(", ")),
             #[cfg(feature = "language-c")]
             Ffi(t) => match t {
                 // See: https://docs.python.org/3/library/ctypes.html#fundamental-data-types
@@ -63,6 +212,14 @@ impl fmt::Display for Type {
                 crate::c::Type::ULong => write!(f, "c_ulong"),
                 crate::c::Type::ULongLong => write!(f, "c_ulonglong"),
                 crate::c::Type::Size_t => write!(f, "c_size_t"),
+                crate::c::Type::Int8_t => write!(f, "c_int8"),
+                crate::c::Type::Int16_t => write!(f, "c_int16"),
+                crate::c::Type::Int32_t => write!(f, "c_int32"),
+                crate::c::Type::Int64_t => write!(f, "c_int64"),
+                crate::c::Type::UInt8_t => write!(f, "c_uint8"),
+                crate::c::Type::UInt16_t => write!(f, "c_uint16"),
+                crate::c::Type::UInt32_t => write!(f, "c_uint32"),
+                crate::c::Type::UInt64_t => write!(f, "c_uint64"),
                 crate::c::Type::Array(t, None) => write!(f, "POINTER({})", Ffi((**t).clone())),
                 crate::c::Type::Array(t, Some(n)) => write!(f, "{} * {}", Ffi((**t).clone()), n),
                 crate::c::Type::Ptr(t) if **t == crate::c::Type::Char => write!(f, "c_char_p"),
@@ -80,7 +237,7 @@ impl fmt::Display for Type {
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -91,10 +248,31 @@ impl TryFrom<rust::Type> for Type {
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
         use Type::*;
         Ok(match input {
-            rust::Type::Any => return Err(()),
+            rust::Type::Any => Any,
             rust::Type::Unit => NoneType,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Type::try_from).try_collect()?),
             rust::Type::Bool => Bool,
             rust::Type::F32 | rust::Type::F64 => Float,
+            rust::Type::I8 | rust::Type::U8 => Int,
+            rust::Type::I16 | rust::Type::U16 => Int,
+            rust::Type::I32 | rust::Type::U32 => Int,
+            rust::Type::I64 | rust::Type::U64 => Int,
+            rust::Type::I128 | rust::Type::U128 => Int,
+            rust::Type::Isize | rust::Type::Usize => Int,
+            rust::Type::Char | rust::Type::Str | rust::Type::String => Str,
+            rust::Type::Option(t) => Optional(Box::new(Type::try_from(*t)?)),
+            rust::Type::Box(t) | rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                Type::try_from(*t)?
+            }
+            rust::Type::Ref(t) => Type::try_from(*t)?,
+            rust::Type::Vec(t) => match *t {
+                rust::Type::U8 => Bytes,
+                t => List(Box::new(Type::try_from(t)?)),
+            },
+            rust::Type::Set(t) => Set(Box::new(Type::try_from(*t)?)),
+            rust::Type::Map(k, v) => {
+                Dict(Box::new(Type::try_from(*k)?), Box::new(Type::try_from(*v)?))
+            }
             #[cfg(feature = "language-c")]
             rust::Type::Ffi(t) => Type::Ffi(t),
             _ => return Err(()),
@@ -107,8 +285,21 @@ impl crate::ToRust for Type {
         use Type::*;
         Some(match self {
             NoneType => rust::Type::Unit,
+            Any => rust::Type::Any,
             Bool => rust::Type::Bool,
+            Int => rust::Type::I64, // Python integers are of arbitrary precision
             Float => rust::Type::F64,
+            Str => rust::Type::String,
+            Bytes | ByteArray => rust::Type::Vec(Box::new(rust::Type::U8)),
+            List(t) | Sequence(t) | VariadicTuple(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Dict(k, v) | Mapping(k, v) => {
+                rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?))
+            }
+            Set(t) | FrozenSet(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Optional(t) => rust::Type::Option(Box::new(t.to_rust()?)),
+            Union(_) | Callable(_, _) | Literal(_) => return None,
             #[cfg(feature = "language-c")]
             Ffi(t) => rust::Type::Ffi(t.clone()),
         })
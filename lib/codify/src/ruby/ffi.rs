@@ -0,0 +1,78 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::{
+    c,
+    prelude::{fmt, String},
+};
+use itertools::Itertools;
+
+/// An `FFI::Struct` subclass for a C structure.
+///
+/// See: https://github.com/ffi/ffi/wiki/Structs
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FfiStruct(pub c::Struct);
+
+/// A `callback` definition for a C function pointer type.
+///
+/// See: https://github.com/ffi/ffi/wiki/Callbacks
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FfiCallback(pub c::Function);
+
+/// An `enum` definition for a C enumeration.
+///
+/// See: https://github.com/ffi/ffi/wiki/Enums
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FfiEnum(pub c::Enum);
+
+impl fmt::Display for FfiStruct {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "class {} < FFI::Struct", class_name(&self.0.name))?;
+        if !self.0.fields.is_empty() {
+            let fields = self.0.fields.iter();
+            let fields = fields.format_with(",\n         ", |(name, t), f| {
+                f(&format_args!(":{}, {}", name, Type::Ffi(t.clone())))
+            });
+            writeln!(f, "  layout {}", fields)?;
+        }
+        write!(f, "end")
+    }
+}
+
+impl fmt::Display for FfiCallback {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let params = self.0.params.iter().map(|t| Type::Ffi(t.clone()));
+        write!(
+            f,
+            "callback :{}, [{}], {}",
+            self.0.name,
+            params.format(", "),
+            Type::Ffi(self.0.result.clone())
+        )
+    }
+}
+
+impl fmt::Display for FfiEnum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let variants = self.0.variants.iter();
+        let variants = variants.format_with(", ", |(name, value), f| {
+            f(&format_args!(":{}, {}", name, value))
+        });
+        write!(f, "enum :{}, [{}]", self.0.name, variants)
+    }
+}
+
+/// Converts a C identifier into a Ruby class name, as in `MyPoint` for
+/// `my_point`.
+fn class_name(name: &str) -> String {
+    name.split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            let head = chars.next().map(|c| c.to_ascii_uppercase());
+            head.into_iter().chain(chars)
+        })
+        .collect()
+}
@@ -1,7 +1,8 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Box, Cow, Named, String},
+    parse::{applied, enclosed, generic, split},
+    prelude::{fmt, format, Box, Cow, Named, String, Vec},
     rust,
 };
 use itertools::Itertools;
@@ -41,6 +42,15 @@ pub enum Type {
     /// See: https://ruby-doc.org/3.3.0/Hash.html
     Hash(Box<Type>, Box<Type>),
 
+    /// See: https://ruby-doc.org/3.3.0/stdlibs/set/Set.html
+    Set(Box<Type>),
+
+    /// See: https://github.com/ruby/rbs/blob/master/docs/syntax.md#tuple-type
+    Tuple(Vec<Type>),
+
+    /// See: https://github.com/ruby/rbs/blob/master/docs/syntax.md#optional-type
+    Optional(Box<Type>),
+
     /// TBD
     Other(String),
 
@@ -49,62 +59,86 @@ pub enum Type {
     Ffi(crate::c::Type),
 }
 
-impl core::str::FromStr for Type {
-    type Err = ();
+/// The notation used to render Ruby type annotations.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Dialect {
+    /// See: https://rubydoc.info/gems/yard/file/docs/Tags.md#types-specifier-list
+    #[default]
+    Yard,
 
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        use Type::*;
-        Ok(match input {
-            "Object" => Object,
-            "NilClass" => NilClass,
-            "Boolean" | "TrueClass" | "FalseClass" => Boolean,
-            "Float" => Float,
-            "Integer" => Integer,
-            "Range" => Range,
-            "String" => String,
-            "Symbol" => Symbol,
-            "Array" => Array(Box::new(Type::Object)),
-            "Hash" => Hash(Box::new(Type::Object), Box::new(Type::Object)),
-            _ => {
-                if input.starts_with("Array<") {
-                    let inner = input
-                        .trim_start_matches("Array<")
-                        .trim_end_matches('>')
-                        .parse()?;
-                    Array(Box::new(inner))
-                } else if input.starts_with("Hash{") {
-                    let (k, v) = input
-                        .trim_start_matches("Hash{")
-                        .trim_end_matches('}')
-                        .split(" => ")
-                        .collect_tuple()
-                        .ok_or(())?;
-                    Hash(Box::new(k.parse()?), Box::new(v.parse()?))
-                } else {
-                    Other(input.into())
-                }
-            }
-        })
-    }
+    /// See: https://github.com/ruby/rbs/blob/master/docs/syntax.md
+    Rbs,
+
+    /// See: https://sorbet.org/docs/stdlib-generics
+    Sorbet,
 }
 
-impl fmt::Display for Type {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+impl Type {
+    /// Returns a displayable form of this type in the given dialect.
+    pub fn with_dialect(&self, dialect: Dialect) -> impl fmt::Display + '_ {
+        Annotation(self, dialect)
+    }
+
+    fn fmt_with(&self, f: &mut fmt::Formatter, dialect: Dialect) -> fmt::Result {
+        use Dialect::*;
         use Type::*;
-        match self {
-            Object => write!(f, "Object"),
-            NilClass => write!(f, "NilClass"),
-            Boolean => write!(f, "Boolean"),
-            Float => write!(f, "Float"),
-            Integer => write!(f, "Integer"),
-            Range => write!(f, "Range"),
-            String => write!(f, "String"),
-            Symbol => write!(f, "Symbol"),
-            Array(t) => write!(f, "Array<{}>", t),
-            Hash(k, v) => write!(f, "Hash{{{} => {}}}", k, v),
-            Other(s) => write!(f, "{}", s),
+        match (self, dialect) {
+            (Object, Yard) => write!(f, "Object"),
+            (Object, Rbs) => write!(f, "untyped"),
+            (Object, Sorbet) => write!(f, "T.untyped"),
+            (NilClass, Yard | Sorbet) => write!(f, "NilClass"),
+            (NilClass, Rbs) => write!(f, "nil"),
+            (Boolean, Yard) => write!(f, "Boolean"),
+            (Boolean, Rbs) => write!(f, "bool"),
+            (Boolean, Sorbet) => write!(f, "T::Boolean"),
+            (Float, _) => write!(f, "Float"),
+            (Integer, _) => write!(f, "Integer"),
+            (Range, Yard) => write!(f, "Range"),
+            (Range, Rbs) => write!(f, "Range[Integer]"),
+            (Range, Sorbet) => write!(f, "T::Range[Integer]"),
+            (String, _) => write!(f, "String"),
+            (Symbol, _) => write!(f, "Symbol"),
+            (Array(e), Yard) => write!(f, "Array<{}>", e.with_dialect(dialect)),
+            (Array(e), Rbs) => write!(f, "Array[{}]", e.with_dialect(dialect)),
+            (Array(e), Sorbet) => write!(f, "T::Array[{}]", e.with_dialect(dialect)),
+            (Hash(k, v), Yard) => write!(
+                f,
+                "Hash{{{} => {}}}",
+                k.with_dialect(dialect),
+                v.with_dialect(dialect)
+            ),
+            (Hash(k, v), Rbs) => write!(
+                f,
+                "Hash[{}, {}]",
+                k.with_dialect(dialect),
+                v.with_dialect(dialect)
+            ),
+            (Hash(k, v), Sorbet) => write!(
+                f,
+                "T::Hash[{}, {}]",
+                k.with_dialect(dialect),
+                v.with_dialect(dialect)
+            ),
+            (Set(e), Yard) => write!(f, "Set<{}>", e.with_dialect(dialect)),
+            (Set(e), Rbs) => write!(f, "Set[{}]", e.with_dialect(dialect)),
+            (Set(e), Sorbet) => write!(f, "T::Set[{}]", e.with_dialect(dialect)),
+            (Tuple(ts), Yard) => write!(
+                f,
+                "Array({})",
+                ts.iter().map(|t| t.with_dialect(dialect)).format(", ")
+            ),
+            (Tuple(ts), Rbs | Sorbet) => write!(
+                f,
+                "[{}]",
+                ts.iter().map(|t| t.with_dialect(dialect)).format(", ")
+            ),
+            (Optional(e), Yard) => write!(f, "{}, nil", e.with_dialect(dialect)),
+            (Optional(e), Rbs) => write!(f, "{}?", e.with_dialect(dialect)),
+            (Optional(e), Sorbet) => write!(f, "T.nilable({})", e.with_dialect(dialect)),
+            (Other(s), _) => write!(f, "{}", s),
             #[cfg(feature = "language-c")]
-            Ffi(t) => match t {
+            (Ffi(t), _) => match t {
                 // See: https://github.com/ffi/ffi/wiki/Types
                 crate::c::Type::Void => write!(f, ":void"),
                 crate::c::Type::Bool => write!(f, ":bool"),
@@ -123,6 +157,14 @@ impl fmt::Display for Type {
                 crate::c::Type::ULong => write!(f, ":ulong"),
                 crate::c::Type::ULongLong => write!(f, ":ulong_long"),
                 crate::c::Type::Size_t => write!(f, ":size_t"),
+                crate::c::Type::Int8_t => write!(f, ":int8"),
+                crate::c::Type::Int16_t => write!(f, ":int16"),
+                crate::c::Type::Int32_t => write!(f, ":int32"),
+                crate::c::Type::Int64_t => write!(f, ":int64"),
+                crate::c::Type::UInt8_t => write!(f, ":uint8"),
+                crate::c::Type::UInt16_t => write!(f, ":uint16"),
+                crate::c::Type::UInt32_t => write!(f, ":uint32"),
+                crate::c::Type::UInt64_t => write!(f, ":uint64"),
                 crate::c::Type::Array(_t, None) => write!(f, ":pointer"),
                 crate::c::Type::Array(t, Some(n)) => write!(f, "[{}, {}]", Ffi((**t).clone()), n),
                 crate::c::Type::Ptr(t) if **t == crate::c::Type::Char => write!(f, ":string"),
@@ -135,8 +177,80 @@ impl fmt::Display for Type {
     }
 }
 
+/// A Ruby type rendered in a specific dialect.
+struct Annotation<'a>(&'a Type, Dialect);
+
+impl fmt::Display for Annotation<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.0.fmt_with(f, self.1)
+    }
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        if let [t, "nil"] | ["nil", t] = split(input, ',')[..] {
+            return Ok(Optional(Box::new(t.parse()?)));
+        }
+        // Sorbet's generics are namespaced, as in `T::Array[Integer]`:
+        let input = input.strip_prefix("T::").unwrap_or(input);
+        // Type arguments are given in square brackets, as in `Array[Integer]`:
+        let subscript = |name| applied(input, name, '[', ']');
+        Ok(match input {
+            "Object" | "untyped" | "T.untyped" | "BasicObject" => Object,
+            "NilClass" | "nil" => NilClass,
+            "Boolean" | "TrueClass" | "FalseClass" | "bool" => Boolean,
+            "Float" => Float,
+            "Integer" => Integer,
+            "Range" => Range,
+            "String" => String,
+            "Symbol" => Symbol,
+            "Array" => Array(Box::new(Type::Object)),
+            "Hash" => Hash(Box::new(Type::Object), Box::new(Type::Object)),
+            _ => {
+                if let Some(t) = input.strip_suffix('?') {
+                    Optional(Box::new(t.parse()?))
+                } else if let Some(t) = applied(input, "T.nilable", '(', ')') {
+                    Optional(Box::new(t.parse()?))
+                } else if let Some(ts) = enclosed(input, '[', ']') {
+                    match ts {
+                        "" => Tuple(Vec::new()),
+                        ts => Tuple(split(ts, ',').into_iter().map(str::parse).try_collect()?),
+                    }
+                } else if let Some(ts) = applied(input, "Array", '(', ')') {
+                    Tuple(split(ts, ',').into_iter().map(str::parse).try_collect()?)
+                } else if let Some(t) = generic(input, "Array").or_else(|| subscript("Array")) {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Set").or_else(|| subscript("Set")) {
+                    Set(Box::new(t.parse()?))
+                } else if subscript("Range").is_some() {
+                    Range
+                } else if let Some(kv) = applied(input, "Hash", '{', '}') {
+                    let (k, v) = kv.split_once("=>").ok_or(())?;
+                    Hash(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(kv) = subscript("Hash") {
+                    let [k, v] = split(kv, ',')[..] else {
+                        return Err(());
+                    };
+                    Hash(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else {
+                    Other(input.into())
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_with(f, Dialect::Yard)
+    }
+}
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -149,6 +263,7 @@ impl TryFrom<rust::Type> for Type {
         Ok(match input {
             rust::Type::Any => Object,
             rust::Type::Unit => NilClass,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Type::try_from).try_collect()?),
             rust::Type::Bool => Boolean,
             rust::Type::F32 | rust::Type::F64 => Float,
             rust::Type::I8 | rust::Type::U8 => Integer,
@@ -161,8 +276,12 @@ impl TryFrom<rust::Type> for Type {
             rust::Type::Char => String,
             rust::Type::Str => String,
             rust::Type::String => String,
+            rust::Type::Option(t) => Optional(Box::new(Type::try_from(*t)?)),
             rust::Type::Box(t) => Type::try_from(*t)?,
+            rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => Type::try_from(*t)?,
+            rust::Type::Weak(_) => return Err(()),
             rust::Type::Vec(t) => Array(Box::new(Type::try_from(*t)?)),
+            rust::Type::Set(t) => Set(Box::new(Type::try_from(*t)?)),
             rust::Type::Map(k, v) => {
                 Hash(Box::new(Type::try_from(*k)?), Box::new(Type::try_from(*v)?))
             }
@@ -192,9 +311,13 @@ impl crate::ToRust for Type {
             Hash(k, v) => {
                 return k.to_rust().and_then(|k| {
                     v.to_rust()
-                        .and_then(|v| Some(rust::Type::Map(Box::new(k), Box::new(v))))
+                        .map(|v| rust::Type::Map(Box::new(k), Box::new(v)))
                 })
             }
+            Set(t) => return t.to_rust().map(|t| rust::Type::Set(Box::new(t))),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Optional(t) => return t.to_rust().map(|t| rust::Type::Option(Box::new(t))),
             Other(_) => return None, // unknown equivalent in Rust
             #[cfg(feature = "language-c")]
             Ffi(t) => rust::Type::Ffi(t.clone()),
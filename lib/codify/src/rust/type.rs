@@ -1,9 +1,11 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
+    parse::{enclosed, generic, split},
     prelude::{fmt, format, Box, Cow, Named},
     rust,
 };
+use itertools::Itertools;
 
 /// See: https://doc.rust-lang.org/reference/types.html
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
@@ -15,6 +17,9 @@ pub enum Type {
     /// See: https://doc.rust-lang.org/reference/types/tuple.html
     Unit,
 
+    /// See: https://doc.rust-lang.org/reference/types/tuple.html
+    Tuple(crate::prelude::Vec<Type>),
+
     /// See: https://doc.rust-lang.org/reference/types/boolean.html
     Bool,
 
@@ -72,12 +77,30 @@ pub enum Type {
     /// See: https://doc.rust-lang.org/alloc/string/struct.String.html
     String,
 
+    /// See: https://doc.rust-lang.org/core/option/enum.Option.html
+    Option(Box<Type>),
+
     /// See: https://doc.rust-lang.org/nightly/alloc/boxed/struct.Box.html
     Box(Box<Type>),
 
+    /// See: https://doc.rust-lang.org/alloc/rc/struct.Rc.html
+    Rc(Box<Type>),
+
+    /// See: https://doc.rust-lang.org/alloc/sync/struct.Arc.html
+    Arc(Box<Type>),
+
+    /// See: https://doc.rust-lang.org/alloc/borrow/enum.Cow.html
+    Cow(Box<Type>),
+
+    /// See: https://doc.rust-lang.org/alloc/sync/struct.Weak.html
+    Weak(Box<Type>),
+
     /// See: https://doc.rust-lang.org/nightly/alloc/vec/struct.Vec.html
     Vec(Box<Type>),
 
+    /// See: https://doc.rust-lang.org/alloc/collections/btree_set/struct.BTreeSet.html
+    Set(Box<Type>),
+
     /// See: https://doc.rust-lang.org/alloc/collections/btree_map/struct.BTreeMap.html
     Map(Box<Type>, Box<Type>),
 
@@ -126,32 +149,73 @@ impl core::str::FromStr for Type {
             "str" => Str,
             "String" => String,
             _ => {
-                if input.starts_with("Range<") && input.ends_with('>') {
-                    Range(Box::new(input[6..input.len() - 1].parse()?))
-                } else if input.starts_with("Box<") && input.ends_with('>') {
-                    Type::Box(Box::new(input[4..input.len() - 1].parse()?))
-                } else if input.starts_with("Vec<") && input.ends_with('>') {
-                    Vec(Box::new(input[4..input.len() - 1].parse()?))
-                } else if input.starts_with("Map<") && input.ends_with('>') {
-                    let mut parts = input[4..input.len() - 1].split(',');
-                    Map(
-                        Box::new(parts.next().unwrap().parse()?),
-                        Box::new(parts.next().unwrap().parse()?),
-                    )
-                } else if input.starts_with("BTreeMap<") && input.ends_with('>') {
-                    let mut parts = input[9..input.len() - 1].split(',');
-                    Map(
-                        Box::new(parts.next().unwrap().parse()?),
-                        Box::new(parts.next().unwrap().parse()?),
-                    )
-                } else if input.starts_with("&mut ") {
-                    RefMut(Box::new(input[5..input.len()].parse()?))
-                } else if input.starts_with("&") {
-                    Ref(Box::new(input[1..input.len()].parse()?))
-                } else if input.starts_with("*const ") {
-                    Ptr(Box::new(input[7..input.len()].parse()?))
-                } else if input.starts_with("*mut ") {
-                    PtrMut(Box::new(input[5..].parse()?))
+                if let Some(t) = generic(input, "Range") {
+                    Range(Box::new(t.parse()?))
+                } else if let Some(t) = enclosed(input, '(', ')') {
+                    let mut types = split(t, ',');
+                    if types.len() == 1 {
+                        return t.parse(); // a parenthesized type, as in `(i32)`
+                    }
+                    if types.last() == Some(&"") {
+                        types.pop(); // a trailing comma, as in `(i32,)`
+                    }
+                    let types = types.into_iter().map(str::parse);
+                    Tuple(types.collect::<Result<_, _>>()?)
+                } else if input.starts_with('[') {
+                    #[cfg(not(feature = "language-c"))]
+                    return Err(());
+                    #[cfg(feature = "language-c")]
+                    {
+                        let t = enclosed(input, '[', ']').ok_or(())?;
+                        let [t, n] = split(t, ';')[..] else {
+                            return Err(());
+                        };
+                        let t = crate::c::Type::try_from(t.parse::<Type>()?)?;
+                        Ffi(crate::c::Type::Array(
+                            Box::new(t),
+                            Some(n.parse().map_err(|_| ())?),
+                        ))
+                    }
+                } else if let Some(t) = generic(input, "Option") {
+                    Option(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Box") {
+                    Type::Box(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Rc") {
+                    Rc(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Arc") {
+                    Arc(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Cow") {
+                    // Skip over any lifetime parameter, as in `Cow<'a, str>`:
+                    let t = match t.split_once(',') {
+                        Some((lifetime, t)) if lifetime.starts_with('\'') => t.trim(),
+                        _ => t,
+                    };
+                    Cow(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Weak") {
+                    Weak(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Vec") {
+                    Vec(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Set")
+                    .or_else(|| generic(input, "BTreeSet"))
+                    .or_else(|| generic(input, "HashSet"))
+                {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Map")
+                    .or_else(|| generic(input, "BTreeMap"))
+                    .or_else(|| generic(input, "HashMap"))
+                {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = input.strip_prefix("&mut ") {
+                    RefMut(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix('&') {
+                    Ref(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix("*const ") {
+                    Ptr(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix("*mut ") {
+                    PtrMut(Box::new(t.parse()?))
                 } else if input.starts_with("c_") {
                     #[cfg(not(feature = "language-c"))]
                     return Err(());
@@ -187,6 +251,8 @@ impl fmt::Display for Type {
         match self {
             Any => write!(f, "Any"),
             Unit => write!(f, "()"),
+            Tuple(ts) if ts.len() == 1 => write!(f, "({},)", ts[0]),
+            Tuple(ts) => write!(f, "({})", ts.iter().format(", ")),
             Bool => write!(f, "bool"),
             F32 => write!(f, "f32"),
             F64 => write!(f, "f64"),
@@ -206,8 +272,14 @@ impl fmt::Display for Type {
             Char => write!(f, "char"),
             Str => write!(f, "str"),
             String => write!(f, "String"),
+            Option(t) => write!(f, "Option<{}>", t),
             Box(t) => write!(f, "Box<{}>", t),
+            Rc(t) => write!(f, "Rc<{}>", t),
+            Arc(t) => write!(f, "Arc<{}>", t),
+            Cow(t) => write!(f, "Cow<{}>", t),
+            Weak(t) => write!(f, "Weak<{}>", t),
             Vec(t) => write!(f, "Vec<{}>", t),
+            Set(t) => write!(f, "BTreeSet<{}>", t),
             Map(k, v) => write!(f, "BTreeMap<{}, {}>", k, v),
             Ref(t) => write!(f, "&{}", t),
             RefMut(t) => write!(f, "&mut {}", t),
@@ -233,6 +305,14 @@ impl fmt::Display for Type {
                 crate::c::Type::ULong => write!(f, "c_ulong"),
                 crate::c::Type::ULongLong => write!(f, "c_ulonglong"),
                 crate::c::Type::Size_t => write!(f, "usize"),
+                crate::c::Type::Int8_t => write!(f, "i8"),
+                crate::c::Type::Int16_t => write!(f, "i16"),
+                crate::c::Type::Int32_t => write!(f, "i32"),
+                crate::c::Type::Int64_t => write!(f, "i64"),
+                crate::c::Type::UInt8_t => write!(f, "u8"),
+                crate::c::Type::UInt16_t => write!(f, "u16"),
+                crate::c::Type::UInt32_t => write!(f, "u32"),
+                crate::c::Type::UInt64_t => write!(f, "u64"),
                 crate::c::Type::Array(t, None) => write!(f, "*const {}", Ffi((**t).clone())),
                 crate::c::Type::Array(t, Some(n)) => write!(f, "[{}; {}]", Ffi((**t).clone()), n),
                 crate::c::Type::Ptr(t) => write!(f, "*const {}", Ffi((**t).clone())),
@@ -245,7 +325,7 @@ impl fmt::Display for Type {
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -0,0 +1,254 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{applied, enclosed, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://docs.scala-lang.org/tour/unified-types.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://www.scala-lang.org/api/current/scala/Unit.html
+    Unit,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Any.html
+    Any,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Nothing.html
+    Nothing,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Boolean.html
+    Boolean,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Byte.html
+    Byte,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Short.html
+    Short,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Int.html
+    Int,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Long.html
+    Long,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Float.html
+    Float,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Double.html
+    Double,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Char.html
+    Char,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Predef$.html#String=String
+    String,
+
+    /// See: https://www.scala-lang.org/api/current/scala/Option.html
+    Option(Box<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/collection/immutable/Seq.html
+    Seq(Box<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/collection/immutable/List.html
+    List(Box<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/collection/immutable/Vector.html
+    Vector(Box<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/Array.html
+    Array(Box<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/collection/immutable/Set.html
+    Set(Box<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/collection/immutable/Map.html
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://docs.scala-lang.org/tour/tuples.html
+    Tuple(Vec<Type>),
+
+    /// See: https://www.scala-lang.org/api/current/scala/util/Either.html
+    Either(Box<Type>, Box<Type>),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let input = [
+            "scala.collection.immutable.",
+            "scala.collection.",
+            "scala.util.",
+            "scala.",
+            "java.lang.",
+        ]
+        .iter()
+        .find_map(|package| input.strip_prefix(package))
+        .unwrap_or(input);
+        // Type arguments are given in square brackets, as in `Seq[Int]`:
+        let subscript = |name| applied(input, name, '[', ']');
+        Ok(match input {
+            "Unit" | "()" => Unit,
+            "Any" => Any,
+            "Nothing" => Nothing,
+            "Boolean" => Boolean,
+            "Byte" => Byte,
+            "Short" => Short,
+            "Int" => Int,
+            "Long" => Long,
+            "Float" => Float,
+            "Double" => Double,
+            "Char" => Char,
+            "String" => String,
+            _ => {
+                if let Some(t) = subscript("Option") {
+                    Option(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("Seq") {
+                    Seq(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("List") {
+                    List(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("Vector") {
+                    Vector(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("Array") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = subscript("Set") {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(ts) = subscript("Map") {
+                    let [k, v] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(ts) = subscript("Either") {
+                    let [l, r] = split(ts, ',')[..] else {
+                        return Err(());
+                    };
+                    Either(Box::new(l.parse()?), Box::new(r.parse()?))
+                } else if let Some(ts) = enclosed(input, '(', ')') {
+                    match split(ts, ',')[..] {
+                        [t] => return t.parse(),
+                        ref ts => Tuple(ts.iter().map(|t| t.parse()).try_collect()?),
+                    }
+                } else if let Some((n, ts)) = input
+                    .strip_prefix("Tuple")
+                    .and_then(|t| t.find('[').map(|i| t.split_at(i)))
+                {
+                    // The explicit `TupleN[A, B, ...]` form:
+                    let ts = split(enclosed(ts, '[', ']').ok_or(())?, ',');
+                    if n.parse() != Ok(ts.len()) {
+                        return Err(());
+                    }
+                    Tuple(ts.into_iter().map(str::parse).try_collect()?)
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Unit => write!(f, "Unit"),
+            Any => write!(f, "Any"),
+            Nothing => write!(f, "Nothing"),
+            Boolean => write!(f, "Boolean"),
+            Byte => write!(f, "Byte"),
+            Short => write!(f, "Short"),
+            Int => write!(f, "Int"),
+            Long => write!(f, "Long"),
+            Float => write!(f, "Float"),
+            Double => write!(f, "Double"),
+            Char => write!(f, "Char"),
+            String => write!(f, "String"),
+            Option(t) => write!(f, "Option[{}]", t),
+            Seq(t) => write!(f, "Seq[{}]", t),
+            List(t) => write!(f, "List[{}]", t),
+            Vector(t) => write!(f, "Vector[{}]", t),
+            Array(t) => write!(f, "Array[{}]", t),
+            Set(t) => write!(f, "Set[{}]", t),
+            Map(k, v) => write!(f, "Map[{}, {}]", k, v),
+            Tuple(ts) => write!(f, "({})", ts.iter().format(", ")),
+            Either(l, r) => write!(f, "Either[{}, {}]", l, r),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Unit,
+            rust::Type::Tuple(ts) if ts.is_empty() => Unit,
+            rust::Type::Tuple(ts) if ts.len() > 22 => return Err(()),
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Boolean,
+            rust::Type::F32 => Float,
+            rust::Type::F64 => Double,
+            rust::Type::I8 => Byte,
+            rust::Type::I16 => Short,
+            rust::Type::I32 => Int,
+            rust::Type::I64 => Long,
+            rust::Type::Char => Char,
+            rust::Type::Str | rust::Type::String => String,
+            // Binary data is conventionally an `Array[Byte]`, as in Spark's `BinaryType`:
+            rust::Type::Vec(t) if *t == rust::Type::U8 => Array(Box::new(Byte)),
+            rust::Type::Vec(t) => Seq(convert(*t)?),
+            rust::Type::Option(t) => Option(convert(*t)?),
+            rust::Type::Set(t) => Set(convert(*t)?),
+            rust::Type::Map(k, v) => Map(convert(*k)?, convert(*v)?),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Unit => rust::Type::Unit,
+            Any => rust::Type::Any,
+            Nothing => return None,
+            Boolean => rust::Type::Bool,
+            Byte => rust::Type::I8,
+            Short => rust::Type::I16,
+            Int => rust::Type::I32,
+            Long => rust::Type::I64,
+            Float => rust::Type::F32,
+            Double => rust::Type::F64,
+            Char => rust::Type::Char,
+            String => rust::Type::String,
+            Option(t) => rust::Type::Option(convert(t)?),
+            Array(t) if **t == Byte => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Seq(t) | List(t) | Vector(t) | Array(t) => rust::Type::Vec(convert(t)?),
+            Set(t) => rust::Type::Set(convert(t)?),
+            Map(k, v) => rust::Type::Map(convert(k)?, convert(v)?),
+            Tuple(ts) => rust::Type::Tuple(
+                ts.iter()
+                    .map(|t| t.to_rust())
+                    .collect::<core::option::Option<_>>()?,
+            ),
+            Either(_, _) => return None, // no `Result` in the Rust type model
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -1,19 +1,106 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    parse::{enclosed, generic, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
     rust,
 };
+use itertools::Itertools;
 
 /// See: https://developer.apple.com/documentation/swift#Standard-Library
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
+    /// See: https://developer.apple.com/documentation/swift/void
+    Void,
+
+    /// See: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/types#Any-Type
+    Any,
+
     /// See: https://developer.apple.com/documentation/swift/bool
     Bool,
 
+    /// See: https://developer.apple.com/documentation/swift/float16
+    Float16,
+
+    /// See: https://developer.apple.com/documentation/swift/float
+    Float,
+
     /// See: https://developer.apple.com/documentation/swift/double
     Double,
+
+    /// See: https://developer.apple.com/documentation/swift/int8
+    Int8,
+
+    /// See: https://developer.apple.com/documentation/swift/int16
+    Int16,
+
+    /// See: https://developer.apple.com/documentation/swift/int32
+    Int32,
+
+    /// See: https://developer.apple.com/documentation/swift/int64
+    Int64,
+
+    /// See: https://developer.apple.com/documentation/swift/int
+    Int,
+
+    /// See: https://developer.apple.com/documentation/swift/uint8
+    UInt8,
+
+    /// See: https://developer.apple.com/documentation/swift/uint16
+    UInt16,
+
+    /// See: https://developer.apple.com/documentation/swift/uint32
+    UInt32,
+
+    /// See: https://developer.apple.com/documentation/swift/uint64
+    UInt64,
+
+    /// See: https://developer.apple.com/documentation/swift/uint
+    UInt,
+
+    /// See: https://developer.apple.com/documentation/swift/character
+    Character,
+
+    /// See: https://developer.apple.com/documentation/swift/string
+    String,
+
+    /// See: https://developer.apple.com/documentation/foundation/data
+    Data,
+
+    /// See: https://developer.apple.com/documentation/swift/array
+    Array(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/dictionary
+    Dictionary(Box<Type>, Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/set
+    Set(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/optional
+    Optional(Box<Type>),
+
+    /// See: https://docs.swift.org/swift-book/documentation/the-swift-programming-language/types#Tuple-Type
+    Tuple(Vec<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/unsafepointer
+    UnsafePointer(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/unsafemutablepointer
+    UnsafeMutablePointer(Box<Type>),
+
+    /// See: https://developer.apple.com/documentation/swift/unsaferawpointer
+    UnsafeRawPointer,
+
+    /// See: https://developer.apple.com/documentation/swift/unsafemutablerawpointer
+    UnsafeMutableRawPointer,
+
+    /// See: https://developer.apple.com/documentation/swift/opaquepointer
+    OpaquePointer,
+
+    /// See: https://developer.apple.com/documentation/swift/c-interoperability
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
 }
 
 impl core::str::FromStr for Type {
@@ -21,10 +108,91 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
+        let input = input.trim();
         Ok(match input {
+            "Void" | "()" => Void,
+            "Any" => Any,
             "Bool" => Bool,
-            "Double" => Double,
-            _ => return Err(()),
+            "Float16" => Float16,
+            "Float" | "Float32" => Float,
+            "Double" | "Float64" => Double,
+            "Int8" => Int8,
+            "Int16" => Int16,
+            "Int32" => Int32,
+            "Int64" => Int64,
+            "Int" => Int,
+            "UInt8" => UInt8,
+            "UInt16" => UInt16,
+            "UInt32" => UInt32,
+            "UInt64" => UInt64,
+            "UInt" => UInt,
+            "Character" => Character,
+            "String" => String,
+            "Data" => Data,
+            "UnsafeRawPointer" => UnsafeRawPointer,
+            "UnsafeMutableRawPointer" => UnsafeMutableRawPointer,
+            "OpaquePointer" => OpaquePointer,
+            #[cfg(feature = "language-c")]
+            "CBool" => Ffi(crate::c::Type::Bool),
+            #[cfg(feature = "language-c")]
+            "CFloat" => Ffi(crate::c::Type::Float),
+            #[cfg(feature = "language-c")]
+            "CDouble" => Ffi(crate::c::Type::Double),
+            #[cfg(feature = "language-c")]
+            "CChar" => Ffi(crate::c::Type::Char),
+            #[cfg(feature = "language-c")]
+            "CSignedChar" => Ffi(crate::c::Type::SChar),
+            #[cfg(feature = "language-c")]
+            "CShort" => Ffi(crate::c::Type::Short),
+            #[cfg(feature = "language-c")]
+            "CInt" => Ffi(crate::c::Type::Int),
+            #[cfg(feature = "language-c")]
+            "CLong" => Ffi(crate::c::Type::Long),
+            #[cfg(feature = "language-c")]
+            "CLongLong" => Ffi(crate::c::Type::LongLong),
+            #[cfg(feature = "language-c")]
+            "CUnsignedChar" => Ffi(crate::c::Type::UChar),
+            #[cfg(feature = "language-c")]
+            "CUnsignedShort" => Ffi(crate::c::Type::UShort),
+            #[cfg(feature = "language-c")]
+            "CUnsignedInt" => Ffi(crate::c::Type::UInt),
+            #[cfg(feature = "language-c")]
+            "CUnsignedLong" => Ffi(crate::c::Type::ULong),
+            #[cfg(feature = "language-c")]
+            "CUnsignedLongLong" => Ffi(crate::c::Type::ULongLong),
+            _ => {
+                if let Some(t) = input.strip_suffix('?') {
+                    Optional(Box::new(t.parse()?))
+                } else if let Some(t) = enclosed(input, '[', ']') {
+                    match split(t, ':')[..] {
+                        [t] => Array(Box::new(t.parse()?)),
+                        [k, v] => Dictionary(Box::new(k.parse()?), Box::new(v.parse()?)),
+                        _ => return Err(()),
+                    }
+                } else if let Some(t) = enclosed(input, '(', ')') {
+                    match split(t, ',')[..] {
+                        [t] => t.parse()?, // a parenthesized type
+                        ref types => Tuple(types.iter().map(|t| t.parse()).try_collect()?),
+                    }
+                } else if let Some(t) = generic(input, "Array") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Dictionary") {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    Dictionary(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = generic(input, "Set") {
+                    Set(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Optional") {
+                    Optional(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "UnsafePointer") {
+                    UnsafePointer(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "UnsafeMutablePointer") {
+                    UnsafeMutablePointer(Box::new(t.parse()?))
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
@@ -33,14 +201,90 @@ impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Type::*;
         match self {
+            Void => write!(f, "Void"),
+            Any => write!(f, "Any"),
             Bool => write!(f, "Bool"),
+            Float16 => write!(f, "Float16"),
+            Float => write!(f, "Float"),
             Double => write!(f, "Double"),
+            Int8 => write!(f, "Int8"),
+            Int16 => write!(f, "Int16"),
+            Int32 => write!(f, "Int32"),
+            Int64 => write!(f, "Int64"),
+            Int => write!(f, "Int"),
+            UInt8 => write!(f, "UInt8"),
+            UInt16 => write!(f, "UInt16"),
+            UInt32 => write!(f, "UInt32"),
+            UInt64 => write!(f, "UInt64"),
+            UInt => write!(f, "UInt"),
+            Character => write!(f, "Character"),
+            String => write!(f, "String"),
+            Data => write!(f, "Data"),
+            Array(t) => write!(f, "[{}]", t),
+            Dictionary(k, v) => write!(f, "[{}: {}]", k, v),
+            Set(t) => write!(f, "Set<{}>", t),
+            Optional(t) => write!(f, "{}?", t),
+            Tuple(ts) => write!(f, "({})", ts.iter().format(", ")),
+            UnsafePointer(t) => write!(f, "UnsafePointer<{}>", t),
+            UnsafeMutablePointer(t) => write!(f, "UnsafeMutablePointer<{}>", t),
+            UnsafeRawPointer => write!(f, "UnsafeRawPointer"),
+            UnsafeMutableRawPointer => write!(f, "UnsafeMutableRawPointer"),
+            OpaquePointer => write!(f, "OpaquePointer"),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://developer.apple.com/documentation/swift/c-interoperability
+                crate::c::Type::Void => write!(f, "Void"),
+                crate::c::Type::Bool => write!(f, "CBool"),
+                crate::c::Type::Float => write!(f, "CFloat"),
+                crate::c::Type::Double => write!(f, "CDouble"),
+                crate::c::Type::Char => write!(f, "CChar"),
+                crate::c::Type::SChar => write!(f, "CSignedChar"),
+                crate::c::Type::Short => write!(f, "CShort"),
+                crate::c::Type::Int => write!(f, "CInt"),
+                crate::c::Type::Long => write!(f, "CLong"),
+                crate::c::Type::LongLong => write!(f, "CLongLong"),
+                crate::c::Type::SSize_t => write!(f, "Int"),
+                crate::c::Type::UChar => write!(f, "CUnsignedChar"),
+                crate::c::Type::UShort => write!(f, "CUnsignedShort"),
+                crate::c::Type::UInt => write!(f, "CUnsignedInt"),
+                crate::c::Type::ULong => write!(f, "CUnsignedLong"),
+                crate::c::Type::ULongLong => write!(f, "CUnsignedLongLong"),
+                crate::c::Type::Size_t => write!(f, "Int"),
+                crate::c::Type::Int8_t => write!(f, "Int8"),
+                crate::c::Type::Int16_t => write!(f, "Int16"),
+                crate::c::Type::Int32_t => write!(f, "Int32"),
+                crate::c::Type::Int64_t => write!(f, "Int64"),
+                crate::c::Type::UInt8_t => write!(f, "UInt8"),
+                crate::c::Type::UInt16_t => write!(f, "UInt16"),
+                crate::c::Type::UInt32_t => write!(f, "UInt32"),
+                crate::c::Type::UInt64_t => write!(f, "UInt64"),
+                crate::c::Type::Array(t, None) => {
+                    write!(f, "UnsafePointer<{}>", Ffi((**t).clone()))
+                }
+                // Fixed-size C arrays are imported as homogeneous tuples:
+                crate::c::Type::Array(t, Some(n)) => {
+                    let t = Ffi((**t).clone());
+                    write!(f, "({})", (0..*n).map(|_| &t).format(", "))
+                }
+                crate::c::Type::Ptr(t) if **t == crate::c::Type::Void => {
+                    write!(f, "UnsafeRawPointer")
+                }
+                crate::c::Type::PtrMut(t) if **t == crate::c::Type::Void => {
+                    write!(f, "UnsafeMutableRawPointer")
+                }
+                crate::c::Type::Ptr(t) => write!(f, "UnsafePointer<{}>", Ffi((**t).clone())),
+                crate::c::Type::PtrMut(t) => {
+                    write!(f, "UnsafeMutablePointer<{}>", Ffi((**t).clone()))
+                }
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "time_t"),
+            },
         }
     }
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -51,8 +295,48 @@ impl TryFrom<rust::Type> for Type {
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
         use Type::*;
         Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => Tuple(
+                ts.into_iter()
+                    .map(Self::try_from)
+                    .collect::<Result<_, _>>()?,
+            ),
             rust::Type::Bool => Bool,
-            rust::Type::F32 | rust::Type::F64 => Double,
+            rust::Type::F32 => Float,
+            rust::Type::F64 => Double,
+            rust::Type::I8 => Int8,
+            rust::Type::I16 => Int16,
+            rust::Type::I32 => Int32,
+            rust::Type::I64 => Int64,
+            rust::Type::Isize => Int,
+            rust::Type::U8 => UInt8,
+            rust::Type::U16 => UInt16,
+            rust::Type::U32 => UInt32,
+            rust::Type::U64 => UInt64,
+            rust::Type::Usize => UInt,
+            rust::Type::Char => Character,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Optional(Box::new(Self::try_from(*t)?)),
+            rust::Type::Box(t) | rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                Self::try_from(*t)?
+            }
+            rust::Type::Vec(t) if *t == rust::Type::U8 => Data,
+            rust::Type::Vec(t) => Array(Box::new(Self::try_from(*t)?)),
+            rust::Type::Set(t) => Set(Box::new(Self::try_from(*t)?)),
+            rust::Type::Map(k, v) => {
+                Dictionary(Box::new(Self::try_from(*k)?), Box::new(Self::try_from(*v)?))
+            }
+            #[cfg(feature = "language-c")]
+            rust::Type::Ptr(t) if *t == rust::Type::Ffi(crate::c::Type::Void) => UnsafeRawPointer,
+            #[cfg(feature = "language-c")]
+            rust::Type::PtrMut(t) if *t == rust::Type::Ffi(crate::c::Type::Void) => {
+                UnsafeMutableRawPointer
+            }
+            rust::Type::Ptr(t) => UnsafePointer(Box::new(Self::try_from(*t)?)),
+            rust::Type::PtrMut(t) => UnsafeMutablePointer(Box::new(Self::try_from(*t)?)),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
             _ => return Err(()),
         })
     }
@@ -62,8 +346,43 @@ impl crate::ToRust for Type {
     fn to_rust(&self) -> Option<rust::Type> {
         use Type::*;
         Some(match self {
+            Void => rust::Type::Unit,
+            Any => rust::Type::Any,
             Bool => rust::Type::Bool,
+            Float16 => return None, // no stable equivalent in Rust
+            Float => rust::Type::F32,
             Double => rust::Type::F64,
+            Int8 => rust::Type::I8,
+            Int16 => rust::Type::I16,
+            Int32 => rust::Type::I32,
+            Int64 => rust::Type::I64,
+            Int => rust::Type::Isize,
+            UInt8 => rust::Type::U8,
+            UInt16 => rust::Type::U16,
+            UInt32 => rust::Type::U32,
+            UInt64 => rust::Type::U64,
+            UInt => rust::Type::Usize,
+            Character => rust::Type::Char,
+            String => rust::Type::String,
+            Data => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Array(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
+            Dictionary(k, v) => rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?)),
+            Set(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Optional(t) => rust::Type::Option(Box::new(t.to_rust()?)),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            UnsafePointer(t) => rust::Type::Ptr(Box::new(t.to_rust()?)),
+            UnsafeMutablePointer(t) => rust::Type::PtrMut(Box::new(t.to_rust()?)),
+            #[cfg(feature = "language-c")]
+            UnsafeRawPointer => rust::Type::Ptr(Box::new(rust::Type::Ffi(crate::c::Type::Void))),
+            #[cfg(feature = "language-c")]
+            UnsafeMutableRawPointer | OpaquePointer => {
+                rust::Type::PtrMut(Box::new(rust::Type::Ffi(crate::c::Type::Void)))
+            }
+            #[cfg(not(feature = "language-c"))]
+            UnsafeRawPointer | UnsafeMutableRawPointer | OpaquePointer => return None,
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
         })
     }
 }
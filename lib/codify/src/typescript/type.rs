@@ -1,19 +1,168 @@
 // This is free and unencumbered software released into the public domain.
 
 use crate::{
-    prelude::{fmt, format, Cow, Named},
+    parse::{enclosed, generic, split},
+    prelude::{fmt, format, vec, Box, Cow, Named, String, ToString, Vec},
     rust,
 };
+use itertools::Itertools;
 
 /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Type {
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#any
+    Any,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/functions.html#unknown
+    Unknown,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/functions.html#never
+    Never,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/functions.html#void
+    Void,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#null-and-undefined
+    Null,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#null-and-undefined
+    Undefined,
+
     /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#boolean_type
     Boolean,
 
     /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html
     Number,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Data_structures#bigint_type
+    BigInt,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html
+    String,
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#arrays
+    Array(Box<Type>),
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/objects.html#the-readonlyarray-type
+    ReadonlyArray(Box<Type>),
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/objects.html#tuple-types
+    Tuple(Vec<Type>),
+
+    /// See: https://www.typescriptlang.org/docs/handbook/utility-types.html#recordkeys-type
+    Record(Box<Type>, Box<Type>),
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Map
+    Map(Box<Type>, Box<Type>),
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Set
+    Set(Box<Type>),
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#union-types
+    Union(Vec<Type>),
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#literal-types
+    Literal(String),
+
+    /// See: https://www.typescriptlang.org/docs/handbook/2/everyday-types.html#object-types
+    Object(Vec<(String, Type)>),
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int8Array
+    Int8Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int16Array
+    Int16Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Int32Array
+    Int32Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigInt64Array
+    BigInt64Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8Array
+    Uint8Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint8ClampedArray
+    Uint8ClampedArray,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint16Array
+    Uint16Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Uint32Array
+    Uint32Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/BigUint64Array
+    BigUint64Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float32Array
+    Float32Array,
+
+    /// See: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Float64Array
+    Float64Array,
+}
+
+/// How to map Rust's 64-bit integer types, which exceed the range that a
+/// JavaScript `number` can represent exactly.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BigIntMapping {
+    /// Map `i64` and `u64` to `number`, as `JSON.parse()` would.
+    #[default]
+    Number,
+
+    /// Map `i64` and `u64` to `bigint`.
+    BigInt,
+}
+
+impl Type {
+    /// Converts a Rust type, mapping `i64` and `u64` as specified.
+    pub fn from_rust(input: rust::Type, bigint: BigIntMapping) -> Result<Self, ()> {
+        use Type::*;
+        let int64 = match bigint {
+            BigIntMapping::Number => Number,
+            BigIntMapping::BigInt => BigInt,
+        };
+        let convert = |t: rust::Type| Self::from_rust(t, bigint);
+        Ok(match input {
+            rust::Type::Any => Any,
+            rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(convert).try_collect()?),
+            rust::Type::Bool => Boolean,
+            rust::Type::F32 | rust::Type::F64 => Number,
+            rust::Type::I8 | rust::Type::U8 => Number,
+            rust::Type::I16 | rust::Type::U16 => Number,
+            rust::Type::I32 | rust::Type::U32 => Number,
+            rust::Type::I64 | rust::Type::U64 => int64,
+            rust::Type::I128 | rust::Type::U128 => BigInt,
+            rust::Type::Isize | rust::Type::Usize => Number,
+            rust::Type::Char | rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => match convert(*t)? {
+                Union(ts) if ts.contains(&Null) => Union(ts), // already nullable
+                t => Union(vec![t, Null]),
+            },
+            rust::Type::Box(t) | rust::Type::Rc(t) | rust::Type::Arc(t) | rust::Type::Cow(t) => {
+                convert(*t)?
+            }
+            rust::Type::Ref(t) => convert(*t)?,
+            rust::Type::Vec(t) => match (*t, bigint) {
+                (rust::Type::I8, _) => Int8Array,
+                (rust::Type::I16, _) => Int16Array,
+                (rust::Type::I32, _) => Int32Array,
+                (rust::Type::I64, BigIntMapping::BigInt) => BigInt64Array,
+                (rust::Type::U8, _) => Uint8Array,
+                (rust::Type::U16, _) => Uint16Array,
+                (rust::Type::U32, _) => Uint32Array,
+                (rust::Type::U64, BigIntMapping::BigInt) => BigUint64Array,
+                (rust::Type::F32, _) => Float32Array,
+                (rust::Type::F64, _) => Float64Array,
+                (t, _) => Array(Box::new(convert(t)?)),
+            },
+            rust::Type::Set(t) => Set(Box::new(convert(*t)?)),
+            rust::Type::Map(k, v) => Record(Box::new(convert(*k)?), Box::new(convert(*v)?)),
+            _ => return Err(()),
+        })
+    }
 }
 
 impl core::str::FromStr for Type {
@@ -21,26 +170,148 @@ impl core::str::FromStr for Type {
 
     fn from_str(input: &str) -> Result<Self, Self::Err> {
         use Type::*;
+        let input = input.trim();
+        let input = input.strip_prefix('|').map(str::trim).unwrap_or(input);
+        let variants = split(input, '|');
+        if variants.len() > 1 {
+            return Ok(Union(variants.into_iter().map(str::parse).try_collect()?));
+        }
         Ok(match input {
+            "any" => Any,
+            "unknown" => Unknown,
+            "never" => Never,
+            "void" => Void,
+            "null" => Null,
+            "undefined" => Undefined,
             "boolean" => Boolean,
             "number" => Number,
-            _ => return Err(()),
+            "bigint" => BigInt,
+            "string" => String,
+            "Int8Array" => Int8Array,
+            "Int16Array" => Int16Array,
+            "Int32Array" => Int32Array,
+            "BigInt64Array" => BigInt64Array,
+            "Uint8Array" => Uint8Array,
+            "Uint8ClampedArray" => Uint8ClampedArray,
+            "Uint16Array" => Uint16Array,
+            "Uint32Array" => Uint32Array,
+            "BigUint64Array" => BigUint64Array,
+            "Float32Array" => Float32Array,
+            "Float64Array" => Float64Array,
+            _ => {
+                if let Some(t) = input.strip_prefix("readonly ") {
+                    match t.parse()? {
+                        Array(t) => ReadonlyArray(t),
+                        Tuple(ts) => Tuple(ts), // readonly tuples are still tuples
+                        _ => return Err(()),
+                    }
+                } else if let Some(t) = enclosed(input, '[', ']') {
+                    match t {
+                        "" => Tuple(Vec::new()),
+                        t => Tuple(split(t, ',').into_iter().map(str::parse).try_collect()?),
+                    }
+                } else if let Some(t) = input.strip_suffix("[]") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = enclosed(input, '(', ')') {
+                    t.parse()?
+                } else if let Some(t) = enclosed(input, '{', '}') {
+                    // Properties are separated by `;` or `,`, or a mix of both:
+                    let mut properties = Vec::new();
+                    for property in split(t, ';').into_iter().flat_map(|t| split(t, ',')) {
+                        if property.is_empty() {
+                            continue; // a trailing separator
+                        }
+                        let (name, t) = property.split_once(':').ok_or(())?;
+                        properties.push(match name.trim().strip_suffix('?') {
+                            Some(name) => {
+                                (name.trim().to_string(), Union(vec![t.parse()?, Undefined]))
+                            }
+                            None => (name.trim().to_string(), t.parse()?),
+                        });
+                    }
+                    Object(properties)
+                } else if let Some(s) = literal(input) {
+                    Literal(s.to_string())
+                } else if let Some(t) = generic(input, "Array") {
+                    Array(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "ReadonlyArray") {
+                    ReadonlyArray(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "Record") {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    Record(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = generic(input, "Map") {
+                    let [k, v] = split(t, ',')[..] else {
+                        return Err(());
+                    };
+                    Map(Box::new(k.parse()?), Box::new(v.parse()?))
+                } else if let Some(t) = generic(input, "Set") {
+                    Set(Box::new(t.parse()?))
+                } else {
+                    return Err(());
+                }
+            }
         })
     }
 }
 
+/// Returns the contents of `input` if it is a string literal, as in `"foo"`.
+fn literal(input: &str) -> Option<&str> {
+    let contents = input
+        .strip_prefix('"')
+        .and_then(|s| s.strip_suffix('"'))
+        .or_else(|| input.strip_prefix('\'').and_then(|s| s.strip_suffix('\'')))?;
+    (!contents.contains(['"', '\''])).then_some(contents)
+}
+
 impl fmt::Display for Type {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         use Type::*;
         match self {
+            Any => write!(f, "any"),
+            Unknown => write!(f, "unknown"),
+            Never => write!(f, "never"),
+            Void => write!(f, "void"),
+            Null => write!(f, "null"),
+            Undefined => write!(f, "undefined"),
             Boolean => write!(f, "boolean"),
             Number => write!(f, "number"),
+            BigInt => write!(f, "bigint"),
+            String => write!(f, "string"),
+            Array(t) if matches!(**t, Union(_)) => write!(f, "({})[]", t),
+            Array(t) => write!(f, "{}[]", t),
+            ReadonlyArray(t) => write!(f, "ReadonlyArray<{}>", t),
+            Tuple(ts) => write!(f, "[{}]", ts.iter().format(", ")),
+            Record(k, v) => write!(f, "Record<{}, {}>", k, v),
+            Map(k, v) => write!(f, "Map<{}, {}>", k, v),
+            Set(t) => write!(f, "Set<{}>", t),
+            Union(ts) => write!(f, "{}", ts.iter().format(" | ")),
+            Literal(s) => write!(f, "\"{}\"", s),
+            Object(ps) if ps.is_empty() => write!(f, "{{}}"),
+            Object(ps) => write!(
+                f,
+                "{{ {} }}",
+                ps.iter()
+                    .format_with("; ", |(k, v), f| f(&format_args!("{}: {}", k, v)))
+            ),
+            Int8Array => write!(f, "Int8Array"),
+            Int16Array => write!(f, "Int16Array"),
+            Int32Array => write!(f, "Int32Array"),
+            BigInt64Array => write!(f, "BigInt64Array"),
+            Uint8Array => write!(f, "Uint8Array"),
+            Uint8ClampedArray => write!(f, "Uint8ClampedArray"),
+            Uint16Array => write!(f, "Uint16Array"),
+            Uint32Array => write!(f, "Uint32Array"),
+            BigUint64Array => write!(f, "BigUint64Array"),
+            Float32Array => write!(f, "Float32Array"),
+            Float64Array => write!(f, "Float64Array"),
         }
     }
 }
 
 impl Named for Type {
-    fn name(&self) -> Cow<str> {
+    fn name(&self) -> Cow<'_, str> {
         Cow::Owned(format!("{}", self))
     }
 }
@@ -49,12 +320,7 @@ impl TryFrom<rust::Type> for Type {
     type Error = ();
 
     fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
-        use Type::*;
-        Ok(match input {
-            rust::Type::Bool => Boolean,
-            rust::Type::F32 | rust::Type::F64 => Number,
-            _ => return Err(()),
-        })
+        Self::from_rust(input, BigIntMapping::default())
     }
 }
 
@@ -62,8 +328,40 @@ impl crate::ToRust for Type {
     fn to_rust(&self) -> Option<rust::Type> {
         use Type::*;
         Some(match self {
+            Any | Unknown => rust::Type::Any,
+            Never => return None,
+            Void | Null | Undefined => rust::Type::Unit,
             Boolean => rust::Type::Bool,
             Number => rust::Type::F64,
+            BigInt => rust::Type::I128,
+            String | Literal(_) => rust::Type::String,
+            Array(t) | ReadonlyArray(t) => rust::Type::Vec(Box::new(t.to_rust()?)),
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            Record(k, v) | Map(k, v) => {
+                rust::Type::Map(Box::new(k.to_rust()?), Box::new(v.to_rust()?))
+            }
+            Set(t) => rust::Type::Set(Box::new(t.to_rust()?)),
+            Union(ts) => {
+                // Only nullable types, as in `T | null`, have a Rust equivalent:
+                let (nulls, rest): (Vec<_>, Vec<_>) =
+                    ts.iter().partition(|t| matches!(t, Null | Undefined));
+                match rest[..] {
+                    [t] if !nulls.is_empty() => rust::Type::Option(Box::new(t.to_rust()?)),
+                    _ => return None,
+                }
+            }
+            Object(_) => return None,
+            Int8Array => rust::Type::Vec(Box::new(rust::Type::I8)),
+            Int16Array => rust::Type::Vec(Box::new(rust::Type::I16)),
+            Int32Array => rust::Type::Vec(Box::new(rust::Type::I32)),
+            BigInt64Array => rust::Type::Vec(Box::new(rust::Type::I64)),
+            Uint8Array | Uint8ClampedArray => rust::Type::Vec(Box::new(rust::Type::U8)),
+            Uint16Array => rust::Type::Vec(Box::new(rust::Type::U16)),
+            Uint32Array => rust::Type::Vec(Box::new(rust::Type::U32)),
+            BigUint64Array => rust::Type::Vec(Box::new(rust::Type::U64)),
+            Float32Array => rust::Type::Vec(Box::new(rust::Type::F32)),
+            Float64Array => rust::Type::Vec(Box::new(rust::Type::F64)),
         })
     }
 }
@@ -0,0 +1,118 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    prelude::{fmt, format, Cow, Named},
+    rust,
+};
+
+/// See: https://webassembly.github.io/spec/core/syntax/types.html
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#number-types
+    I32,
+
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#number-types
+    I64,
+
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#number-types
+    F32,
+
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#number-types
+    F64,
+
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#vector-types
+    V128,
+
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#reference-types
+    FuncRef,
+
+    /// See: https://webassembly.github.io/spec/core/syntax/types.html#reference-types
+    ExternRef,
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        Ok(match input.trim() {
+            "i32" => I32,
+            "i64" => I64,
+            "f32" => F32,
+            "f64" => F64,
+            "v128" => V128,
+            "funcref" => FuncRef,
+            "externref" => ExternRef,
+            _ => return Err(()),
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            I32 => write!(f, "i32"),
+            I64 => write!(f, "i64"),
+            F32 => write!(f, "f32"),
+            F64 => write!(f, "f64"),
+            V128 => write!(f, "v128"),
+            FuncRef => write!(f, "funcref"),
+            ExternRef => write!(f, "externref"),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+/// Lowers the Rust types that are a single core value, following the
+/// canonical ABI: the integers narrower than 64 bits widen to `i32`, and
+/// pointers and references to sized types are `i32` addresses into the
+/// linear memory of `wasm32`.
+///
+/// See: https://github.com/WebAssembly/component-model/blob/main/design/mvp/CanonicalABI.md#flattening
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        Ok(match input {
+            rust::Type::Bool | rust::Type::Char => I32,
+            rust::Type::I8 | rust::Type::I16 | rust::Type::I32 | rust::Type::Isize => I32,
+            rust::Type::U8 | rust::Type::U16 | rust::Type::U32 | rust::Type::Usize => I32,
+            rust::Type::I64 | rust::Type::U64 => I64,
+            rust::Type::F32 => F32,
+            rust::Type::F64 => F64,
+            rust::Type::Box(t) | rust::Type::Ref(t) | rust::Type::RefMut(t) if is_sized(&t) => I32,
+            rust::Type::Ptr(t) | rust::Type::PtrMut(t) if is_sized(&t) => I32,
+            _ => return Err(()),
+        })
+    }
+}
+
+/// Checks whether a Rust type is sized, so that a pointer to it is thin,
+/// rather than a fat pointer that pairs an address with a length or a
+/// vtable and so does not fit into a single core value.
+fn is_sized(t: &rust::Type) -> bool {
+    !matches!(t, rust::Type::Str | rust::Type::Any)
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        Some(match self {
+            I32 => rust::Type::I32,
+            I64 => rust::Type::I64,
+            F32 => rust::Type::F32,
+            F64 => rust::Type::F64,
+            V128 | FuncRef | ExternRef => return None,
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -0,0 +1,137 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::Type;
+use crate::prelude::{fmt, String, Vec};
+use itertools::Itertools;
+
+/// See: https://component-model.bytecodealliance.org/design/wit.html#records
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Record {
+    pub name: String,
+    pub fields: Vec<(String, Type)>,
+}
+
+impl Record {
+    pub fn new(name: impl Into<String>, fields: Vec<(String, Type)>) -> Self {
+        Self {
+            name: name.into(),
+            fields,
+        }
+    }
+}
+
+impl fmt::Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let fields = self
+            .fields
+            .iter()
+            .format_with(", ", |(name, t), f| f(&format_args!("{}: {}", name, t)));
+        write!(f, "record {} {{ {} }}", self.name, fields)
+    }
+}
+
+/// See: https://component-model.bytecodealliance.org/design/wit.html#variants
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Variant {
+    pub name: String,
+    pub cases: Vec<(String, Option<Type>)>,
+}
+
+impl Variant {
+    pub fn new(name: impl Into<String>, cases: Vec<(String, Option<Type>)>) -> Self {
+        Self {
+            name: name.into(),
+            cases,
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let cases = self.cases.iter().format_with(", ", |(name, t), f| match t {
+            Some(t) => f(&format_args!("{}({})", name, t)),
+            None => f(name),
+        });
+        write!(f, "variant {} {{ {} }}", self.name, cases)
+    }
+}
+
+/// See: https://component-model.bytecodealliance.org/design/wit.html#enums
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enum {
+    pub name: String,
+    pub cases: Vec<String>,
+}
+
+impl Enum {
+    pub fn new(name: impl Into<String>, cases: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            cases,
+        }
+    }
+}
+
+impl fmt::Display for Enum {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "enum {} {{ {} }}",
+            self.name,
+            self.cases.iter().format(", ")
+        )
+    }
+}
+
+/// See: https://component-model.bytecodealliance.org/design/wit.html#flags
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Flags {
+    pub name: String,
+    pub flags: Vec<String>,
+}
+
+impl Flags {
+    pub fn new(name: impl Into<String>, flags: Vec<String>) -> Self {
+        Self {
+            name: name.into(),
+            flags,
+        }
+    }
+}
+
+impl fmt::Display for Flags {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "flags {} {{ {} }}",
+            self.name,
+            self.flags.iter().format(", ")
+        )
+    }
+}
+
+/// A resource, whose values are only ever passed around as `own` or
+/// `borrow` handles.
+///
+/// See: https://component-model.bytecodealliance.org/design/wit.html#resources
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resource {
+    pub name: String,
+}
+
+impl Resource {
+    pub fn new(name: impl Into<String>) -> Self {
+        Self { name: name.into() }
+    }
+}
+
+impl fmt::Display for Resource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "resource {};", self.name)
+    }
+}
@@ -0,0 +1,304 @@
+// This is free and unencumbered software released into the public domain.
+
+use super::{Enum, Flags, Record, Resource, Variant};
+use crate::{
+    parse::{generic, split},
+    prelude::{fmt, format, vec, Box, Cow, Named, Vec},
+    rust, wasm,
+};
+use itertools::Itertools;
+
+/// See: https://component-model.bytecodealliance.org/design/wit.html#types
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    Bool,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    S8,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    S16,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    S32,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    S64,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    U8,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    U16,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    U32,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    U64,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    F32,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    F64,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    Char,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#primitive-types
+    String,
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#lists
+    List(Box<Type>),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#options
+    Option(Box<Type>),
+
+    /// A result with an optional success type and an optional error type,
+    /// as in `result`, `result<T>`, `result<_, E>`, or `result<T, E>`.
+    ///
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#results
+    Result(Option<Box<Type>>, Option<Box<Type>>),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#tuples
+    Tuple(Vec<Type>),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#records
+    Record(Record),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#variants
+    Variant(Variant),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#enums
+    Enum(Enum),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#flags
+    Flags(Flags),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#resources
+    Own(Resource),
+
+    /// See: https://component-model.bytecodealliance.org/design/wit.html#resources
+    Borrow(Resource),
+}
+
+impl Type {
+    /// Flattens this type into the core wasm values that the canonical ABI
+    /// passes it as, as in the `i32` address and `i32` length of a `string`.
+    ///
+    /// Integers narrower than 64 bits, characters, enums, and handles are
+    /// each an `i32`; strings and lists are an address and a length; records
+    /// and tuples are the concatenation of their fields; flags are an `i32`
+    /// per 32 flags; and variants, options, and results are an `i32`
+    /// discriminant followed by the join of their cases. A function with more
+    /// than 16 flattened parameters, or more than 1 flattened result, instead
+    /// passes them through linear memory.
+    ///
+    /// See: https://github.com/WebAssembly/component-model/blob/main/design/mvp/CanonicalABI.md#flattening
+    pub fn flatten(&self) -> Vec<wasm::Type> {
+        use Type::*;
+        match self {
+            Bool | S8 | S16 | S32 | U8 | U16 | U32 | Char => vec![wasm::Type::I32],
+            Enum(_) | Own(_) | Borrow(_) => vec![wasm::Type::I32],
+            S64 | U64 => vec![wasm::Type::I64],
+            F32 => vec![wasm::Type::F32],
+            F64 => vec![wasm::Type::F64],
+            String | List(_) => vec![wasm::Type::I32, wasm::Type::I32],
+            Tuple(ts) => ts.iter().flat_map(Self::flatten).collect(),
+            Record(r) => r.fields.iter().flat_map(|(_, t)| t.flatten()).collect(),
+            Flags(f) => vec![wasm::Type::I32; (f.flags.len() + 31) / 32],
+            Option(t) => flatten_cases([None, Some(&**t)]),
+            Result(t, e) => flatten_cases([t.as_deref(), e.as_deref()]),
+            Variant(v) => flatten_cases(v.cases.iter().map(|(_, t)| t.as_ref())),
+        }
+    }
+}
+
+/// Flattens the cases of a variant into its discriminant followed by, at
+/// each position, the narrowest core type that all cases fit into.
+fn flatten_cases<'a>(
+    cases: impl IntoIterator<Item = core::option::Option<&'a Type>>,
+) -> Vec<wasm::Type> {
+    let mut flat = vec![wasm::Type::I32];
+    for t in cases.into_iter().flatten() {
+        for (i, t) in t.flatten().into_iter().enumerate() {
+            match flat.get_mut(i + 1) {
+                Some(u) if *u == t => {}
+                Some(u) => {
+                    *u = match (&*u, &t) {
+                        (wasm::Type::I32, wasm::Type::F32) | (wasm::Type::F32, wasm::Type::I32) => {
+                            wasm::Type::I32
+                        }
+                        _ => wasm::Type::I64,
+                    }
+                }
+                None => flat.push(t),
+            }
+        }
+    }
+    flat
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let args = |name: &str| generic(input, name).map(|ts| split(ts, ','));
+        Ok(match input {
+            "bool" => Bool,
+            "s8" => S8,
+            "s16" => S16,
+            "s32" => S32,
+            "s64" => S64,
+            "u8" => U8,
+            "u16" => U16,
+            "u32" => U32,
+            "u64" => U64,
+            "f32" | "float32" => F32,
+            "f64" | "float64" => F64,
+            "char" => Char,
+            "string" => String,
+            "result" => Result(None, None),
+            _ => {
+                if let Some(t) = generic(input, "list") {
+                    List(Box::new(t.parse()?))
+                } else if let Some(t) = generic(input, "option") {
+                    Option(Box::new(t.parse()?))
+                } else if let Some(ts) = args("result") {
+                    let parse = |t: &str| match t {
+                        "_" => Ok(None),
+                        t => t.parse().map(|t| Some(Box::new(t))),
+                    };
+                    match ts[..] {
+                        [t] => Result(parse(t)?, None),
+                        [t, e] => Result(parse(t)?, parse(e)?),
+                        _ => return Err(()),
+                    }
+                } else if let Some(ts) = args("tuple") {
+                    Tuple(ts.into_iter().map(str::parse).try_collect()?)
+                } else if let Some(r) = generic(input, "own") {
+                    Own(Resource::new(r))
+                } else if let Some(r) = generic(input, "borrow") {
+                    Borrow(Resource::new(r))
+                } else {
+                    // A user-defined type can't be resolved without its
+                    // definition:
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Bool => write!(f, "bool"),
+            S8 => write!(f, "s8"),
+            S16 => write!(f, "s16"),
+            S32 => write!(f, "s32"),
+            S64 => write!(f, "s64"),
+            U8 => write!(f, "u8"),
+            U16 => write!(f, "u16"),
+            U32 => write!(f, "u32"),
+            U64 => write!(f, "u64"),
+            F32 => write!(f, "f32"),
+            F64 => write!(f, "f64"),
+            Char => write!(f, "char"),
+            String => write!(f, "string"),
+            List(t) => write!(f, "list<{}>", t),
+            Option(t) => write!(f, "option<{}>", t),
+            Result(None, None) => write!(f, "result"),
+            Result(Some(t), None) => write!(f, "result<{}>", t),
+            Result(None, Some(e)) => write!(f, "result<_, {}>", e),
+            Result(Some(t), Some(e)) => write!(f, "result<{}, {}>", t, e),
+            Tuple(ts) => write!(f, "tuple<{}>", ts.iter().format(", ")),
+            Record(r) => write!(f, "{}", r.name),
+            Variant(v) => write!(f, "{}", v.name),
+            Enum(e) => write!(f, "{}", e.name),
+            Flags(flags) => write!(f, "{}", flags.name),
+            Own(r) => write!(f, "own<{}>", r.name),
+            Borrow(r) => write!(f, "borrow<{}>", r.name),
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Tuple(ts) if !ts.is_empty() => {
+                Tuple(ts.into_iter().map(Self::try_from).try_collect()?)
+            }
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => F32,
+            rust::Type::F64 => F64,
+            rust::Type::I8 => S8,
+            rust::Type::I16 => S16,
+            rust::Type::I32 => S32,
+            rust::Type::I64 => S64,
+            rust::Type::U8 => U8,
+            rust::Type::U16 => U16,
+            rust::Type::U32 => U32,
+            rust::Type::U64 => U64,
+            rust::Type::Char => Char,
+            rust::Type::Str | rust::Type::String => String,
+            rust::Type::Option(t) => Option(convert(*t)?),
+            rust::Type::Vec(t) | rust::Type::Set(t) => List(convert(*t)?),
+            // WIT has no maps, so a map is conventionally a list of pairs:
+            rust::Type::Map(k, v) => List(Box::new(Tuple(vec![
+                Self::try_from(*k)?,
+                Self::try_from(*v)?,
+            ]))),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Bool => rust::Type::Bool,
+            S8 => rust::Type::I8,
+            S16 => rust::Type::I16,
+            S32 => rust::Type::I32,
+            S64 => rust::Type::I64,
+            U8 => rust::Type::U8,
+            U16 => rust::Type::U16,
+            U32 => rust::Type::U32,
+            U64 => rust::Type::U64,
+            F32 => rust::Type::F32,
+            F64 => rust::Type::F64,
+            Char => rust::Type::Char,
+            String => rust::Type::String,
+            List(t) => rust::Type::Vec(convert(t)?),
+            Option(t) => rust::Type::Option(convert(t)?),
+            Tuple(ts) => rust::Type::Tuple(
+                ts.iter()
+                    .map(|t| t.to_rust())
+                    .collect::<core::option::Option<_>>()?,
+            ),
+            Result(_, _) => return None, // no `Result` in the Rust type model
+            Record(_) | Variant(_) | Enum(_) | Flags(_) | Own(_) | Borrow(_) => return None,
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -0,0 +1,371 @@
+// This is free and unencumbered software released into the public domain.
+
+use crate::{
+    parse::{applied, split},
+    prelude::{fmt, format, Box, Cow, Named, Vec},
+    rust,
+};
+use itertools::Itertools;
+
+/// See: https://ziglang.org/documentation/master/#Primitive-Types
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Type {
+    /// See: https://ziglang.org/documentation/master/#void
+    Void,
+
+    /// See: https://ziglang.org/documentation/master/#noreturn
+    NoReturn,
+
+    /// See: https://ziglang.org/documentation/master/#anyopaque
+    AnyOpaque,
+
+    /// See: https://ziglang.org/documentation/master/#Primitive-Types
+    Bool,
+
+    /// A signed integer of arbitrary bit width, as in `i32` or `i7`.
+    ///
+    /// See: https://ziglang.org/documentation/master/#Integers
+    Int(u16),
+
+    /// An unsigned integer of arbitrary bit width, as in `u8` or `u21`.
+    ///
+    /// See: https://ziglang.org/documentation/master/#Integers
+    UInt(u16),
+
+    /// See: https://ziglang.org/documentation/master/#Primitive-Types
+    Isize,
+
+    /// See: https://ziglang.org/documentation/master/#Primitive-Types
+    Usize,
+
+    /// See: https://ziglang.org/documentation/master/#Floats
+    F16,
+
+    /// See: https://ziglang.org/documentation/master/#Floats
+    F32,
+
+    /// See: https://ziglang.org/documentation/master/#Floats
+    F64,
+
+    /// See: https://ziglang.org/documentation/master/#Floats
+    F80,
+
+    /// See: https://ziglang.org/documentation/master/#Floats
+    F128,
+
+    /// See: https://ziglang.org/documentation/master/#Arrays
+    Array(Box<Type>, usize),
+
+    /// See: https://ziglang.org/documentation/master/#Slices
+    Slice(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Slices
+    SliceMut(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Optionals
+    Optional(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Pointers
+    Ptr(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Pointers
+    PtrMut(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Pointers
+    ManyPtr(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Pointers
+    ManyPtrMut(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#C-Pointers
+    CPtr(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#C-Pointers
+    CPtrMut(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Error-Union-Type
+    ErrorUnion(Box<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#Tuples
+    Tuple(Vec<Type>),
+
+    /// See: https://ziglang.org/documentation/master/#C-Type-Primitives
+    #[cfg(feature = "language-c")]
+    Ffi(crate::c::Type),
+}
+
+impl core::str::FromStr for Type {
+    type Err = ();
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        use Type::*;
+        let input = input.trim();
+        let pointee = |prefix: &str| -> Option<(bool, &str)> {
+            let t = input.strip_prefix(prefix)?;
+            Some(match t.strip_prefix("const ") {
+                Some(t) => (true, t),
+                None => (false, t),
+            })
+        };
+        Ok(match input {
+            "void" => Void,
+            "noreturn" => NoReturn,
+            "anyopaque" => AnyOpaque,
+            "bool" => Bool,
+            "isize" => Isize,
+            "usize" => Usize,
+            "f16" => F16,
+            "f32" => F32,
+            "f64" => F64,
+            "f80" => F80,
+            "f128" => F128,
+            _ => {
+                if let Some(t) = input.strip_prefix('?') {
+                    Optional(Box::new(t.parse()?))
+                } else if let Some(t) = input.strip_prefix("anyerror!") {
+                    ErrorUnion(Box::new(t.parse()?))
+                } else if let Some((constant, t)) = pointee("*") {
+                    let t = Box::new(t.parse()?);
+                    if constant {
+                        Ptr(t)
+                    } else {
+                        PtrMut(t)
+                    }
+                } else if let Some((constant, t)) = pointee("[*c]") {
+                    let t = Box::new(t.parse()?);
+                    if constant {
+                        CPtr(t)
+                    } else {
+                        CPtrMut(t)
+                    }
+                } else if let Some((constant, t)) = pointee("[*]") {
+                    let t = Box::new(t.parse()?);
+                    if constant {
+                        ManyPtr(t)
+                    } else {
+                        ManyPtrMut(t)
+                    }
+                } else if let Some((constant, t)) = pointee("[]") {
+                    let t = Box::new(t.parse()?);
+                    if constant {
+                        Slice(t)
+                    } else {
+                        SliceMut(t)
+                    }
+                } else if let Some((n, t)) = input.strip_prefix('[').and_then(|t| t.split_once(']'))
+                {
+                    Array(Box::new(t.parse()?), n.trim().parse().map_err(|_| ())?)
+                } else if let Some(ts) = applied(input, "struct", '{', '}') {
+                    match ts {
+                        "" => Tuple(Vec::new()),
+                        ts => Tuple(
+                            split(ts, ',')
+                                .into_iter()
+                                .filter(|t| !t.is_empty())
+                                .map(str::parse)
+                                .try_collect()?,
+                        ),
+                    }
+                } else if let Some(bits) = input.strip_prefix('i').and_then(|n| n.parse().ok()) {
+                    Int(bits)
+                } else if let Some(bits) = input.strip_prefix('u').and_then(|n| n.parse().ok()) {
+                    UInt(bits)
+                } else if input.starts_with("c_") {
+                    #[cfg(not(feature = "language-c"))]
+                    return Err(());
+                    #[cfg(feature = "language-c")]
+                    match input {
+                        "c_char" => Ffi(crate::c::Type::Char),
+                        "c_short" => Ffi(crate::c::Type::Short),
+                        "c_ushort" => Ffi(crate::c::Type::UShort),
+                        "c_int" => Ffi(crate::c::Type::Int),
+                        "c_uint" => Ffi(crate::c::Type::UInt),
+                        "c_long" => Ffi(crate::c::Type::Long),
+                        "c_ulong" => Ffi(crate::c::Type::ULong),
+                        "c_longlong" => Ffi(crate::c::Type::LongLong),
+                        "c_ulonglong" => Ffi(crate::c::Type::ULongLong),
+                        _ => return Err(()),
+                    }
+                } else {
+                    return Err(());
+                }
+            }
+        })
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        use Type::*;
+        match self {
+            Void => write!(f, "void"),
+            NoReturn => write!(f, "noreturn"),
+            AnyOpaque => write!(f, "anyopaque"),
+            Bool => write!(f, "bool"),
+            Int(bits) => write!(f, "i{}", bits),
+            UInt(bits) => write!(f, "u{}", bits),
+            Isize => write!(f, "isize"),
+            Usize => write!(f, "usize"),
+            F16 => write!(f, "f16"),
+            F32 => write!(f, "f32"),
+            F64 => write!(f, "f64"),
+            F80 => write!(f, "f80"),
+            F128 => write!(f, "f128"),
+            Array(t, n) => write!(f, "[{}]{}", n, t),
+            Slice(t) => write!(f, "[]const {}", t),
+            SliceMut(t) => write!(f, "[]{}", t),
+            Optional(t) => write!(f, "?{}", t),
+            Ptr(t) => write!(f, "*const {}", t),
+            PtrMut(t) => write!(f, "*{}", t),
+            ManyPtr(t) => write!(f, "[*]const {}", t),
+            ManyPtrMut(t) => write!(f, "[*]{}", t),
+            CPtr(t) => write!(f, "[*c]const {}", t),
+            CPtrMut(t) => write!(f, "[*c]{}", t),
+            ErrorUnion(t) => write!(f, "anyerror!{}", t),
+            Tuple(ts) => write!(f, "struct {{ {} }}", ts.iter().format(", ")),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => match t {
+                // See: https://ziglang.org/documentation/master/#C-Type-Primitives
+                crate::c::Type::Void => write!(f, "void"),
+                crate::c::Type::Bool => write!(f, "bool"),
+                crate::c::Type::Float => write!(f, "f32"),
+                crate::c::Type::Double => write!(f, "f64"),
+                crate::c::Type::Char => write!(f, "c_char"),
+                crate::c::Type::SChar => write!(f, "i8"),
+                crate::c::Type::Short => write!(f, "c_short"),
+                crate::c::Type::Int => write!(f, "c_int"),
+                crate::c::Type::Long => write!(f, "c_long"),
+                crate::c::Type::LongLong => write!(f, "c_longlong"),
+                crate::c::Type::SSize_t => write!(f, "isize"),
+                crate::c::Type::UChar => write!(f, "u8"),
+                crate::c::Type::UShort => write!(f, "c_ushort"),
+                crate::c::Type::UInt => write!(f, "c_uint"),
+                crate::c::Type::ULong => write!(f, "c_ulong"),
+                crate::c::Type::ULongLong => write!(f, "c_ulonglong"),
+                crate::c::Type::Size_t => write!(f, "usize"),
+                crate::c::Type::Int8_t => write!(f, "i8"),
+                crate::c::Type::Int16_t => write!(f, "i16"),
+                crate::c::Type::Int32_t => write!(f, "i32"),
+                crate::c::Type::Int64_t => write!(f, "i64"),
+                crate::c::Type::UInt8_t => write!(f, "u8"),
+                crate::c::Type::UInt16_t => write!(f, "u16"),
+                crate::c::Type::UInt32_t => write!(f, "u32"),
+                crate::c::Type::UInt64_t => write!(f, "u64"),
+                crate::c::Type::Array(t, None) => write!(f, "[*c]{}", Ffi((**t).clone())),
+                crate::c::Type::Array(t, Some(n)) => write!(f, "[{}]{}", n, Ffi((**t).clone())),
+                crate::c::Type::Ptr(t) if **t == crate::c::Type::Void => {
+                    write!(f, "?*const anyopaque")
+                }
+                crate::c::Type::PtrMut(t) if **t == crate::c::Type::Void => {
+                    write!(f, "?*anyopaque")
+                }
+                crate::c::Type::Ptr(t) => write!(f, "[*c]const {}", Ffi((**t).clone())),
+                crate::c::Type::PtrMut(t) => write!(f, "[*c]{}", Ffi((**t).clone())),
+                #[cfg(feature = "libc")]
+                crate::c::Type::Time_t => write!(f, "i64"),
+            },
+        }
+    }
+}
+
+impl Named for Type {
+    fn name(&self) -> Cow<'_, str> {
+        Cow::Owned(format!("{}", self))
+    }
+}
+
+impl TryFrom<rust::Type> for Type {
+    type Error = ();
+
+    fn try_from(input: rust::Type) -> Result<Self, Self::Error> {
+        use Type::*;
+        let convert = |t: rust::Type| Self::try_from(t).map(Box::new);
+        Ok(match input {
+            rust::Type::Any => return Err(()),
+            rust::Type::Unit => Void,
+            rust::Type::Tuple(ts) => Tuple(ts.into_iter().map(Self::try_from).try_collect()?),
+            rust::Type::Bool => Bool,
+            rust::Type::F32 => F32,
+            rust::Type::F64 => F64,
+            rust::Type::I8 => Int(8),
+            rust::Type::I16 => Int(16),
+            rust::Type::I32 => Int(32),
+            rust::Type::I64 => Int(64),
+            rust::Type::I128 => Int(128),
+            rust::Type::Isize => Isize,
+            rust::Type::U8 => UInt(8),
+            rust::Type::U16 => UInt(16),
+            rust::Type::U32 => UInt(32),
+            rust::Type::U64 => UInt(64),
+            rust::Type::U128 => UInt(128),
+            rust::Type::Usize => Usize,
+            rust::Type::Char => UInt(21), // a Unicode code point, as in `std.unicode`
+            rust::Type::Str | rust::Type::String => Slice(Box::new(UInt(8))),
+            rust::Type::Option(t) => Optional(convert(*t)?),
+            rust::Type::Box(t) => PtrMut(convert(*t)?),
+            rust::Type::Vec(t) => SliceMut(convert(*t)?),
+            rust::Type::Ref(t) => Ptr(convert(*t)?),
+            rust::Type::RefMut(t) => PtrMut(convert(*t)?),
+            rust::Type::Ptr(t) => CPtr(convert(*t)?),
+            rust::Type::PtrMut(t) => CPtrMut(convert(*t)?),
+            #[cfg(feature = "language-c")]
+            rust::Type::Ffi(t) => Ffi(t),
+            _ => return Err(()),
+        })
+    }
+}
+
+impl crate::ToRust for Type {
+    fn to_rust(&self) -> Option<rust::Type> {
+        use Type::*;
+        let convert = |t: &Type| t.to_rust().map(Box::new);
+        Some(match self {
+            Void => rust::Type::Unit,
+            NoReturn => return None, // no stable equivalent in Rust
+            #[cfg(feature = "language-c")]
+            AnyOpaque => rust::Type::Ffi(crate::c::Type::Void),
+            #[cfg(not(feature = "language-c"))]
+            AnyOpaque => return None,
+            Bool => rust::Type::Bool,
+            Int(8) => rust::Type::I8,
+            Int(16) => rust::Type::I16,
+            Int(32) => rust::Type::I32,
+            Int(64) => rust::Type::I64,
+            Int(128) => rust::Type::I128,
+            UInt(8) => rust::Type::U8,
+            UInt(16) => rust::Type::U16,
+            UInt(21) => rust::Type::Char,
+            UInt(32) => rust::Type::U32,
+            UInt(64) => rust::Type::U64,
+            UInt(128) => rust::Type::U128,
+            Int(_) | UInt(_) => return None, // no arbitrary-width integers in Rust
+            Isize => rust::Type::Isize,
+            Usize => rust::Type::Usize,
+            F16 | F80 | F128 => return None, // no stable equivalent in Rust
+            F32 => rust::Type::F32,
+            F64 => rust::Type::F64,
+            #[cfg(feature = "language-c")]
+            Array(t, n) => rust::Type::Ffi(crate::c::Type::Array(
+                Box::new(crate::c::Type::try_from(t.to_rust()?).ok()?),
+                Some(*n),
+            )),
+            #[cfg(not(feature = "language-c"))]
+            Array(_, _) => return None,
+            Slice(t) if **t == UInt(8) => rust::Type::String,
+            Slice(t) | SliceMut(t) => rust::Type::Vec(convert(t)?),
+            Optional(t) => rust::Type::Option(convert(t)?),
+            Ptr(t) => rust::Type::Ref(convert(t)?),
+            PtrMut(t) => rust::Type::RefMut(convert(t)?),
+            ManyPtr(t) | CPtr(t) => rust::Type::Ptr(convert(t)?),
+            ManyPtrMut(t) | CPtrMut(t) => rust::Type::PtrMut(convert(t)?),
+            ErrorUnion(_) => return None, // no `Result` in the Rust type model
+            Tuple(ts) if ts.is_empty() => rust::Type::Unit,
+            Tuple(ts) => rust::Type::Tuple(ts.iter().map(|t| t.to_rust()).collect::<Option<_>>()?),
+            #[cfg(feature = "language-c")]
+            Ffi(t) => rust::Type::Ffi(t.clone()),
+        })
+    }
+}
+
+impl crate::Type for Type {}
@@ -0,0 +1,47 @@
+use codify::c::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn c_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 10] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int8_t, rust::Type::I8),
+        (Type::Int32_t, rust::Type::I32),
+        (Type::Int64_t, rust::Type::I64),
+        (Type::UInt8_t, rust::Type::U8),
+        (Type::UInt16_t, rust::Type::U16),
+        (Type::UInt64_t, rust::Type::U64),
+        (Type::Size_t, rust::Type::Usize),
+        (Type::SSize_t, rust::Type::Isize),
+    ];
+
+    for (c_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), c_type.to_rust());
+        assert_eq!(Ok(c_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn c_from_rust_pointers() {
+    assert_eq!(
+        Ok(Type::Ptr(Box::new(Type::Char))),
+        Type::try_from(rust::Type::Str)
+    );
+    assert_eq!(
+        Ok(Type::PtrMut(Box::new(Type::UInt32_t))),
+        Type::try_from(rust::Type::RefMut(Box::new(rust::Type::U32)))
+    );
+    assert_eq!(Err(()), Type::try_from(rust::Type::U128));
+}
+
+#[test]
+fn c_parse_fixed_width() {
+    for input in [
+        "int8_t", "int64_t", "uint16_t", "uint32_t", "size_t", "ssize_t",
+    ] {
+        assert_eq!(input, input.parse::<Type>().unwrap().to_string());
+    }
+    assert_eq!(Ok(Type::Long), "long int".parse());
+    assert_eq!(rust::Type::Ffi(Type::Long), Type::Long.to_rust().unwrap());
+}
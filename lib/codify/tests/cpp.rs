@@ -0,0 +1,136 @@
+use codify::cpp::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn cpp_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 8] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Int32, rust::Type::I32),
+        (Type::UInt64, rust::Type::U64),
+        (Type::String, rust::Type::String),
+        (
+            Type::UniquePtr(Box::new(Type::Int32)),
+            rust::Type::Box(Box::new(rust::Type::I32)),
+        ),
+        (
+            Type::SharedPtr(Box::new(Type::String)),
+            rust::Type::Arc(Box::new(rust::Type::String)),
+        ),
+        (
+            Type::Ref(Box::new(Type::Double)),
+            rust::Type::Ref(Box::new(rust::Type::F64)),
+        ),
+        (
+            Type::RefMut(Box::new(Type::Float)),
+            rust::Type::RefMut(Box::new(rust::Type::F32)),
+        ),
+    ];
+
+    for (cpp_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), cpp_type.to_rust());
+        assert_eq!(Ok(cpp_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn cpp_from_rust_smart_pointers() {
+    assert_eq!(
+        Ok(Type::SharedPtr(Box::new(Type::Bool))),
+        Type::try_from(rust::Type::Rc(Box::new(rust::Type::Bool)))
+    );
+    assert_eq!(
+        Ok(Type::WeakPtr(Box::new(Type::Bool))),
+        Type::try_from(rust::Type::Weak(Box::new(rust::Type::Bool)))
+    );
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Cow(Box::new(rust::Type::Bool)))
+    );
+}
+
+#[test]
+fn cpp_format() {
+    assert_eq!("std::int32_t", Type::Int32.to_string());
+    assert_eq!(
+        "std::unique_ptr<std::string>",
+        Type::UniquePtr(Box::new(Type::String)).to_string()
+    );
+    assert_eq!(
+        "std::shared_ptr<double>",
+        Type::SharedPtr(Box::new(Type::Double)).to_string()
+    );
+    assert_eq!(
+        "std::weak_ptr<double>",
+        Type::WeakPtr(Box::new(Type::Double)).to_string()
+    );
+    assert_eq!(
+        "std::string const&",
+        Type::Ref(Box::new(Type::String)).to_string()
+    );
+    assert_eq!("float&", Type::RefMut(Box::new(Type::Float)).to_string());
+    assert_eq!("bool const*", Type::Ptr(Box::new(Type::Bool)).to_string());
+}
+
+#[test]
+fn cpp_nested_const() {
+    let ptr_ptr = Type::Ptr(Box::new(Type::Ptr(Box::new(Type::Int8))));
+    assert_eq!("std::int8_t const* const*", ptr_ptr.to_string());
+    assert_eq!(Ok(ptr_ptr.clone()), ptr_ptr.to_string().parse());
+
+    let ref_ptr = Type::Ref(Box::new(Type::Ptr(Box::new(Type::Int32))));
+    assert_eq!("std::int32_t const* const&", ref_ptr.to_string());
+    assert_eq!(Ok(ref_ptr.clone()), ref_ptr.to_string().parse());
+
+    // A leading `const` qualifies the innermost pointee:
+    assert_eq!(
+        Ok(Type::PtrMut(Box::new(Type::Ptr(Box::new(Type::Int32))))),
+        "const int**".parse()
+    );
+    assert_eq!(
+        Ok(Type::RefMut(Box::new(Type::Ptr(Box::new(Type::UInt8))))),
+        "const unsigned char*&".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ptr(Box::new(Type::PtrMut(Box::new(Type::Bool))))),
+        "bool* const*".parse()
+    );
+}
+
+#[test]
+fn cpp_parse() {
+    assert_eq!(Ok(Type::Int32), "int32_t".parse());
+    assert_eq!(Ok(Type::Int32), "std::int32_t".parse());
+    assert_eq!(
+        Ok(Type::UniquePtr(Box::new(Type::Int32))),
+        "std::unique_ptr<std::int32_t>".parse()
+    );
+    assert_eq!(
+        Ok(Type::SharedPtr(Box::new(Type::String))),
+        "std::shared_ptr< std::string >".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ref(Box::new(Type::String))),
+        "const std::string&".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ref(Box::new(Type::String))),
+        "const std::string &".parse()
+    );
+    assert_eq!(Ok(Type::RefMut(Box::new(Type::Float))), "float&".parse());
+    assert_eq!(
+        Ok(Type::Ref(Box::new(Type::UniquePtr(Box::new(Type::Bool))))),
+        "const std::unique_ptr<bool>&".parse()
+    );
+    assert_eq!(Ok(Type::Int32), "int".parse());
+    assert_eq!(Ok(Type::Int8), "signed char".parse());
+    assert_eq!(
+        Ok(Type::UniquePtr(Box::new(Type::Int32))),
+        "unique_ptr<int>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ptr(Box::new(Type::String))),
+        "std::string const *".parse()
+    );
+    assert_eq!(Err(()), "std::unique_ptr<bogus>".parse::<Type>());
+    assert_eq!(Err(()), "&".parse::<Type>());
+}
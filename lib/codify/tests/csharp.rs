@@ -1,5 +1,6 @@
 use codify::csharp::Type;
 use codify::{rust, ToRust};
+use core::num::NonZeroUsize;
 
 #[test]
 fn csharp_to_rust_and_back() {
@@ -23,8 +24,8 @@ fn csharp_to_rust_and_back() {
     ];
 
     for (cs_type, rust_type) in type_map {
-        assert_eq!(Some(rust_type), cs_type.to_rust());
-        assert_eq!(Ok(cs_type.clone()), codify::csharp::Type::try_from(cs_type));
+        assert_eq!(Some(rust_type.clone()), cs_type.to_rust());
+        assert_eq!(Ok(cs_type), Type::try_from(rust_type));
     }
 }
 
@@ -169,3 +170,199 @@ fn csharp_malformated() {
         "System.Collections.Generic.Dictionary<long   ,string".parse::<Type>()
     );
 }
+
+#[test]
+fn csharp_interop() {
+    use codify::c;
+    use codify::csharp::{LibraryImport, StructLayout};
+
+    let string = Type::try_from(rust::Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))));
+    assert_eq!(
+        "[MarshalAs(UnmanagedType.LPUTF8Str)] string",
+        string.unwrap().to_string()
+    );
+    let boolean = Type::try_from(rust::Type::Ffi(c::Type::Bool)).unwrap();
+    assert_eq!("[MarshalAs(UnmanagedType.U1)] bool", boolean.to_string());
+    assert_eq!(
+        "[MarshalAs(UnmanagedType.U1)] bool",
+        boolean.unqualified().to_string()
+    );
+    assert_eq!(
+        Ok(boolean.clone()),
+        "[MarshalAs(UnmanagedType.U1)] bool".parse()
+    );
+    assert_eq!(Ok(Type::Ffi(c::Type::Long)), "CLong".parse());
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::Ffi(c::Type::Long)))),
+        "CLong?".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Ffi(c::Type::ULong)))),
+        "CULong[]".parse()
+    );
+    assert_eq!(
+        "System.Collections.Generic.List<bool>",
+        Type::List(Box::new(boolean.clone())).to_string()
+    );
+    assert_eq!(
+        Some(rust::Type::Ffi(c::Type::Long)),
+        Type::Ffi(c::Type::Long).to_rust()
+    );
+
+    let header = c::Struct::new(
+        "Header",
+        vec![
+            ("version".into(), c::Type::UInt16_t),
+            ("valid".into(), c::Type::Bool),
+            (
+                "data".into(),
+                c::Type::Array(Box::new(c::Type::UInt8_t), Some(32)),
+            ),
+        ],
+    );
+    assert_eq!(
+        "[StructLayout(LayoutKind.Sequential)]
+public unsafe struct Header
+{
+    public ushort version;
+    [MarshalAs(UnmanagedType.U1)] public bool valid;
+    public fixed byte data[32];
+}",
+        StructLayout(header).to_string()
+    );
+
+    let open = c::Function::new(
+        "db_open",
+        vec![
+            c::Type::Ptr(Box::new(c::Type::Char)),
+            c::Type::PtrMut(Box::new(c::Type::Int)),
+        ],
+        c::Type::Bool,
+    );
+    assert_eq!(
+        "[LibraryImport(\"db\")]
+[return: MarshalAs(UnmanagedType.U1)]
+internal static unsafe partial bool db_open([MarshalAs(UnmanagedType.LPUTF8Str)] string arg0, int* arg1);",
+        LibraryImport::new("db", open).to_string()
+    );
+
+    let version = c::Function::new("db_version", vec![], c::Type::Ptr(Box::new(c::Type::Char)));
+    assert_eq!(
+        "[LibraryImport(\"db\")]
+internal static partial nint db_version();",
+        LibraryImport::new("db", version).to_string()
+    );
+}
+
+#[test]
+fn csharp_generics() {
+    let int = || Box::new(Type::Int);
+    let string = || Box::new(Type::String);
+    let rank = |n| NonZeroUsize::new(n).unwrap();
+
+    assert_eq!(
+        Ok(Type::IReadOnlyList(int())),
+        "System.Collections.Generic.IReadOnlyList<int>".parse()
+    );
+    assert_eq!(Ok(Type::IList(int())), "IList<int>".parse());
+    assert_eq!(
+        Ok(Type::IEnumerable(string())),
+        "IEnumerable<string>".parse()
+    );
+    assert_eq!(Ok(Type::HashSet(string())), "HashSet<String>".parse());
+    assert_eq!(
+        Ok(Type::IDictionary(string(), Box::new(Type::List(int())))),
+        "IDictionary<string, List<int>>".parse()
+    );
+    assert_eq!(
+        Ok(Type::ReadOnlySpan(Box::new(Type::Byte))),
+        "ReadOnlySpan<byte>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Memory(Box::new(Type::Byte))),
+        "System.Memory<byte>".parse()
+    );
+    assert_eq!(Ok(Type::Task(Box::new(Type::Void))), "Task".parse());
+    assert_eq!(Ok(Type::Task(Box::new(Type::Guid))), "Task<Guid>".parse());
+    assert_eq!(Ok(Type::DateTime), "System.DateTime".parse());
+    assert_eq!(Ok(Type::TimeSpan), "TimeSpan".parse());
+    assert_eq!(
+        Ok(Type::ValueTuple(vec![Type::Int, Type::String])),
+        "(int Count, string Name)".parse()
+    );
+    assert_eq!(
+        Ok(Type::ValueTuple(vec![Type::Int, Type::String])),
+        "ValueTuple<int, string>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Array(int())))),
+        "int[][]".parse()
+    );
+    assert_eq!(Ok(Type::MultiArray(int(), rank(3))), "int[,,]".parse());
+    assert_eq!(Err(()), "int[2]".parse::<Type>());
+    assert_eq!(Err(()), "int[]]".parse::<Type>());
+    assert_eq!(Err(()), "Task<>".parse::<Type>());
+
+    let dictionary = Type::Dictionary(string(), Box::new(Type::Task(Box::new(Type::Guid))));
+    assert_eq!(
+        "System.Collections.Generic.Dictionary<string, System.Threading.Tasks.Task<System.Guid>>",
+        dictionary.to_string()
+    );
+    assert_eq!(
+        "Dictionary<string, Task<Guid>>",
+        dictionary.unqualified().to_string()
+    );
+    assert_eq!(
+        vec![
+            "System",
+            "System.Collections.Generic",
+            "System.Threading.Tasks"
+        ],
+        dictionary.usings()
+    );
+    assert_eq!("int[,]", Type::MultiArray(int(), rank(2)).to_string());
+    assert_eq!(
+        "(int, string)",
+        Type::ValueTuple(vec![Type::Int, Type::String]).to_string()
+    );
+
+    assert_eq!(
+        Ok(Type::HashSet(int())),
+        Type::try_from(rust::Type::Set(Box::new(rust::Type::I32)))
+    );
+    assert_eq!(
+        Some(rust::Type::Vec(Box::new(rust::Type::U8))),
+        Type::ReadOnlySpan(Box::new(Type::Byte)).to_rust()
+    );
+    assert_eq!(
+        Some(rust::Type::Tuple(vec![rust::Type::I32, rust::Type::String])),
+        Type::ValueTuple(vec![Type::Int, Type::String]).to_rust()
+    );
+}
+
+#[test]
+fn csharp_jagged_arrays() {
+    let rank = |n| NonZeroUsize::new(n).unwrap();
+
+    // An array of two-dimensional arrays:
+    let jagged = Type::Array(Box::new(Type::MultiArray(Box::new(Type::Int), rank(2))));
+    assert_eq!("int[][,]", jagged.to_string());
+    assert_eq!(Ok(jagged), "int[][,]".parse());
+
+    // A two-dimensional array of arrays:
+    let jagged = Type::MultiArray(Box::new(Type::Array(Box::new(Type::Int))), rank(2));
+    assert_eq!("int[,][]", jagged.to_string());
+    assert_eq!(Ok(jagged), "int[,][]".parse());
+
+    let nested = Type::Array(Box::new(Type::MultiArray(
+        Box::new(Type::Array(Box::new(Type::Guid))),
+        rank(3),
+    )));
+    assert_eq!("System.Guid[][,,][]", nested.to_string());
+    assert_eq!(Ok(nested), "Guid[][,,][]".parse());
+
+    let generic = Type::Array(Box::new(Type::List(Box::new(Type::Array(Box::new(
+        Type::Int,
+    ))))));
+    assert_eq!(Ok(generic), "List<int[]>[]".parse());
+}
@@ -0,0 +1,81 @@
+use codify::d::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn d_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 10] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Byte, rust::Type::I8),
+        (Type::Ulong, rust::Type::U64),
+        (Type::Size_t, rust::Type::Usize),
+        (Type::Dchar, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Nullable(Box::new(Type::DynamicArray(Box::new(Type::Int)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I32)))),
+        ),
+        (
+            Type::AssocArray(Box::new(Type::String), Box::new(Type::Double)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F64)),
+        ),
+        (
+            Type::ConstPtr(Box::new(Type::Ubyte)),
+            rust::Type::Ptr(Box::new(rust::Type::U8)),
+        ),
+        (Type::Ffi(c::Type::Long), rust::Type::Ffi(c::Type::Long)),
+    ];
+
+    for (d_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), d_type.to_rust());
+        assert_eq!(Ok(d_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn d_format() {
+    assert_eq!(
+        "int[][string]",
+        Type::AssocArray(
+            Box::new(Type::String),
+            Box::new(Type::DynamicArray(Box::new(Type::Int)))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "Tuple!(uint, Nullable!(float))",
+        Type::Tuple(vec![Type::Uint, Type::Nullable(Box::new(Type::Float))]).to_string()
+    );
+    assert_eq!("c_ulong", Type::Ffi(c::Type::ULong).to_string());
+    assert_eq!(
+        "const(char)*",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+    assert_eq!(
+        "c_longlong[8]",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::LongLong), Some(8))).to_string()
+    );
+}
+
+#[test]
+fn d_parse() {
+    assert_eq!(Ok(Type::Ffi(c::Type::LongLong)), "c_longlong".parse());
+    assert_eq!(
+        Ok(Type::StaticArray(Box::new(Type::Ubyte), 16)),
+        "ubyte[16]".parse()
+    );
+    assert_eq!(
+        Ok(Type::AssocArray(
+            Box::new(Type::String),
+            Box::new(Type::DynamicArray(Box::new(Type::Long)))
+        )),
+        "long[][string]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ptr(Box::new(Type::ConstPtr(Box::new(Type::Char))))),
+        "const(char)**".parse()
+    );
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::Int))),
+        "Nullable!int".parse()
+    );
+}
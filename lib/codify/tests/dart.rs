@@ -0,0 +1,153 @@
+use codify::dart::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn dart_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 9] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Dynamic, rust::Type::Any),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int, rust::Type::I64),
+        (Type::Double, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (Type::Uint8List, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (
+            Type::Map(
+                Box::new(Type::String),
+                Box::new(Type::List(Box::new(Type::Bool))),
+            ),
+            rust::Type::Map(
+                Box::new(rust::Type::String),
+                Box::new(rust::Type::Vec(Box::new(rust::Type::Bool))),
+            ),
+        ),
+        (
+            Type::Record(vec![Type::Int, Type::Nullable(Box::new(Type::String))]),
+            rust::Type::Tuple(vec![
+                rust::Type::I64,
+                rust::Type::Option(Box::new(rust::Type::String)),
+            ]),
+        ),
+    ];
+
+    for (dart_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), dart_type.to_rust());
+        assert_eq!(Ok(dart_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn dart_from_rust_ffi() {
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char)))),
+        Type::try_from(rust::Type::Ptr(Box::new(rust::Type::Ffi(c::Type::Char))))
+    );
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Int32_t)))),
+        Type::try_from(rust::Type::PtrMut(Box::new(rust::Type::I32)))
+    );
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Int)),
+        Type::try_from(rust::Type::Ffi(c::Type::Int))
+    );
+}
+
+#[test]
+fn dart_format() {
+    assert_eq!(
+        "Object?",
+        Type::Nullable(Box::new(Type::Object)).to_string()
+    );
+    assert_eq!("List<int>", Type::List(Box::new(Type::Int)).to_string());
+    assert_eq!(
+        "Map<String, int>",
+        Type::Map(Box::new(Type::String), Box::new(Type::Int)).to_string()
+    );
+    assert_eq!("Set<double>", Type::Set(Box::new(Type::Double)).to_string());
+    assert_eq!(
+        "(int, String)",
+        Type::Record(vec![Type::Int, Type::String]).to_string()
+    );
+    assert_eq!("(int,)", Type::Record(vec![Type::Int]).to_string());
+    assert_eq!("Int32", Type::Ffi(c::Type::Int32_t).to_string());
+    assert_eq!("Uint64", Type::Ffi(c::Type::UInt64_t).to_string());
+    assert_eq!(
+        "Pointer<Utf8>",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+    assert_eq!(
+        "Pointer<Void>",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))).to_string()
+    );
+    assert_eq!(
+        "@Array(16) Array<Uint8>",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::UInt8_t), Some(16))).to_string()
+    );
+    assert_eq!(
+        "Pointer<Uint8>",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::UInt8_t), None)).to_string()
+    );
+}
+
+#[test]
+fn dart_native_arrays() {
+    let matrix = Type::Ffi(c::Type::Array(
+        Box::new(c::Type::Array(Box::new(c::Type::Int32_t), Some(3))),
+        Some(2),
+    ));
+    assert_eq!("@Array(2, 3) Array<Array<Int32>>", matrix.to_string());
+    assert_eq!(Ok(matrix.clone()), matrix.to_string().parse());
+
+    let bytes = Type::Ffi(c::Type::Array(Box::new(c::Type::UInt8_t), Some(16)));
+    assert_eq!(Ok(bytes.clone()), bytes.to_string().parse());
+    assert_eq!(Err(()), "@Array(2, 3) Array<Int32>".parse::<Type>());
+}
+
+#[test]
+fn dart_native_function_pairs() {
+    assert_eq!(Type::Int, Type::Ffi(c::Type::Int32_t).to_dart());
+    assert_eq!(Type::Int, Type::Ffi(c::Type::Size_t).to_dart());
+    assert_eq!(Type::Double, Type::Ffi(c::Type::Float).to_dart());
+    assert_eq!(Type::Void, Type::Ffi(c::Type::Void).to_dart());
+    assert_eq!(Type::Bool, Type::Ffi(c::Type::Bool).to_dart());
+    let ptr = Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char)));
+    assert_eq!(ptr, ptr.to_dart());
+    assert_eq!(Type::String, Type::String.to_dart());
+}
+
+#[test]
+fn dart_parse() {
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::Object))),
+        "Object?".parse()
+    );
+    assert_eq!(
+        Ok(Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::List(Box::new(Type::Int)))
+        )),
+        "Map<String, List<int>>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Record(vec![Type::Int, Type::String])),
+        "(int, String)".parse()
+    );
+    assert_eq!(Ok(Type::Record(vec![Type::Int])), "(int,)".parse());
+    assert_eq!(Ok(Type::Int), "(int)".parse());
+    assert_eq!(Ok(Type::Float64List), "Float64List".parse());
+    assert_eq!(Ok(Type::Ffi(c::Type::Int32_t)), "Int32".parse());
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char)))),
+        "Pointer<Utf8>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void)))),
+        "Pointer<Void>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Array(Box::new(c::Type::UInt8_t), None))),
+        "Array<Uint8>".parse()
+    );
+    assert_eq!(Err(()), "Pointer<int>".parse::<Type>());
+    assert_eq!(Err(()), "Map<String>".parse::<Type>());
+}
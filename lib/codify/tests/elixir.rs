@@ -0,0 +1,87 @@
+use codify::elixir::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn elixir_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 9] = [
+        (Type::Any, rust::Type::Any),
+        (Type::Tuple(vec![]), rust::Type::Unit),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Integer, rust::Type::I64),
+        (Type::NonNegInteger, rust::Type::U64),
+        (Type::Float, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (Type::Binary, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (
+            Type::Union(vec![Type::List(Box::new(Type::Integer)), Type::Nil]),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I64)))),
+        ),
+    ];
+
+    for (elixir_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), elixir_type.to_rust());
+        assert_eq!(Ok(elixir_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn elixir_rustler_mappings() {
+    assert_eq!(
+        Ok(Type::AnyMap),
+        Type::try_from(rust::Type::Map(
+            Box::new(rust::Type::String),
+            Box::new(rust::Type::U32)
+        ))
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::String, Type::Float])),
+        Type::try_from(rust::Type::Tuple(vec![rust::Type::Str, rust::Type::F32]))
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![Type::NonNegInteger, Type::Nil])),
+        Type::try_from(rust::Type::Option(Box::new(rust::Type::Option(Box::new(
+            rust::Type::U8
+        )))))
+    );
+    assert_eq!(Err(()), Type::try_from(rust::Type::Char));
+}
+
+#[test]
+fn elixir_format() {
+    assert_eq!(
+        "%{String.t() => [non_neg_integer()]}",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::List(Box::new(Type::NonNegInteger)))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "{atom(), binary()} | nil",
+        Type::Union(vec![Type::Tuple(vec![Type::Atom, Type::Binary]), Type::Nil]).to_string()
+    );
+}
+
+#[test]
+fn elixir_parse() {
+    assert_eq!(Ok(Type::Tuple(vec![])), "{}".parse());
+    assert_eq!(
+        Ok(Type::List(Box::new(Type::Float))),
+        "list(float())".parse()
+    );
+    assert_eq!(
+        Ok(Type::Map(Box::new(Type::Atom), Box::new(Type::Any))),
+        "%{optional(atom()) => term()}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![
+            Type::Map(
+                Box::new(Type::String),
+                Box::new(Type::Tuple(vec![Type::Integer, Type::Boolean]))
+            ),
+            Type::Nil
+        ])),
+        "%{String.t() => {integer(), boolean()}} | nil".parse()
+    );
+    assert_eq!(Err(()), "integer".parse::<Type>());
+}
@@ -0,0 +1,116 @@
+use codify::fortran::{Interface, Type};
+use codify::{c, rust, ToRust};
+
+#[test]
+fn fortran_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 8] = [
+        (Type::Ffi(c::Type::Void), rust::Type::Unit),
+        (Type::Ffi(c::Type::Bool), rust::Type::Bool),
+        (Type::Ffi(c::Type::Int32_t), rust::Type::I32),
+        (Type::Ffi(c::Type::Int64_t), rust::Type::I64),
+        (Type::Ffi(c::Type::Size_t), rust::Type::Usize),
+        (Type::Ffi(c::Type::Double), rust::Type::Ffi(c::Type::Double)),
+        (
+            Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+            rust::Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+        ),
+        (
+            Type::Ffi(c::Type::Array(Box::new(c::Type::Float), Some(3))),
+            rust::Type::Ffi(c::Type::Array(Box::new(c::Type::Float), Some(3))),
+        ),
+    ];
+
+    for (fortran_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), fortran_type.to_rust());
+        assert_eq!(Ok(fortran_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn fortran_format() {
+    assert_eq!("integer(c_int)", Type::Ffi(c::Type::Int).to_string());
+    assert_eq!(
+        "integer(c_int64_t)",
+        Type::Ffi(c::Type::UInt64_t).to_string()
+    );
+    assert_eq!("real(c_float)", Type::Ffi(c::Type::Float).to_string());
+    assert_eq!("logical(c_bool)", Type::Ffi(c::Type::Bool).to_string());
+    assert_eq!(
+        "character(kind=c_char)",
+        Type::Ffi(c::Type::Char).to_string()
+    );
+    assert_eq!(
+        "type(c_ptr)",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Int))).to_string()
+    );
+    assert_eq!("type(c_funptr)", Type::CFunPtr.to_string());
+    assert_eq!(
+        "real(c_double), dimension(3, 2)",
+        Type::Ffi(c::Type::Array(
+            Box::new(c::Type::Array(Box::new(c::Type::Double), Some(3))),
+            Some(2)
+        ))
+        .to_string()
+    );
+    assert_eq!(
+        "integer, dimension(*)",
+        Type::Array(Box::new(Type::Integer), None).to_string()
+    );
+}
+
+#[test]
+fn fortran_parse() {
+    assert_eq!(Ok(Type::Ffi(c::Type::Long)), "integer(kind=c_long)".parse());
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Char)),
+        "character(kind=c_char)".parse()
+    );
+    assert_eq!(Ok(Type::DoublePrecision), "double precision".parse());
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Array(
+            Box::new(c::Type::Array(Box::new(c::Type::Int), Some(4))),
+            None
+        ))),
+        "integer(c_int), dimension(4, *), intent(in)".parse()
+    );
+    assert_eq!(Err(()), "integer(c_unsigned)".parse::<Type>());
+}
+
+#[test]
+fn fortran_interface() {
+    let function = c::Function::new(
+        "scale",
+        vec![
+            c::Type::PtrMut(Box::new(c::Type::Double)),
+            c::Type::Size_t,
+            c::Type::Ptr(Box::new(c::Type::Char)),
+            c::Type::PtrMut(Box::new(c::Type::Void)),
+        ],
+        c::Type::Int,
+    );
+    assert_eq!(
+        concat!(
+            "interface\n",
+            "  function scale(arg0, arg1, arg2, arg3) bind(c, name=\"scale\")\n",
+            "    use, intrinsic :: iso_c_binding\n",
+            "    real(c_double), intent(inout) :: arg0\n",
+            "    integer(c_size_t), value, intent(in) :: arg1\n",
+            "    character(kind=c_char), dimension(*), intent(in) :: arg2\n",
+            "    type(c_ptr), value, intent(in) :: arg3\n",
+            "    integer(c_int) :: scale\n",
+            "  end function scale\n",
+            "end interface",
+        ),
+        Interface(function).to_string()
+    );
+    assert_eq!(
+        concat!(
+            "interface\n",
+            "  subroutine reset() bind(c, name=\"reset\")\n",
+            "    use, intrinsic :: iso_c_binding\n",
+            "  end subroutine reset\n",
+            "end interface",
+        ),
+        Interface(c::Function::new("reset", vec![], c::Type::Void)).to_string()
+    );
+}
@@ -0,0 +1,70 @@
+use codify::go::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn go_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 10] = [
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int, rust::Type::Isize),
+        (Type::Int32, rust::Type::I32),
+        (Type::Uint8, rust::Type::U8),
+        (Type::Uint64, rust::Type::U64),
+        (Type::Float64, rust::Type::F64),
+        (Type::Rune, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Slice(Box::new(Type::Int16)),
+            rust::Type::Vec(Box::new(rust::Type::I16)),
+        ),
+        (
+            Type::Map(Box::new(Type::String), Box::new(Type::Float32)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F32)),
+        ),
+    ];
+
+    for (go_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), go_type.to_rust());
+        assert_eq!(Ok(go_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn go_from_rust_pointers_and_sets() {
+    let pointer = Type::Pointer(Box::new(Type::Uint32));
+    assert_eq!(
+        Ok(pointer.clone()),
+        Type::try_from(rust::Type::Option(Box::new(rust::Type::U32)))
+    );
+    assert_eq!(
+        Ok(pointer.clone()),
+        Type::try_from(rust::Type::Box(Box::new(rust::Type::U32)))
+    );
+    // A pointer converts back to an `Option`, the more common of the two:
+    assert_eq!(
+        Some(rust::Type::Option(Box::new(rust::Type::U32))),
+        pointer.to_rust()
+    );
+    assert_eq!(
+        Ok(Type::Map(Box::new(Type::Int64), Box::new(Type::Bool))),
+        Type::try_from(rust::Type::Set(Box::new(rust::Type::I64)))
+    );
+    assert_eq!(Err(()), Type::try_from(rust::Type::U128));
+}
+
+#[test]
+fn go_parse() {
+    for input in [
+        "int8",
+        "uint",
+        "[]string",
+        "*float32",
+        "map[string][]int",
+        "map[string]map[int]bool",
+        "[]*uint16",
+    ] {
+        assert_eq!(input, input.parse::<Type>().unwrap().to_string());
+    }
+    assert_eq!(Ok(Type::Uint8), "byte".parse());
+    assert_eq!(Err(()), "map[string".parse::<Type>());
+    assert_eq!(Err(()), "chan int".parse::<Type>());
+}
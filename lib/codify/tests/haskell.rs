@@ -0,0 +1,136 @@
+use codify::haskell::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn haskell_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Unit, rust::Type::Unit),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Char, rust::Type::Char),
+        (Type::Int, rust::Type::Isize),
+        (Type::Int32, rust::Type::I32),
+        (Type::Word8, rust::Type::U8),
+        (Type::Double, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (Type::ByteString, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (
+            Type::Maybe(Box::new(Type::List(Box::new(Type::Int64)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I64)))),
+        ),
+        (
+            Type::Map(
+                Box::new(Type::String),
+                Box::new(Type::Tuple(vec![Type::Int8, Type::Float])),
+            ),
+            rust::Type::Map(
+                Box::new(rust::Type::String),
+                Box::new(rust::Type::Tuple(vec![rust::Type::I8, rust::Type::F32])),
+            ),
+        ),
+        (Type::Ffi(c::Type::Size_t), rust::Type::Ffi(c::Type::Size_t)),
+    ];
+
+    for (hs_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), hs_type.to_rust());
+        assert_eq!(Ok(hs_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn haskell_to_c_and_back() {
+    let type_map: [(Type, c::Type); 5] = [
+        (Type::Unit, c::Type::Void),
+        (Type::Word16, c::Type::UInt16_t),
+        (Type::Ffi(c::Type::Int), c::Type::Int),
+        (
+            Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+            c::Type::Ptr(Box::new(c::Type::Char)),
+        ),
+        (
+            Type::Ptr(Box::new(Type::Ffi(c::Type::Double))),
+            c::Type::PtrMut(Box::new(c::Type::Double)),
+        ),
+    ];
+
+    for (hs_type, c_type) in type_map {
+        assert_eq!(Ok(c_type.clone()), c::Type::try_from(hs_type.clone()));
+        assert_eq!(Ok(hs_type), Type::try_from(c_type));
+    }
+}
+
+#[test]
+fn haskell_format() {
+    let maybe_int = || Box::new(Type::Maybe(Box::new(Type::Int)));
+    assert_eq!("[Maybe Int]", Type::List(maybe_int()).to_string());
+    assert_eq!(
+        "Either Text (Maybe Int)",
+        Type::Either(Box::new(Type::Text), maybe_int()).to_string()
+    );
+    assert_eq!(
+        "(Int, Char)",
+        Type::Tuple(vec![Type::Int, Type::Char]).to_string()
+    );
+    assert_eq!(
+        "Ptr (Ptr CChar)",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::PtrMut(Box::new(
+            c::Type::Char
+        )))))
+        .to_string()
+    );
+    assert_eq!(
+        "CString",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+    assert_eq!(
+        "FunPtr (CInt -> Ptr () -> IO ())",
+        Type::FunPtr(
+            vec![Type::Ffi(c::Type::Int), Type::Ptr(Box::new(Type::Unit))],
+            Box::new(Type::IO(Box::new(Type::Unit)))
+        )
+        .to_string()
+    );
+}
+
+#[test]
+fn haskell_parse() {
+    assert_eq!(Ok(Type::Unit), "()".parse());
+    assert_eq!(Ok(Type::Text), "Data.Text.Text".parse());
+    assert_eq!(
+        Ok(Type::Map(
+            Box::new(Type::Text),
+            Box::new(Type::List(Box::new(Type::Word)))
+        )),
+        "Map.Map Text [Word]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Either(
+            Box::new(Type::String),
+            Box::new(Type::Maybe(Box::new(Type::Int)))
+        )),
+        "Either String (Maybe Int)".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Int,
+            Type::Set(Box::new(Type::Char))
+        ])),
+        "(Int, Set Char)".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ptr(Box::new(Type::Ffi(c::Type::Int)))),
+        "Ptr CInt".parse()
+    );
+    assert_eq!(
+        Ok(Type::FunPtr(
+            vec![Type::Ffi(c::Type::Size_t)],
+            Box::new(Type::IO(Box::new(Type::Ffi(c::Type::Int))))
+        )),
+        "FunPtr (CSize -> IO CInt)".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char)))),
+        "Foreign.C.String.CString".parse()
+    );
+    assert_eq!(Err(()), "Maybe".parse::<Type>());
+    assert_eq!(Err(()), "Int Int".parse::<Type>());
+}
@@ -0,0 +1,120 @@
+use codify::javascript::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn javascript_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 8] = [
+        (Type::Any, rust::Type::Any),
+        (Type::Undefined, rust::Type::Unit),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Number, rust::Type::F64),
+        (Type::BigInt, rust::Type::I128),
+        (Type::Uint8Array, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (
+            Type::Nullable(Box::new(Type::String)),
+            rust::Type::Option(Box::new(rust::Type::String)),
+        ),
+        (
+            Type::ObjectOf(Box::new(Type::String), Box::new(Type::Number)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F64)),
+        ),
+    ];
+
+    for (js_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), js_type.to_rust());
+        assert_eq!(Ok(js_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn javascript_format() {
+    assert_eq!("{*}", Type::Any.to_string());
+    assert_eq!(
+        "{Array<number>}",
+        Type::Array(Box::new(Type::Number)).to_string()
+    );
+    assert_eq!(
+        "{?string}",
+        Type::Nullable(Box::new(Type::String)).to_string()
+    );
+    assert_eq!(
+        "{Object<string, number>}",
+        Type::ObjectOf(Box::new(Type::String), Box::new(Type::Number)).to_string()
+    );
+    assert_eq!(
+        "{Map<string, Array<?bigint>>}",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Array(Box::new(Type::Nullable(Box::new(
+                Type::BigInt
+            )))))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "{(number|string)}",
+        Type::Union(vec![Type::Number, Type::String]).to_string()
+    );
+    assert_eq!("{DataView}", Type::DataView.to_string());
+}
+
+#[test]
+fn javascript_check() {
+    assert_eq!("typeof x === \"bigint\"", Type::BigInt.to_check("x"));
+    assert_eq!("typeof x === \"symbol\"", Type::Symbol.to_check("x"));
+    assert_eq!(
+        "Array.isArray(x)",
+        Type::Array(Box::new(Type::Number)).to_check("x")
+    );
+    assert_eq!("x instanceof ArrayBuffer", Type::ArrayBuffer.to_check("x"));
+    assert_eq!(
+        "x instanceof Float64Array",
+        Type::Float64Array.to_check("x")
+    );
+    assert_eq!(
+        "x instanceof Map",
+        Type::Map(Box::new(Type::String), Box::new(Type::Number)).to_check("x")
+    );
+    assert_eq!(
+        "(x === null || typeof x === \"string\")",
+        Type::Nullable(Box::new(Type::String)).to_check("x")
+    );
+    assert_eq!(
+        "(typeof x === \"number\" || typeof x === \"string\")",
+        Type::Union(vec![Type::Number, Type::String]).to_check("x")
+    );
+}
+
+#[test]
+fn javascript_parse() {
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Number))),
+        "{Array<number>}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Number))),
+        "Array.<number>".parse()
+    );
+    assert_eq!(Ok(Type::Array(Box::new(Type::Number))), "number[]".parse());
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::String))),
+        "{?string}".parse()
+    );
+    assert_eq!(Ok(Type::String), "{!String}".parse());
+    assert_eq!(
+        Ok(Type::ObjectOf(
+            Box::new(Type::String),
+            Box::new(Type::Number)
+        )),
+        "{Object.<string, number>}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![Type::Number, Type::Null])),
+        "{(number|null)}".parse()
+    );
+    assert_eq!(Ok(Type::BigInt), "BigInt".parse());
+    assert_eq!(Ok(Type::Symbol), "symbol".parse());
+    assert_eq!(Ok(Type::Uint8ClampedArray), "Uint8ClampedArray".parse());
+    assert_eq!(Err(()), "{Object<string>}".parse::<Type>());
+    assert_eq!(Err(()), "{bogus}".parse::<Type>());
+}
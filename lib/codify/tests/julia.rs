@@ -0,0 +1,129 @@
+use codify::julia::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn julia_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Nothing, rust::Type::Unit),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int128, rust::Type::I128),
+        (Type::UInt8, rust::Type::U8),
+        (Type::Int, rust::Type::Isize),
+        (Type::Float32, rust::Type::F32),
+        (Type::Char, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Union(vec![Type::Vector(Box::new(Type::Float64)), Type::Nothing]),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::F64)))),
+        ),
+        (
+            Type::Dict(
+                Box::new(Type::String),
+                Box::new(Type::Set(Box::new(Type::Int32))),
+            ),
+            rust::Type::Map(
+                Box::new(rust::Type::String),
+                Box::new(rust::Type::Set(Box::new(rust::Type::I32))),
+            ),
+        ),
+        (
+            Type::Ptr(Box::new(Type::UInt16)),
+            rust::Type::PtrMut(Box::new(rust::Type::U16)),
+        ),
+        (Type::Ffi(c::Type::Int), rust::Type::Ffi(c::Type::Int)),
+    ];
+
+    for (jl_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), jl_type.to_rust());
+        assert_eq!(Ok(jl_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn julia_format() {
+    assert_eq!(
+        "Tuple{Int64, Vector{String}}",
+        Type::Tuple(vec![Type::Int64, Type::Vector(Box::new(Type::String))]).to_string()
+    );
+    assert_eq!("Tuple{}", Type::Tuple(vec![]).to_string());
+    assert_eq!(
+        "Matrix{Float64}",
+        Type::Matrix(Box::new(Type::Float64)).to_string()
+    );
+    assert_eq!(
+        "Ref{Cint}",
+        Type::Ref(Box::new(Type::Ffi(c::Type::Int))).to_string()
+    );
+    assert_eq!("Csize_t", Type::Ffi(c::Type::Size_t).to_string());
+    assert_eq!(
+        "Cstring",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+    assert_eq!(
+        "Ptr{Cvoid}",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))).to_string()
+    );
+}
+
+#[test]
+fn julia_parse() {
+    assert_eq!(Ok(Type::UInt), "UInt".parse());
+    assert_eq!(Ok(Type::Float16), "Core.Float16".parse());
+    assert_eq!(
+        Ok(Type::Dict(Box::new(Type::String), Box::new(Type::Any))),
+        "Dict{String,Any}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Vector(Box::new(Type::Int32))),
+        "Array{Int32, 1}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![Type::Int64, Type::Nothing])),
+        "Union{Int64, Nothing}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Ffi(c::Type::Int),
+            Type::Ptr(Box::new(Type::Ffi(c::Type::Double)))
+        ])),
+        "Tuple{Cint, Ptr{Cdouble}}".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char)))),
+        "Cstring".parse()
+    );
+    assert_eq!(Err(()), "Array{Int32, 3}".parse::<Type>());
+    assert_eq!(Err(()), "Dict{String}".parse::<Type>());
+}
+
+#[test]
+fn julia_ccall() {
+    use codify::julia::Ccall;
+
+    let add = c::Function::from_rust(
+        "add",
+        vec![rust::Type::I32, rust::Type::I32],
+        rust::Type::I32,
+    )
+    .unwrap();
+    assert_eq!(
+        "add(arg0, arg1) = ccall((:add, \"libmath\"), Int32, (Int32, Int32), arg0, arg1)",
+        Ccall::new("libmath", add).to_string()
+    );
+
+    let len = c::Function::new(
+        "strlen",
+        vec![c::Type::Ptr(Box::new(c::Type::Char))],
+        c::Type::Size_t,
+    );
+    assert_eq!(
+        "strlen(arg0) = ccall((:strlen, \"libc\"), Csize_t, (Cstring,), arg0)",
+        Ccall::new("libc", len).to_string()
+    );
+
+    let tick = c::Function::new("tick", vec![], c::Type::Void);
+    assert_eq!(
+        "tick() = ccall((:tick, \"libgame\"), Cvoid, ())",
+        Ccall::new("libgame", tick).to_string()
+    );
+}
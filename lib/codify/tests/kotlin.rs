@@ -0,0 +1,83 @@
+use codify::kotlin::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn kotlin_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Unit, rust::Type::Unit),
+        (Type::Any, rust::Type::Any),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Byte, rust::Type::I8),
+        (Type::UByte, rust::Type::U8),
+        (Type::ULong, rust::Type::U64),
+        (Type::Double, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (Type::ByteArray, rust::Type::Vec(Box::new(rust::Type::I8))),
+        (
+            Type::Nullable(Box::new(Type::List(Box::new(Type::String)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::String)))),
+        ),
+        (
+            Type::Map(Box::new(Type::String), Box::new(Type::Int)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::I32)),
+        ),
+        (
+            Type::Pair(Box::new(Type::Int), Box::new(Type::Char)),
+            rust::Type::Tuple(vec![rust::Type::I32, rust::Type::Char]),
+        ),
+    ];
+
+    for (kt_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), kt_type.to_rust());
+        assert_eq!(Ok(kt_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn kotlin_format() {
+    assert_eq!("UByte", Type::UByte.to_string());
+    assert_eq!("Int?", Type::Nullable(Box::new(Type::Int)).to_string());
+    assert_eq!(
+        "MutableList<Array<String>>",
+        Type::MutableList(Box::new(Type::Array(Box::new(Type::String)))).to_string()
+    );
+    assert_eq!(
+        "Map<String, Set<Long>>",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Set(Box::new(Type::Long)))
+        )
+        .to_string()
+    );
+}
+
+#[test]
+fn kotlin_parse() {
+    assert_eq!(Ok(Type::Nothing), "Nothing".parse());
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::Int))),
+        "kotlin.Int?".parse()
+    );
+    assert_eq!(
+        Ok(Type::MutableMap(
+            Box::new(Type::String),
+            Box::new(Type::IntArray)
+        )),
+        "kotlin.collections.MutableMap<String, IntArray>".parse()
+    );
+    assert_eq!(
+        Ok(Type::List(Box::new(Type::Nullable(Box::new(Type::Char))))),
+        "List<Char?>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Triple(
+            Box::new(Type::Int),
+            Box::new(Type::Int),
+            Box::new(Type::Unit)
+        )),
+        "Triple<Int, Int, Unit>".parse()
+    );
+    assert_eq!(Err(()), "List<>".parse::<Type>());
+    assert_eq!(Err(()), "Map<String>".parse::<Type>());
+    assert_eq!(Err(()), "int".parse::<Type>());
+}
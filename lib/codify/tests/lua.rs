@@ -0,0 +1,128 @@
+use codify::lua::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn lua_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 9] = [
+        (Type::Nil, rust::Type::Unit),
+        (Type::Any, rust::Type::Any),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Integer, rust::Type::I64),
+        (Type::Number, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (
+            Type::Optional(Box::new(Type::Array(Box::new(Type::Integer)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I64)))),
+        ),
+        (
+            Type::Map(Box::new(Type::String), Box::new(Type::Number)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F64)),
+        ),
+        (
+            Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+            rust::Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+        ),
+    ];
+
+    for (lua_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), lua_type.to_rust());
+        assert_eq!(Ok(lua_type), Type::try_from(rust_type));
+    }
+
+    assert_eq!(
+        Ok(Type::Map(Box::new(Type::String), Box::new(Type::Boolean))),
+        Type::try_from(rust::Type::Set(Box::new(rust::Type::String)))
+    );
+}
+
+#[test]
+fn lua_format() {
+    assert_eq!(
+        "(string|integer)[]",
+        Type::Array(Box::new(Type::Union(vec![Type::String, Type::Integer]))).to_string()
+    );
+    assert_eq!(
+        "fun(a: integer, b: string?): boolean",
+        Type::Function(
+            vec![
+                ("a".into(), Type::Integer),
+                ("b".into(), Type::Optional(Box::new(Type::String)))
+            ],
+            vec![Type::Boolean]
+        )
+        .to_string()
+    );
+    assert_eq!("fun()", Type::Function(vec![], vec![]).to_string());
+    assert_eq!(
+        "uint8_t[16]",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::UInt8_t), Some(16))).to_string()
+    );
+}
+
+#[test]
+fn lua_parse() {
+    assert_eq!(
+        Ok(Type::Optional(Box::new(Type::Integer))),
+        "integer?".parse()
+    );
+    assert_eq!(
+        Ok(Type::Optional(Box::new(Type::Number))),
+        "nil|number".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Array(Box::new(Type::String))))),
+        "string[][]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Union(vec![Type::Integer, Type::Boolean, Type::Table]))
+        )),
+        "table<string, integer|boolean|table>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Function(
+            vec![("cb".into(), Type::Function(vec![], vec![Type::Integer]))],
+            vec![Type::Boolean, Type::Optional(Box::new(Type::String))]
+        )),
+        "fun(cb: fun(): integer): boolean, string?".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Function(
+            vec![("x".into(), Type::Any)],
+            vec![]
+        )))),
+        "(fun(x: any))[]".parse()
+    );
+    assert_eq!(Err(()), "table<string>".parse::<Type>());
+    assert_eq!(Err(()), "fun(integer)".parse::<Type>());
+}
+
+#[test]
+fn lua_cdef() {
+    use codify::lua::{Cdef, Declaration};
+
+    let point = c::Struct::from_rust(
+        "point",
+        vec![("x".into(), rust::Type::I32), ("y".into(), rust::Type::F64)],
+    )
+    .unwrap();
+    let add = c::Function::from_rust(
+        "add",
+        vec![rust::Type::I32, rust::Type::I32],
+        rust::Type::I32,
+    )
+    .unwrap();
+    let tick = c::Function::new("tick", vec![], c::Type::Void);
+    let cdef = Cdef(vec![
+        Declaration::Enum(c::Enum::new("mode", vec![("fast".into(), 1)])),
+        Declaration::Struct(point),
+        Declaration::Callback(c::Function::new("on_tick", vec![], c::Type::Void)),
+        Declaration::Function(add),
+        Declaration::Function(tick),
+    ]);
+    assert_eq!(
+        "ffi.cdef[[\nenum mode { fast = 1, };\nstruct point { int32_t x; double y; };\ntypedef void (*on_tick)(void);\nint32_t add(int32_t, int32_t);\nvoid tick(void);\n]]",
+        cdef.to_string()
+    );
+}
@@ -0,0 +1,77 @@
+use codify::nim::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn nim_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 10] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int, rust::Type::Isize),
+        (Type::UInt8, rust::Type::U8),
+        (Type::Float32, rust::Type::F32),
+        (Type::Rune, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Option(Box::new(Type::Seq(Box::new(Type::Int64)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I64)))),
+        ),
+        (
+            Type::Table(Box::new(Type::String), Box::new(Type::Float64)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F64)),
+        ),
+        (
+            Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+            rust::Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+        ),
+    ];
+
+    for (nim_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), nim_type.to_rust());
+        assert_eq!(Ok(nim_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn nim_format() {
+    assert_eq!(
+        "array[4, seq[uint16]]",
+        Type::Array(Box::new(Type::Seq(Box::new(Type::UInt16))), 4).to_string()
+    );
+    assert_eq!("(int,)", Type::Tuple(vec![Type::Int]).to_string());
+    assert_eq!("cint", Type::Ffi(c::Type::Int).to_string());
+    assert_eq!("csize_t", Type::Ffi(c::Type::Size_t).to_string());
+    assert_eq!(
+        "pointer",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))).to_string()
+    );
+    assert_eq!(
+        "ptr UncheckedArray[cdouble]",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::Double), None)).to_string()
+    );
+}
+
+#[test]
+fn nim_parse() {
+    assert_eq!(Ok(Type::Ffi(c::Type::ULong)), "culong".parse());
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char)))),
+        "cstring".parse()
+    );
+    assert_eq!(
+        Ok(Type::Table(
+            Box::new(Type::String),
+            Box::new(Type::HashSet(Box::new(Type::Int)))
+        )),
+        "Table[string, HashSet[int]]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Bool,
+            Type::Ptr(Box::new(Type::Char))
+        ])),
+        "(bool, ptr char)".parse()
+    );
+    assert_eq!(Ok(Type::Tuple(vec![Type::Int])), "(int,)".parse());
+    assert_eq!(Ok(Type::Int), "(int)".parse());
+    assert_eq!(Err(()), "Integer".parse::<Type>());
+}
@@ -0,0 +1,99 @@
+use codify::objc::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn objc_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 11] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Id, rust::Type::Any),
+        (Type::Bool, rust::Type::Bool),
+        (Type::NSInteger, rust::Type::Isize),
+        (Type::NSUInteger, rust::Type::Usize),
+        (Type::NSString, rust::Type::String),
+        (Type::NSData, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (Type::Ffi(c::Type::Int32_t), rust::Type::I32),
+        (Type::Ffi(c::Type::Float), rust::Type::F32),
+        (
+            Type::Nullable(Box::new(Type::NSArray(Box::new(Type::NSString)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::String)))),
+        ),
+        (
+            Type::NSDictionary(Box::new(Type::NSString), Box::new(Type::NSData)),
+            rust::Type::Map(
+                Box::new(rust::Type::String),
+                Box::new(rust::Type::Vec(Box::new(rust::Type::U8))),
+            ),
+        ),
+    ];
+
+    for (objc_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), objc_type.to_rust());
+        assert_eq!(Ok(objc_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn objc_boxing() {
+    assert_eq!(
+        Ok(Type::NSArray(Box::new(Type::NSNumber))),
+        Type::try_from(rust::Type::Vec(Box::new(rust::Type::I32)))
+    );
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::NSNumber))),
+        Type::try_from(rust::Type::Option(Box::new(rust::Type::Bool)))
+    );
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Vec(Box::new(rust::Type::Option(Box::new(
+            rust::Type::String
+        )))))
+    );
+}
+
+#[test]
+fn objc_format() {
+    assert_eq!("BOOL", Type::Bool.to_string());
+    assert_eq!("NSString *", Type::NSString.to_string());
+    assert_eq!(
+        "nullable NSArray<NSNumber *> *",
+        Type::Nullable(Box::new(Type::NSArray(Box::new(Type::NSNumber)))).to_string()
+    );
+    assert_eq!(
+        "NSDictionary<NSString *, id> *",
+        Type::NSDictionary(Box::new(Type::NSString), Box::new(Type::Id)).to_string()
+    );
+    assert_eq!(
+        "const char*",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+}
+
+#[test]
+fn objc_parse() {
+    assert_eq!(Ok(Type::CGFloat), "CGFloat".parse());
+    assert_eq!(Ok(Type::NSString), "NSString*".parse());
+    assert_eq!(Ok(Type::NSArray(Box::new(Type::Id))), "NSArray *".parse());
+    assert_eq!(
+        Ok(Type::NSArray(Box::new(Type::NSString))),
+        "NSArray<NSString *> *".parse()
+    );
+    assert_eq!(
+        Ok(Type::NSDictionary(
+            Box::new(Type::NSString),
+            Box::new(Type::NSArray(Box::new(Type::NSNumber)))
+        )),
+        "NSDictionary<NSString *, NSArray<NSNumber *> *> *".parse()
+    );
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::NSString))),
+        "nullable NSString *".parse()
+    );
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::NSData))),
+        "NSData * _Nullable".parse()
+    );
+    assert_eq!(Ok(Type::Nonnull(Box::new(Type::Id))), "id _Nonnull".parse());
+    assert_eq!(Ok(Type::Ffi(c::Type::UInt)), "unsigned int".parse());
+    assert_eq!(Err(()), "NSDictionary<NSString *> *".parse::<Type>());
+    assert_eq!(Err(()), "NSWhatever *".parse::<Type>());
+}
@@ -0,0 +1,114 @@
+use codify::ocaml::{Ctypes, Type};
+use codify::{c, rust, ToRust};
+
+#[test]
+fn ocaml_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Unit, rust::Type::Unit),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int32, rust::Type::I32),
+        (Type::Int64, rust::Type::I64),
+        (Type::NativeInt, rust::Type::Isize),
+        (Type::Float, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (Type::Bytes, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (Type::Ffi(c::Type::Float), rust::Type::F32),
+        (Type::Ffi(c::Type::UInt16_t), rust::Type::U16),
+        (
+            Type::Option(Box::new(Type::List(Box::new(Type::String)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::String)))),
+        ),
+        (
+            Type::Tuple(vec![Type::Int32, Type::Bool]),
+            rust::Type::Tuple(vec![rust::Type::I32, rust::Type::Bool]),
+        ),
+    ];
+
+    for (ml_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), ml_type.to_rust());
+        assert_eq!(Ok(ml_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn ocaml_format() {
+    let pair = || Type::Tuple(vec![Type::Int, Type::String]);
+    assert_eq!("int * string", pair().to_string());
+    assert_eq!(
+        "(int * string) list",
+        Type::List(Box::new(pair())).to_string()
+    );
+    assert_eq!(
+        "int array option",
+        Type::Option(Box::new(Type::Array(Box::new(Type::Int)))).to_string()
+    );
+    assert_eq!(
+        "(int list, string) result",
+        Type::Result(
+            Box::new(Type::List(Box::new(Type::Int))),
+            Box::new(Type::String)
+        )
+        .to_string()
+    );
+    assert_eq!("Unsigned.uint64", Type::Ffi(c::Type::UInt64_t).to_string());
+    assert_eq!(
+        "Unsigned.size_t ptr",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Size_t))).to_string()
+    );
+}
+
+#[test]
+fn ocaml_parse() {
+    assert_eq!(Ok(Type::NativeInt), "nativeint".parse());
+    assert_eq!(
+        Ok(Type::List(Box::new(Type::Option(Box::new(Type::Char))))),
+        "char option list".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Tuple(vec![
+            Type::Int,
+            Type::Float
+        ])))),
+        "(int * float) array".parse()
+    );
+    assert_eq!(
+        Ok(Type::Result(Box::new(Type::Unit), Box::new(Type::String))),
+        "(unit, string) result".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Bytes,
+            Type::List(Box::new(Type::Int64))
+        ])),
+        "bytes * int64 list".parse()
+    );
+    assert_eq!(Ok(Type::Ffi(c::Type::ULong)), "Unsigned.ulong".parse());
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Char)))),
+        "char ptr".parse()
+    );
+    assert_eq!(Err(()), "int foo".parse::<Type>());
+    assert_eq!(Err(()), "int result".parse::<Type>());
+}
+
+#[test]
+fn ocaml_ctypes() {
+    let char_ptr = c::Type::PtrMut(Box::new(c::Type::Char));
+    let type_map: [(&str, c::Type); 6] = [
+        ("void", c::Type::Void),
+        ("int32_t", c::Type::Int32_t),
+        ("uint64_t", c::Type::UInt64_t),
+        ("string", c::Type::Ptr(Box::new(c::Type::Char))),
+        ("ptr char", char_ptr.clone()),
+        (
+            "array 4 (ptr (ptr char))",
+            c::Type::Array(Box::new(c::Type::PtrMut(Box::new(char_ptr))), Some(4)),
+        ),
+    ];
+
+    for (ctypes, c_type) in type_map {
+        assert_eq!(ctypes, Ctypes(c_type.clone()).to_string());
+        assert_eq!(Ok(Ctypes(c_type)), ctypes.parse());
+    }
+    assert_eq!(Err(()), "ptr".parse::<Ctypes>());
+}
@@ -0,0 +1,83 @@
+use codify::odin::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn odin_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 11] = [
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int, rust::Type::Isize),
+        (Type::U128, rust::Type::U128),
+        (Type::F32, rust::Type::F32),
+        (Type::Rune, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Maybe(Box::new(Type::Dynamic(Box::new(Type::I64)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I64)))),
+        ),
+        (
+            Type::Map(Box::new(Type::String), Box::new(Type::F64)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F64)),
+        ),
+        (
+            Type::Cstring,
+            rust::Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))),
+        ),
+        (
+            Type::Rawptr,
+            rust::Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))),
+        ),
+        (Type::Ffi(c::Type::Int), rust::Type::Ffi(c::Type::Int)),
+    ];
+
+    for (odin_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), odin_type.to_rust());
+        assert_eq!(Ok(odin_type), Type::try_from(rust_type));
+    }
+    assert_eq!(
+        Ok(Type::String),
+        Type::try_from(rust::Type::Ref(Box::new(rust::Type::Str)))
+    );
+}
+
+#[test]
+fn odin_format() {
+    assert_eq!(
+        "map[string][dynamic]^u8",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Dynamic(Box::new(Type::Ptr(Box::new(Type::U8)))))
+        )
+        .to_string()
+    );
+    assert_eq!("c.size_t", Type::Ffi(c::Type::Size_t).to_string());
+    assert_eq!(
+        "^c.int",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Int))).to_string()
+    );
+    assert_eq!(
+        "[4]c.float",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::Float), Some(4))).to_string()
+    );
+}
+
+#[test]
+fn odin_parse() {
+    assert_eq!(Ok(Type::Ffi(c::Type::UShort)), "c.ushort".parse());
+    assert_eq!(Ok(Type::Rawptr), "rawptr".parse());
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Slice(Box::new(Type::F16))), 3)),
+        "[3][]f16".parse()
+    );
+    assert_eq!(
+        Ok(Type::MultiPtr(Box::new(Type::Cstring))),
+        "[^]cstring".parse()
+    );
+    assert_eq!(
+        Ok(Type::Maybe(Box::new(Type::Map(
+            Box::new(Type::Rune),
+            Box::new(Type::Uint)
+        )))),
+        "Maybe(map[rune]uint)".parse()
+    );
+    assert_eq!(Err(()), "c.nonsense".parse::<Type>());
+}
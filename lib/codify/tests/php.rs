@@ -0,0 +1,123 @@
+use codify::php::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn php_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 10] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Mixed, rust::Type::Any),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int, rust::Type::I64),
+        (Type::Float, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (
+            Type::Nullable(Box::new(Type::Int)),
+            rust::Type::Option(Box::new(rust::Type::I64)),
+        ),
+        (
+            Type::List(Box::new(Type::String)),
+            rust::Type::Vec(Box::new(rust::Type::String)),
+        ),
+        (
+            Type::Map(
+                Box::new(Type::String),
+                Box::new(Type::List(Box::new(Type::Float))),
+            ),
+            rust::Type::Map(
+                Box::new(rust::Type::String),
+                Box::new(rust::Type::Vec(Box::new(rust::Type::F64))),
+            ),
+        ),
+        (
+            Type::Ffi(c::Type::PtrMut(Box::new(c::Type::UInt8_t))),
+            rust::Type::Ffi(c::Type::PtrMut(Box::new(c::Type::UInt8_t))),
+        ),
+    ];
+
+    for (php_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), php_type.to_rust());
+        assert_eq!(Ok(php_type), Type::try_from(rust_type));
+    }
+
+    assert_eq!(Ok(Type::Int), Type::try_from(rust::Type::U32));
+    assert_eq!(Err(()), Type::try_from(rust::Type::U64));
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Map(
+            Box::new(rust::Type::Bool),
+            Box::new(rust::Type::I32)
+        ))
+    );
+}
+
+#[test]
+fn php_format() {
+    assert_eq!(
+        "?string",
+        Type::Nullable(Box::new(Type::String)).to_string()
+    );
+    assert_eq!(
+        "int|string|null",
+        Type::Nullable(Box::new(Type::Union(vec![Type::Int, Type::String]))).to_string()
+    );
+    assert_eq!(
+        "array<string, list<int>>",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::List(Box::new(Type::Int)))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "array<mixed>",
+        Type::ArrayOf(Box::new(Type::Mixed)).to_string()
+    );
+}
+
+#[test]
+fn php_ffi() {
+    let type_map: [(&str, c::Type); 5] = [
+        ("int32_t", c::Type::Int32_t),
+        ("size_t", c::Type::Size_t),
+        ("const char*", c::Type::Ptr(Box::new(c::Type::Char))),
+        (
+            "uint8_t[16]",
+            c::Type::Array(Box::new(c::Type::UInt8_t), Some(16)),
+        ),
+        ("int64_t*", c::Type::PtrMut(Box::new(c::Type::Time_t))),
+    ];
+
+    for (cdef, c_type) in type_map {
+        assert_eq!(cdef, Type::Ffi(c_type).to_string());
+    }
+}
+
+#[test]
+fn php_parse() {
+    assert_eq!(Ok(Type::Nullable(Box::new(Type::Int))), "?int".parse());
+    assert_eq!(
+        Ok(Type::Nullable(Box::new(Type::Float))),
+        "null|float".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![Type::Int, Type::String, Type::Bool])),
+        "int|string|bool".parse()
+    );
+    assert_eq!(
+        Ok(Type::Map(
+            Box::new(Type::Int),
+            Box::new(Type::Union(vec![Type::String, Type::Null, Type::Int]))
+        )),
+        "array<int, string|null|int>".parse()
+    );
+    assert_eq!(
+        Ok(Type::ArrayOf(Box::new(Type::Bool))),
+        "array<boolean>".parse()
+    );
+    assert_eq!(
+        Ok(Type::List(Box::new(Type::Nullable(Box::new(Type::Array))))),
+        "list<?array>".parse()
+    );
+    assert_eq!(Err(()), "array<int, int, int>".parse::<Type>());
+    assert_eq!(Err(()), "list<>".parse::<Type>());
+}
@@ -0,0 +1,212 @@
+use codify::proto::{Declaration, Enum, Message, Schema, Type};
+use codify::{csharp, go, rust, ToRust};
+
+#[test]
+fn proto_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 9] = [
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int32, rust::Type::I32),
+        (Type::UInt64, rust::Type::U64),
+        (Type::Double, rust::Type::F64),
+        (Type::String, rust::Type::String),
+        (Type::Bytes, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (Type::Empty, rust::Type::Unit),
+        (
+            Type::Repeated(Box::new(Type::Float)),
+            rust::Type::Vec(Box::new(rust::Type::F32)),
+        ),
+        (
+            Type::Map(Box::new(Type::String), Box::new(Type::Int64)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::I64)),
+        ),
+    ];
+
+    for (proto_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), proto_type.to_rust());
+        assert_eq!(Ok(proto_type), Type::try_from(rust_type));
+    }
+
+    assert_eq!(Some(rust::Type::I32), Type::SInt32.to_rust());
+    assert_eq!(Some(rust::Type::U32), Type::Fixed32.to_rust());
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Vec(Box::new(rust::Type::Vec(Box::new(
+            rust::Type::I32
+        )))))
+    );
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Map(
+            Box::new(rust::Type::F64),
+            Box::new(rust::Type::I32)
+        ))
+    );
+}
+
+#[test]
+fn proto_parse() {
+    assert_eq!(
+        Ok(Type::Repeated(Box::new(Type::Timestamp))),
+        "repeated google.protobuf.Timestamp".parse()
+    );
+    assert_eq!(
+        Ok(Type::Optional(Box::new(Type::Named(".geo.Point".into())))),
+        "optional .geo.Point".parse()
+    );
+    assert_eq!(
+        Ok(Type::Map(
+            Box::new(Type::SInt64),
+            Box::new(Type::Named("Point".into()))
+        )),
+        "map<sint64, Point>".parse()
+    );
+    assert_eq!(Err(()), "repeated repeated int32".parse::<Type>());
+    assert_eq!(Err(()), "map<bytes, int32>".parse::<Type>());
+}
+
+#[test]
+fn proto_message_from_rust() {
+    let message = Message::from_rust(
+        "User",
+        vec![
+            ("id".into(), rust::Type::U64),
+            ("name".into(), rust::Type::String),
+            (
+                "email".into(),
+                rust::Type::Option(Box::new(rust::Type::String)),
+            ),
+            ("tags".into(), rust::Type::Vec(Box::new(rust::Type::String))),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        "message User { uint64 id = 1; string name = 2; optional string email = 3; repeated string tags = 4; }",
+        message.to_string()
+    );
+}
+
+#[test]
+fn proto_schema() {
+    let schema: Schema = r#"
+        syntax = "proto3";
+        package example;
+        import "google/protobuf/timestamp.proto";
+
+        // A point in time and space.
+        message Event {
+          fixed32 id = 1;
+          map<string, double> coordinates = 2 [deprecated = true];
+          repeated bytes payloads = 4;
+          google.protobuf.Timestamp at = 5;
+        }
+
+        enum Kind {
+          option allow_alias = true;
+          KIND_UNSPECIFIED = 0;
+          KIND_CREATED = 1;
+        }
+    "#
+    .parse()
+    .unwrap();
+
+    let event = Message::new(
+        "Event",
+        vec![
+            ("id".into(), Type::Fixed32, 1),
+            (
+                "coordinates".into(),
+                Type::Map(Box::new(Type::String), Box::new(Type::Double)),
+                2,
+            ),
+            ("payloads".into(), Type::Repeated(Box::new(Type::Bytes)), 4),
+            ("at".into(), Type::Timestamp, 5),
+        ],
+    );
+    let kind = Enum::new(
+        "Kind",
+        vec![("KIND_UNSPECIFIED".into(), 0), ("KIND_CREATED".into(), 1)],
+    );
+    assert_eq!(
+        Schema(vec![
+            Declaration::Message(event.clone()),
+            Declaration::Enum(kind)
+        ]),
+        schema
+    );
+    assert_eq!(
+        concat!(
+            "syntax = \"proto3\";\n",
+            "\n",
+            "import \"google/protobuf/timestamp.proto\";\n",
+            "\n",
+            "message Event { fixed32 id = 1; map<string, double> coordinates = 2; repeated bytes payloads = 4; google.protobuf.Timestamp at = 5; }\n",
+            "\n",
+            "enum Kind { KIND_UNSPECIFIED = 0; KIND_CREATED = 1; }\n",
+        ),
+        schema.to_string()
+    );
+
+    // The fields convert to other languages by way of Rust:
+    let fields = Message::new("Event", event.fields[..3].to_vec())
+        .to_rust()
+        .unwrap();
+    let go_fields: Vec<_> = fields
+        .iter()
+        .map(|(_, t)| go::Type::try_from(t.clone()).unwrap().to_string())
+        .collect();
+    let csharp_fields: Vec<_> = fields
+        .iter()
+        .map(|(_, t)| csharp::Type::try_from(t.clone()).unwrap().to_string())
+        .collect();
+    assert_eq!(vec!["uint32", "map[string]float64", "[][]uint8"], go_fields);
+    assert_eq!(
+        vec![
+            "uint",
+            "System.Collections.Generic.Dictionary<string, double>",
+            "System.Collections.Generic.List<System.Collections.Generic.List<byte>>",
+        ],
+        csharp_fields
+    );
+    assert_eq!(None, event.to_rust());
+    assert_eq!(
+        Err(()),
+        "message Outer { message Inner {} }".parse::<Schema>()
+    );
+}
+
+#[test]
+fn proto_schema_options_and_comments() {
+    let schema: Schema = r#"
+        syntax = "proto3"; /* the current syntax */
+        option go_package = "example.com/events;events"; // not a comment: "//"
+        option java_package = "com.example // events";
+
+        /*
+         * A user account.
+         */
+        message Account {
+          reserved 2, 15 to 20;
+          reserved "email";
+          option deprecated = true;
+          string name = 1; // the display name
+          optional int64 created = /* seconds */ 3;
+        }
+    "#
+    .parse()
+    .unwrap();
+
+    assert_eq!(
+        Schema(vec![Declaration::Message(Message::new(
+            "Account",
+            vec![
+                ("name".into(), Type::String, 1),
+                ("created".into(), Type::Optional(Box::new(Type::Int64)), 3),
+            ],
+        ))]),
+        schema
+    );
+    assert_eq!(
+        Ok(Message::new("Empty", vec![])),
+        "message Empty { option deprecated = true; }".parse()
+    );
+}
@@ -0,0 +1,177 @@
+use codify::python::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn python_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 11] = [
+        (Type::NoneType, rust::Type::Unit),
+        (Type::Any, rust::Type::Any),
+        (Type::Bool, rust::Type::Bool),
+        (Type::Int, rust::Type::I64),
+        (Type::Float, rust::Type::F64),
+        (Type::Str, rust::Type::String),
+        (Type::Bytes, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (
+            Type::List(Box::new(Type::Str)),
+            rust::Type::Vec(Box::new(rust::Type::String)),
+        ),
+        (
+            Type::Dict(Box::new(Type::Str), Box::new(Type::Bool)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::Bool)),
+        ),
+        (
+            Type::Optional(Box::new(Type::Set(Box::new(Type::Int)))),
+            rust::Type::Option(Box::new(rust::Type::Set(Box::new(rust::Type::I64)))),
+        ),
+        (
+            Type::Tuple(vec![Type::Int, Type::Str]),
+            rust::Type::Tuple(vec![rust::Type::I64, rust::Type::String]),
+        ),
+    ];
+
+    for (py_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), py_type.to_rust());
+        assert_eq!(Ok(py_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn python_format() {
+    let int = || Box::new(Type::Int);
+    assert_eq!("None", Type::NoneType.to_string());
+    assert_eq!(
+        "dict[str, list[int]]",
+        Type::Dict(Box::new(Type::Str), Box::new(Type::List(int()))).to_string()
+    );
+    assert_eq!("tuple[()]", Type::Tuple(vec![]).to_string());
+    assert_eq!("int | None", Type::Optional(int()).to_string());
+    assert_eq!(
+        "int | None",
+        Type::Optional(Box::new(Type::Optional(int()))).to_string()
+    );
+    assert_eq!("tuple[int, ...]", Type::VariadicTuple(int()).to_string());
+    assert_eq!(
+        "int | str | None",
+        Type::Union(vec![Type::Int, Type::Str, Type::NoneType]).to_string()
+    );
+    assert_eq!(
+        "Callable[[int, str], bool]",
+        Type::Callable(vec![Type::Int, Type::Str], Box::new(Type::Bool)).to_string()
+    );
+    assert_eq!(
+        "Literal[\"r\", \"w\"]",
+        Type::Literal(vec!["\"r\"".into(), "\"w\"".into()]).to_string()
+    );
+}
+
+#[test]
+fn python_parse() {
+    let int = || Box::new(Type::Int);
+    assert_eq!(
+        Ok(Type::Dict(Box::new(Type::Str), Box::new(Type::List(int())))),
+        "dict[str, list[int]]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Dict(Box::new(Type::Str), Box::new(Type::List(int())))),
+        "typing.Dict[str, List[int]]".parse()
+    );
+    assert_eq!(Ok(Type::Optional(int())), "int | None".parse());
+    assert_eq!(Ok(Type::Optional(int())), "None | int".parse());
+    assert_eq!(Ok(Type::Optional(int())), "Optional[int]".parse());
+    assert_eq!(Ok(Type::Optional(int())), "Union[int, None]".parse());
+    assert_eq!(
+        Ok(Type::Union(vec![Type::Int, Type::Str])),
+        "Union[int, str]".parse()
+    );
+    assert_eq!(Ok(Type::Tuple(vec![])), "tuple[()]".parse());
+    assert_eq!(Ok(Type::VariadicTuple(int())), "tuple[int, ...]".parse());
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::Int, Type::Str])),
+        "tuple[int, str]".parse()
+    );
+    assert_eq!(Ok(Type::FrozenSet(int())), "frozenset[int]".parse());
+    assert_eq!(
+        Ok(Type::Sequence(int())),
+        "collections.abc.Sequence[int]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Mapping(Box::new(Type::Str), int())),
+        "Mapping[str, int]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Callable(vec![], Box::new(Type::NoneType))),
+        "Callable[[], None]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Callable(
+            vec![Type::Int],
+            Box::new(Type::Optional(int()))
+        )),
+        "Callable[[int], int | None]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Literal(vec!["1".into(), "2".into()])),
+        "Literal[1, 2]".parse()
+    );
+    assert_eq!(Err(()), "list[int".parse::<Type>());
+    assert_eq!(Err(()), "settings[int]".parse::<Type>());
+}
+
+#[test]
+fn python_dtype() {
+    use codify::c;
+    use codify::python::Dtype;
+    assert_eq!(Ok(Dtype::UInt16), Dtype::try_from(rust::Type::U16));
+    assert_eq!("np.uint16", Dtype::UInt16.to_string());
+    assert_eq!("np.bool_", Dtype::Bool.to_string());
+    assert_eq!(Ok("<H".into()), Dtype::UInt16.format());
+    assert_eq!(Ok("<f".into()), Dtype::Float32.format());
+    assert_eq!(Ok("?".into()), Dtype::Bool.format());
+    assert_eq!(Ok(Dtype::Float32), "np.float32".parse());
+
+    let array: rust::Type = "[u8; 4]".parse().unwrap();
+    let array = Dtype::try_from(array).unwrap();
+    assert_eq!("(np.uint8, (4,))", array.to_string());
+    assert_eq!(Ok("4B".into()), array.format());
+    assert_eq!(Some("[u8; 4]".parse().unwrap()), array.to_rust());
+
+    let point = c::Struct::new(
+        "sample",
+        vec![
+            ("flag".into(), c::Type::Bool),
+            ("value".into(), c::Type::Float),
+            (
+                "ids".into(),
+                c::Type::Array(Box::new(c::Type::UInt16_t), Some(3)),
+            ),
+        ],
+    );
+    assert_eq!(Some(vec![0, 4, 8]), point.offsets());
+    assert_eq!(Some((16, 4)), point.layout());
+    let dtype = Dtype::try_from(&point).unwrap();
+    assert_eq!(
+        "np.dtype({'names': ['flag', 'value', 'ids'], 'formats': [np.bool_, np.float32, (np.uint16, (3,))], 'offsets': [0, 4, 8], 'itemsize': 16})",
+        dtype.to_string()
+    );
+    assert_eq!(Ok("<?3xf3H2x".into()), dtype.format());
+    assert_eq!(16, dtype.size());
+}
+
+#[test]
+fn python_dtype_invalid_struct() {
+    use codify::python::Dtype;
+    let overlapping = Dtype::Struct(
+        vec![("a".into(), Dtype::Int32, 0), ("b".into(), Dtype::Int32, 2)],
+        8,
+    );
+    assert_eq!(Err(()), overlapping.format());
+
+    let unsorted = Dtype::Struct(
+        vec![("a".into(), Dtype::Int32, 4), ("b".into(), Dtype::Int32, 0)],
+        8,
+    );
+    assert_eq!(Err(()), unsorted.format());
+
+    let truncated = Dtype::Struct(vec![("a".into(), Dtype::Float64, 0)], 4);
+    assert_eq!(Err(()), truncated.format());
+}
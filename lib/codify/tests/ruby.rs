@@ -0,0 +1,130 @@
+use codify::ruby::{Dialect, Type};
+use codify::{rust, ToRust};
+
+#[test]
+fn ruby_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 9] = [
+        (Type::Object, rust::Type::Any),
+        (Type::NilClass, rust::Type::Unit),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Float, rust::Type::F64),
+        (Type::Integer, rust::Type::I64),
+        (Type::String, rust::Type::String),
+        (
+            Type::Hash(Box::new(Type::String), Box::new(Type::Integer)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::I64)),
+        ),
+        (
+            Type::Optional(Box::new(Type::Array(Box::new(Type::Integer)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I64)))),
+        ),
+        (
+            Type::Tuple(vec![Type::Integer, Type::String]),
+            rust::Type::Tuple(vec![rust::Type::I64, rust::Type::String]),
+        ),
+    ];
+
+    for (rb_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), rb_type.to_rust());
+        assert_eq!(Ok(rb_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn ruby_format() {
+    let hash = Type::Hash(
+        Box::new(Type::String),
+        Box::new(Type::Array(Box::new(Type::Integer))),
+    );
+    let optional = Type::Optional(Box::new(Type::Integer));
+    let tuple = Type::Tuple(vec![Type::Integer, Type::String]);
+
+    assert_eq!("Hash{String => Array<Integer>}", hash.to_string());
+    assert_eq!("Integer, nil", optional.to_string());
+    assert_eq!("Array(Integer, String)", tuple.to_string());
+
+    let rbs = |t: &Type| t.with_dialect(Dialect::Rbs).to_string();
+    assert_eq!("Hash[String, Array[Integer]]", rbs(&hash));
+    assert_eq!("Integer?", rbs(&optional));
+    assert_eq!("[Integer, String]", rbs(&tuple));
+    assert_eq!("bool", rbs(&Type::Boolean));
+    assert_eq!("untyped", rbs(&Type::Object));
+
+    let sorbet = |t: &Type| t.with_dialect(Dialect::Sorbet).to_string();
+    assert_eq!("T::Hash[String, T::Array[Integer]]", sorbet(&hash));
+    assert_eq!("T.nilable(Integer)", sorbet(&optional));
+    assert_eq!("[Integer, String]", sorbet(&tuple));
+    assert_eq!("T::Boolean", sorbet(&Type::Boolean));
+}
+
+#[test]
+fn ruby_parse() {
+    let hash = Type::Hash(
+        Box::new(Type::String),
+        Box::new(Type::Array(Box::new(Type::Integer))),
+    );
+    let optional = Type::Optional(Box::new(Type::Integer));
+    for input in [
+        "Hash{String => Array<Integer>}",
+        "Hash[String, Array[Integer]]",
+        "T::Hash[String, T::Array[Integer]]",
+    ] {
+        assert_eq!(Ok(hash.clone()), input.parse(), "{}", input);
+    }
+    for input in ["Integer, nil", "Integer?", "T.nilable(Integer)"] {
+        assert_eq!(Ok(optional.clone()), input.parse(), "{}", input);
+    }
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::Integer, Type::Boolean])),
+        "[Integer, bool]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Set(Box::new(Type::Symbol))),
+        "T::Set[Symbol]".parse()
+    );
+    assert_eq!(Ok(Type::Object), "T.untyped".parse());
+    assert_eq!(Ok(Type::Other("Foo::Bar".into())), "Foo::Bar".parse());
+}
+
+#[test]
+fn ruby_ffi() {
+    use codify::c;
+    use codify::ruby::{FfiCallback, FfiEnum, FfiStruct};
+
+    let point = c::Struct::from_rust(
+        "my_point",
+        vec![
+            ("x".into(), rust::Type::I32),
+            ("y".into(), rust::Type::F64),
+            ("name".into(), rust::Type::Str),
+            ("tag".into(), "[u8; 4]".parse().unwrap()),
+        ],
+    )
+    .unwrap();
+    assert_eq!(
+        "class MyPoint < FFI::Struct\n  layout :x, :int32,\n         :y, :double,\n         :name, :string,\n         :tag, [:uint8, 4]\nend",
+        FfiStruct(point).to_string()
+    );
+
+    let callback = c::Function::from_rust(
+        "on_event",
+        vec![rust::Type::I32, rust::Type::Ptr(Box::new(rust::Type::U8))],
+        rust::Type::Unit,
+    )
+    .unwrap();
+    assert_eq!(
+        "typedef void (*on_event)(int32_t, const uint8_t*);",
+        callback.to_string()
+    );
+    assert_eq!(
+        "callback :on_event, [:int32, :pointer], :void",
+        FfiCallback(callback).to_string()
+    );
+
+    let color = c::Enum::new("color", vec![("red".into(), 0), ("blue".into(), 2)]);
+    assert_eq!("enum color { red = 0, blue = 2, };", color.to_string());
+    assert_eq!(
+        "enum :color, [:red, 0, :blue, 2]",
+        FfiEnum(color).to_string()
+    );
+}
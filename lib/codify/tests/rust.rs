@@ -0,0 +1,40 @@
+use codify::rust::Type;
+
+#[test]
+fn rust_format() {
+    assert_eq!("Rc<u8>", Type::Rc(Box::new(Type::U8)).to_string());
+    assert_eq!("Arc<String>", Type::Arc(Box::new(Type::String)).to_string());
+    assert_eq!("Cow<str>", Type::Cow(Box::new(Type::Str)).to_string());
+    assert_eq!("Weak<bool>", Type::Weak(Box::new(Type::Bool)).to_string());
+}
+
+#[test]
+fn rust_parse() {
+    assert_eq!(Ok(Type::Box(Box::new(Type::I32))), "Box<i32>".parse());
+    assert_eq!(Ok(Type::Rc(Box::new(Type::U8))), "Rc<u8>".parse());
+    assert_eq!(Ok(Type::Arc(Box::new(Type::String))), "Arc<String>".parse());
+    assert_eq!(Ok(Type::Cow(Box::new(Type::Str))), "Cow<str>".parse());
+    assert_eq!(Ok(Type::Cow(Box::new(Type::Str))), "Cow<'a, str>".parse());
+    assert_eq!(Ok(Type::Weak(Box::new(Type::Bool))), "Weak<bool>".parse());
+    assert_eq!(
+        Ok(Type::Map(Box::new(Type::I64), Box::new(Type::String))),
+        "BTreeMap<i64, String>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Ffi(codify::c::Type::Array(
+            Box::new(codify::c::Type::UInt16_t),
+            Some(4)
+        ))),
+        "[u16; 4]".parse()
+    );
+    assert_eq!(Ok(Type::I32), "(i32)".parse());
+    assert_eq!(Ok(Type::Tuple(vec![Type::I32])), "(i32,)".parse::<Type>());
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::I32, Type::Bool])),
+        "(i32, bool)".parse::<Type>()
+    );
+    assert_eq!(Ok(Type::Vec(Box::new(Type::U8))), "(Vec<u8>)".parse());
+    assert_eq!(Err(()), "[u16]".parse::<Type>());
+    assert_eq!(Err(()), "Rc<>".parse::<Type>());
+    assert_eq!(Err(()), "Arc<bogus>".parse::<Type>());
+}
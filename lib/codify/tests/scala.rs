@@ -0,0 +1,105 @@
+use codify::scala::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn scala_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Unit, rust::Type::Unit),
+        (Type::Any, rust::Type::Any),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Byte, rust::Type::I8),
+        (Type::Long, rust::Type::I64),
+        (Type::Float, rust::Type::F32),
+        (Type::Char, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Array(Box::new(Type::Byte)),
+            rust::Type::Vec(Box::new(rust::Type::U8)),
+        ),
+        (
+            Type::Option(Box::new(Type::Seq(Box::new(Type::Int)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::I32)))),
+        ),
+        (
+            Type::Map(
+                Box::new(Type::String),
+                Box::new(Type::Set(Box::new(Type::Short))),
+            ),
+            rust::Type::Map(
+                Box::new(rust::Type::String),
+                Box::new(rust::Type::Set(Box::new(rust::Type::I16))),
+            ),
+        ),
+        (
+            Type::Tuple(vec![Type::Int, Type::Double]),
+            rust::Type::Tuple(vec![rust::Type::I32, rust::Type::F64]),
+        ),
+    ];
+
+    for (scala_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), scala_type.to_rust());
+        assert_eq!(Ok(scala_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn scala_format() {
+    assert_eq!(
+        "Map[String, Vector[Long]]",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Vector(Box::new(Type::Long)))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "Either[String, (Int, Boolean)]",
+        Type::Either(
+            Box::new(Type::String),
+            Box::new(Type::Tuple(vec![Type::Int, Type::Boolean]))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "List[Option[Char]]",
+        Type::List(Box::new(Type::Option(Box::new(Type::Char)))).to_string()
+    );
+}
+
+#[test]
+fn scala_parse() {
+    assert_eq!(Ok(Type::Unit), "()".parse());
+    assert_eq!(Ok(Type::String), "java.lang.String".parse());
+    assert_eq!(
+        Ok(Type::Vector(Box::new(Type::Int))),
+        "scala.collection.immutable.Vector[Int]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Array(Box::new(Type::Byte)))
+        )),
+        "Map[String, Array[Byte]]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Either(
+            Box::new(Type::String),
+            Box::new(Type::Seq(Box::new(Type::Double)))
+        )),
+        "scala.util.Either[String, Seq[Double]]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Int,
+            Type::Map(Box::new(Type::Char), Box::new(Type::Long))
+        ])),
+        "(Int, Map[Char, Long])".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::Short, Type::Float, Type::Any])),
+        "Tuple3[Short, Float, Any]".parse()
+    );
+    assert_eq!(Err(()), "Tuple2[Int]".parse::<Type>());
+    assert_eq!(Err(()), "Map[Int]".parse::<Type>());
+    assert_eq!(Err(()), "Option<Int>".parse::<Type>());
+}
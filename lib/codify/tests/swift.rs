@@ -0,0 +1,130 @@
+use codify::swift::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn swift_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Any, rust::Type::Any),
+        (Type::Float, rust::Type::F32),
+        (Type::Int8, rust::Type::I8),
+        (Type::UInt64, rust::Type::U64),
+        (Type::Int, rust::Type::Isize),
+        (Type::Character, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Array(Box::new(Type::Int32)),
+            rust::Type::Vec(Box::new(rust::Type::I32)),
+        ),
+        (
+            Type::Dictionary(Box::new(Type::String), Box::new(Type::Double)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::F64)),
+        ),
+        (
+            Type::Optional(Box::new(Type::Set(Box::new(Type::UInt16)))),
+            rust::Type::Option(Box::new(rust::Type::Set(Box::new(rust::Type::U16)))),
+        ),
+        (
+            Type::Tuple(vec![Type::Bool, Type::String]),
+            rust::Type::Tuple(vec![rust::Type::Bool, rust::Type::String]),
+        ),
+    ];
+
+    for (swift_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), swift_type.to_rust());
+        assert_eq!(Ok(swift_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn swift_from_rust_ffi() {
+    assert_eq!(
+        Ok(Type::Ffi(c::Type::Int)),
+        Type::try_from(rust::Type::Ffi(c::Type::Int))
+    );
+    assert_eq!(
+        Ok(Type::UnsafeRawPointer),
+        Type::try_from(rust::Type::Ptr(Box::new(rust::Type::Ffi(c::Type::Void))))
+    );
+    assert_eq!(
+        Ok(Type::UnsafeMutablePointer(Box::new(Type::UInt8))),
+        Type::try_from(rust::Type::PtrMut(Box::new(rust::Type::U8)))
+    );
+    assert_eq!(
+        Ok(Type::Data),
+        Type::try_from(rust::Type::Vec(Box::new(rust::Type::U8)))
+    );
+}
+
+#[test]
+fn swift_format() {
+    assert_eq!("[Int]", Type::Array(Box::new(Type::Int)).to_string());
+    assert_eq!(
+        "[String: [Int]]",
+        Type::Dictionary(
+            Box::new(Type::String),
+            Box::new(Type::Array(Box::new(Type::Int)))
+        )
+        .to_string()
+    );
+    assert_eq!("Set<String>", Type::Set(Box::new(Type::String)).to_string());
+    assert_eq!("Int?", Type::Optional(Box::new(Type::Int)).to_string());
+    assert_eq!(
+        "(Int, String)",
+        Type::Tuple(vec![Type::Int, Type::String]).to_string()
+    );
+    assert_eq!("CInt", Type::Ffi(c::Type::Int).to_string());
+    assert_eq!("CChar", Type::Ffi(c::Type::Char).to_string());
+    assert_eq!("CLong", Type::Ffi(c::Type::Long).to_string());
+    assert_eq!(
+        "UnsafePointer<CChar>",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+    assert_eq!(
+        "UnsafeMutableRawPointer",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))).to_string()
+    );
+    assert_eq!(
+        "(CChar, CChar, CChar)",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::Char), Some(3))).to_string()
+    );
+}
+
+#[test]
+fn swift_parse() {
+    assert_eq!(Ok(Type::Void), "()".parse());
+    assert_eq!(Ok(Type::Float), "Float32".parse());
+    assert_eq!(Ok(Type::Array(Box::new(Type::Int))), "[Int]".parse());
+    assert_eq!(Ok(Type::Array(Box::new(Type::Int))), "Array<Int>".parse());
+    assert_eq!(
+        Ok(Type::Dictionary(
+            Box::new(Type::String),
+            Box::new(Type::Array(Box::new(Type::Int)))
+        )),
+        "[String: [Int]]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Dictionary(
+            Box::new(Type::String),
+            Box::new(Type::Int)
+        )),
+        "Dictionary<String, Int>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Optional(Box::new(Type::Array(Box::new(Type::Int))))),
+        "[Int]?".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::Int, Type::String])),
+        "(Int, String)".parse()
+    );
+    assert_eq!(Ok(Type::Int), "(Int)".parse());
+    assert_eq!(Ok(Type::Ffi(c::Type::Int)), "CInt".parse());
+    assert_eq!(
+        Ok(Type::UnsafePointer(Box::new(Type::Ffi(c::Type::Char)))),
+        "UnsafePointer<CChar>".parse()
+    );
+    assert_eq!(Err(()), "[Int: String: Bool]".parse::<Type>());
+    assert_eq!(Err(()), "[Int] | [String]".parse::<Type>());
+    assert_eq!(Err(()), "Bogus".parse::<Type>());
+}
@@ -0,0 +1,199 @@
+use codify::typescript::{BigIntMapping, Type};
+use codify::{rust, ToRust};
+
+#[test]
+fn typescript_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 9] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Any, rust::Type::Any),
+        (Type::Boolean, rust::Type::Bool),
+        (Type::Number, rust::Type::F64),
+        (Type::BigInt, rust::Type::I128),
+        (Type::String, rust::Type::String),
+        (Type::Uint8Array, rust::Type::Vec(Box::new(rust::Type::U8))),
+        (
+            Type::Union(vec![Type::Array(Box::new(Type::String)), Type::Null]),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::String)))),
+        ),
+        (
+            Type::Record(Box::new(Type::String), Box::new(Type::Boolean)),
+            rust::Type::Map(Box::new(rust::Type::String), Box::new(rust::Type::Bool)),
+        ),
+    ];
+
+    for (ts_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), ts_type.to_rust());
+        assert_eq!(Ok(ts_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn typescript_bigint_mapping() {
+    assert_eq!(Ok(Type::Number), Type::try_from(rust::Type::U64));
+    assert_eq!(
+        Ok(Type::Number),
+        Type::from_rust(rust::Type::I64, BigIntMapping::Number)
+    );
+    assert_eq!(
+        Ok(Type::BigInt),
+        Type::from_rust(rust::Type::I64, BigIntMapping::BigInt)
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Number))),
+        Type::from_rust(
+            rust::Type::Vec(Box::new(rust::Type::U64)),
+            BigIntMapping::Number
+        )
+    );
+    assert_eq!(
+        Ok(Type::BigUint64Array),
+        Type::from_rust(
+            rust::Type::Vec(Box::new(rust::Type::U64)),
+            BigIntMapping::BigInt
+        )
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::BigInt, Type::String])),
+        Type::from_rust(
+            rust::Type::Tuple(vec![rust::Type::U64, rust::Type::String]),
+            BigIntMapping::BigInt
+        )
+    );
+}
+
+#[test]
+fn typescript_format() {
+    let nested = rust::Type::Option(Box::new(rust::Type::Option(Box::new(rust::Type::U8))));
+    assert_eq!("number | null", Type::try_from(nested).unwrap().to_string());
+    assert_eq!("string[]", Type::Array(Box::new(Type::String)).to_string());
+    assert_eq!(
+        "(string | number)[]",
+        Type::Array(Box::new(Type::Union(vec![Type::String, Type::Number]))).to_string()
+    );
+    assert_eq!(
+        "ReadonlyArray<number>",
+        Type::ReadonlyArray(Box::new(Type::Number)).to_string()
+    );
+    assert_eq!(
+        "[number, string]",
+        Type::Tuple(vec![Type::Number, Type::String]).to_string()
+    );
+    assert_eq!(
+        "Map<string, Set<bigint>>",
+        Type::Map(
+            Box::new(Type::String),
+            Box::new(Type::Set(Box::new(Type::BigInt)))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "\"on\" | \"off\"",
+        Type::Union(vec![
+            Type::Literal("on".into()),
+            Type::Literal("off".into())
+        ])
+        .to_string()
+    );
+    assert_eq!(
+        "{ id: number; name: string }",
+        Type::Object(vec![
+            ("id".into(), Type::Number),
+            ("name".into(), Type::String)
+        ])
+        .to_string()
+    );
+    assert_eq!("{}", Type::Object(vec![]).to_string());
+}
+
+#[test]
+fn typescript_parse() {
+    assert_eq!(Ok(Type::Array(Box::new(Type::String))), "string[]".parse());
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::String))),
+        "Array<string>".parse()
+    );
+    assert_eq!(
+        Ok(Type::ReadonlyArray(Box::new(Type::Number))),
+        "readonly number[]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Array(Box::new(Type::Number))))),
+        "number[][]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Array(Box::new(Type::Union(vec![
+            Type::String,
+            Type::Number
+        ])))),
+        "(string | number)[]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![
+            Type::Array(Box::new(Type::String)),
+            Type::Null,
+            Type::Undefined
+        ])),
+        "string[] | null | undefined".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![
+            Type::Literal("a|b".into()),
+            Type::Literal("c".into())
+        ])),
+        "\"a|b\" | 'c'".parse()
+    );
+    assert_eq!(
+        Ok(Type::Union(vec![
+            Type::Literal("a|b".into()),
+            Type::Literal("c,d".into())
+        ])),
+        "'a|b' | 'c,d'".parse()
+    );
+    assert_eq!(Ok(Type::Tuple(vec![])), "[]".parse());
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Number,
+            Type::Tuple(vec![Type::String])
+        ])),
+        "[number, [string]]".parse()
+    );
+    assert_eq!(
+        Ok(Type::Record(
+            Box::new(Type::String),
+            Box::new(Type::Map(Box::new(Type::Number), Box::new(Type::Boolean)))
+        )),
+        "Record<string, Map<number, boolean>>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Object(vec![
+            ("id".into(), Type::Number),
+            (
+                "tags".into(),
+                Type::Union(vec![Type::Array(Box::new(Type::String)), Type::Undefined])
+            ),
+        ])),
+        "{ id: number; tags?: string[]; }".parse()
+    );
+    assert_eq!(
+        Ok(Type::Object(vec![
+            ("id".into(), Type::Number),
+            ("ok".into(), Type::Boolean)
+        ])),
+        "{ id: number, ok: boolean }".parse()
+    );
+    assert_eq!(Ok(Type::Float64Array), "Float64Array".parse());
+    assert_eq!(Err(()), "Record<string>".parse::<Type>());
+    assert_eq!(Err(()), "string | ".parse::<Type>());
+    assert_eq!(
+        Ok(Type::Object(vec![
+            (
+                "a".into(),
+                Type::Object(vec![("b".into(), Type::Number), ("c".into(), Type::String)])
+            ),
+            ("d".into(), Type::Number)
+        ])),
+        "{ a: { b: number; c: string }, d: number }".parse()
+    );
+    assert_eq!(Err(()), "{ id }".parse::<Type>());
+    assert_eq!(Err(()), "bogus".parse::<Type>());
+}
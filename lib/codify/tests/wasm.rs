@@ -0,0 +1,47 @@
+use codify::wasm::Type;
+use codify::{rust, ToRust};
+
+#[test]
+fn wasm_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 4] = [
+        (Type::I32, rust::Type::I32),
+        (Type::I64, rust::Type::I64),
+        (Type::F32, rust::Type::F32),
+        (Type::F64, rust::Type::F64),
+    ];
+
+    for (wasm_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), wasm_type.to_rust());
+        assert_eq!(Ok(wasm_type), Type::try_from(rust_type));
+    }
+}
+
+#[test]
+fn wasm_lowering() {
+    assert_eq!(Ok(Type::I32), Type::try_from(rust::Type::Bool));
+    assert_eq!(Ok(Type::I32), Type::try_from(rust::Type::U8));
+    assert_eq!(Ok(Type::I32), Type::try_from(rust::Type::Char));
+    assert_eq!(Ok(Type::I64), Type::try_from(rust::Type::U64));
+    assert_eq!(
+        Ok(Type::I32),
+        Type::try_from(rust::Type::PtrMut(Box::new(rust::Type::U8)))
+    );
+    assert_eq!(Err(()), Type::try_from(rust::Type::String));
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Ref(Box::new(rust::Type::Str)))
+    );
+    assert_eq!(
+        Err(()),
+        Type::try_from(rust::Type::Box(Box::new(rust::Type::Str)))
+    );
+    assert_eq!(None, Type::ExternRef.to_rust());
+}
+
+#[test]
+fn wasm_parse() {
+    for name in ["i32", "i64", "f32", "f64", "v128", "funcref", "externref"] {
+        assert_eq!(name, name.parse::<Type>().unwrap().to_string());
+    }
+    assert_eq!(Err(()), "anyref".parse::<Type>());
+}
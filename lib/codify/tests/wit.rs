@@ -0,0 +1,138 @@
+use codify::wit::{Enum, Flags, Record, Resource, Type, Variant};
+use codify::{rust, wasm, ToRust};
+
+#[test]
+fn wit_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 8] = [
+        (Type::Bool, rust::Type::Bool),
+        (Type::S8, rust::Type::I8),
+        (Type::U64, rust::Type::U64),
+        (Type::F32, rust::Type::F32),
+        (Type::Char, rust::Type::Char),
+        (Type::String, rust::Type::String),
+        (
+            Type::Option(Box::new(Type::List(Box::new(Type::U8)))),
+            rust::Type::Option(Box::new(rust::Type::Vec(Box::new(rust::Type::U8)))),
+        ),
+        (
+            Type::Tuple(vec![Type::S32, Type::F64]),
+            rust::Type::Tuple(vec![rust::Type::I32, rust::Type::F64]),
+        ),
+    ];
+
+    for (wit_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), wit_type.to_rust());
+        assert_eq!(Ok(wit_type), Type::try_from(rust_type));
+    }
+
+    assert_eq!(
+        Ok(Type::List(Box::new(Type::Tuple(vec![
+            Type::String,
+            Type::U32
+        ])))),
+        Type::try_from(rust::Type::Map(
+            Box::new(rust::Type::String),
+            Box::new(rust::Type::U32)
+        ))
+    );
+}
+
+#[test]
+fn wit_format() {
+    assert_eq!(
+        "result<list<u8>, string>",
+        Type::Result(
+            Some(Box::new(Type::List(Box::new(Type::U8)))),
+            Some(Box::new(Type::String))
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "result<_, s32>",
+        Type::Result(None, Some(Box::new(Type::S32))).to_string()
+    );
+    assert_eq!(
+        "record point { x: s32, y: s32 }",
+        Record::new(
+            "point",
+            vec![("x".into(), Type::S32), ("y".into(), Type::S32)]
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "variant filter { all, none, some(list<string>) }",
+        Variant::new(
+            "filter",
+            vec![
+                ("all".into(), None),
+                ("none".into(), None),
+                ("some".into(), Some(Type::List(Box::new(Type::String))))
+            ]
+        )
+        .to_string()
+    );
+    assert_eq!(
+        "enum color { red, green }",
+        Enum::new("color", vec!["red".into(), "green".into()]).to_string()
+    );
+    assert_eq!(
+        "flags permissions { read, write }",
+        Flags::new("permissions", vec!["read".into(), "write".into()]).to_string()
+    );
+    assert_eq!("resource blob;", Resource::new("blob").to_string());
+    assert_eq!(
+        "borrow<blob>",
+        Type::Borrow(Resource::new("blob")).to_string()
+    );
+}
+
+#[test]
+fn wit_parse() {
+    assert_eq!(Ok(Type::Result(None, None)), "result".parse());
+    assert_eq!(
+        Ok(Type::Result(Some(Box::new(Type::String)), None)),
+        "result<string>".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![
+            Type::Own(Resource::new("blob")),
+            Type::Option(Box::new(Type::F64))
+        ])),
+        "tuple<own<blob>, option<float64>>".parse()
+    );
+    assert_eq!(Err(()), "point".parse::<Type>());
+}
+
+#[test]
+fn wit_flatten() {
+    use wasm::Type::{F32, F64, I32, I64};
+    assert_eq!(vec![I32, I32], Type::String.flatten());
+    assert_eq!(
+        vec![I32, I32, I64, F64],
+        Type::try_from(rust::Type::Tuple(vec![
+            rust::Type::Vec(Box::new(rust::Type::U16)),
+            rust::Type::I64,
+            rust::Type::F64
+        ]))
+        .unwrap()
+        .flatten()
+    );
+    // The cases of a variant share their flattened positions:
+    assert_eq!(
+        vec![I32, I32],
+        Type::Result(Some(Box::new(Type::U32)), Some(Box::new(Type::F32))).flatten()
+    );
+    assert_eq!(
+        vec![I32, I64],
+        Type::Result(Some(Box::new(Type::F32)), Some(Box::new(Type::S64))).flatten()
+    );
+    assert_eq!(vec![I32, F32], Type::Option(Box::new(Type::F32)).flatten());
+    assert_eq!(
+        vec![I32, I32],
+        Type::Flags(Flags::new(
+            "f",
+            (0..33).map(|i| format!("f{}", i)).collect()
+        ))
+        .flatten()
+    );
+}
@@ -0,0 +1,106 @@
+use codify::zig::Type;
+use codify::{c, rust, ToRust};
+
+#[test]
+fn zig_to_rust_and_back() {
+    let type_map: [(Type, rust::Type); 12] = [
+        (Type::Void, rust::Type::Unit),
+        (Type::Bool, rust::Type::Bool),
+        (Type::UInt(8), rust::Type::U8),
+        (Type::Int(128), rust::Type::I128),
+        (Type::Usize, rust::Type::Usize),
+        (Type::F64, rust::Type::F64),
+        (Type::UInt(21), rust::Type::Char),
+        (Type::Slice(Box::new(Type::UInt(8))), rust::Type::String),
+        (
+            Type::Optional(Box::new(Type::Int(32))),
+            rust::Type::Option(Box::new(rust::Type::I32)),
+        ),
+        (
+            Type::SliceMut(Box::new(Type::F32)),
+            rust::Type::Vec(Box::new(rust::Type::F32)),
+        ),
+        (
+            Type::CPtr(Box::new(Type::UInt(16))),
+            rust::Type::Ptr(Box::new(rust::Type::U16)),
+        ),
+        (
+            Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))),
+            rust::Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))),
+        ),
+    ];
+
+    for (zig_type, rust_type) in type_map {
+        assert_eq!(Some(rust_type.clone()), zig_type.to_rust());
+        assert_eq!(Ok(zig_type), Type::try_from(rust_type));
+    }
+    assert_eq!(
+        Some(rust::Type::Option(Box::new(rust::Type::String))),
+        "?[]const u8".parse::<Type>().unwrap().to_rust()
+    );
+}
+
+#[test]
+fn zig_format() {
+    let u8 = || Box::new(Type::UInt(8));
+    assert_eq!("u7", Type::UInt(7).to_string());
+    assert_eq!("[4]u8", Type::Array(u8(), 4).to_string());
+    assert_eq!("[]const u8", Type::Slice(u8()).to_string());
+    assert_eq!("[*]u8", Type::ManyPtrMut(u8()).to_string());
+    assert_eq!("[*c]const u8", Type::CPtr(u8()).to_string());
+    assert_eq!(
+        "anyerror!?*i32",
+        Type::ErrorUnion(Box::new(Type::Optional(Box::new(Type::PtrMut(Box::new(
+            Type::Int(32)
+        ))))))
+        .to_string()
+    );
+    assert_eq!(
+        "struct { u8, bool }",
+        Type::Tuple(vec![Type::UInt(8), Type::Bool]).to_string()
+    );
+    assert_eq!("c_long", Type::Ffi(c::Type::Long).to_string());
+    assert_eq!(
+        "[*c]const c_char",
+        Type::Ffi(c::Type::Ptr(Box::new(c::Type::Char))).to_string()
+    );
+    assert_eq!(
+        "[16]u8",
+        Type::Ffi(c::Type::Array(Box::new(c::Type::UInt8_t), Some(16))).to_string()
+    );
+    assert_eq!(
+        "?*anyopaque",
+        Type::Ffi(c::Type::PtrMut(Box::new(c::Type::Void))).to_string()
+    );
+}
+
+#[test]
+fn zig_parse() {
+    let u8 = || Box::new(Type::UInt(8));
+    assert_eq!(Ok(Type::UInt(65535)), "u65535".parse());
+    assert_eq!(Ok(Type::Int(3)), "i3".parse());
+    assert_eq!(Ok(Type::Array(u8(), 32)), "[32]u8".parse());
+    assert_eq!(Ok(Type::Slice(u8())), "[]const u8".parse());
+    assert_eq!(Ok(Type::SliceMut(u8())), "[]u8".parse());
+    assert_eq!(Ok(Type::Ptr(u8())), "*const u8".parse());
+    assert_eq!(Ok(Type::ManyPtr(u8())), "[*]const u8".parse());
+    assert_eq!(Ok(Type::CPtrMut(u8())), "[*c]u8".parse());
+    assert_eq!(
+        Ok(Type::Optional(Box::new(Type::PtrMut(Box::new(
+            Type::AnyOpaque
+        ))))),
+        "?*anyopaque".parse()
+    );
+    assert_eq!(
+        Ok(Type::ErrorUnion(Box::new(Type::Void))),
+        "anyerror!void".parse()
+    );
+    assert_eq!(
+        Ok(Type::Tuple(vec![Type::UInt(8), Type::F16])),
+        "struct { u8, f16 }".parse()
+    );
+    assert_eq!(Ok(Type::Ffi(c::Type::UInt)), "c_uint".parse());
+    assert_eq!(Err(()), "u65536".parse::<Type>());
+    assert_eq!(Err(()), "[n]u8".parse::<Type>());
+    assert_eq!(Err(()), "c_bogus".parse::<Type>());
+}